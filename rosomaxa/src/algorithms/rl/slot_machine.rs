@@ -91,6 +91,11 @@ where
     pub fn get_params(&self) -> (f64, f64, f64, f64, usize) {
         (self.alpha, self.beta, self.mu, self.v, self.n)
     }
+
+    /// Sets learned params (alpha, beta, mean and variants) and usage amount.
+    pub fn set_params(&mut self, params: (f64, f64, f64, f64, usize)) {
+        (self.alpha, self.beta, self.mu, self.v, self.n) = params;
+    }
 }
 
 impl<T, S> Display for SlotMachine<T, S>
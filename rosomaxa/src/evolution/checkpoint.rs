@@ -0,0 +1,75 @@
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/checkpoint_test.rs"]
+mod checkpoint_test;
+
+use crate::evolution::HeuristicSolutionProcessing;
+use crate::hyper::{HyperHeuristic, HyperHeuristicState};
+use crate::prelude::*;
+
+/// Represents the search state which can be saved periodically and used to resume the search later.
+pub struct EvolutionCheckpoint<S> {
+    /// A generation number.
+    pub generation: usize,
+    /// Solutions which represent population state.
+    pub solutions: Vec<S>,
+    /// An internal state of hyper heuristic.
+    pub heuristic: HyperHeuristicState,
+    /// A seed of random generator used by the search, if it is seeded. Please note that the state of
+    /// random generator is not saved, so the resumed search does not continue the same random sequence.
+    pub seed: Option<u64>,
+}
+
+/// A function which handles created checkpoint, e.g. writes it to a file.
+pub type CheckpointHandler<S> = Box<dyn Fn(EvolutionCheckpoint<S>) -> Result<(), GenericError> + Send + Sync>;
+
+/// Specifies how often checkpoint is created and how it is handled.
+pub struct CheckpointConfig<S> {
+    interval: usize,
+    handler: CheckpointHandler<S>,
+}
+
+impl<S> CheckpointConfig<S>
+where
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `CheckpointConfig` which creates checkpoint each `interval` generations.
+    pub fn new(interval: usize, handler: CheckpointHandler<S>) -> Result<Self, GenericError> {
+        if interval == 0 {
+            return Err("checkpoint interval should be greater than zero".into());
+        }
+
+        Ok(Self { interval, handler })
+    }
+
+    /// Returns amount of generations between checkpoints.
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+
+    /// Creates a checkpoint using the current heuristic context and heuristic and passes it to the handler.
+    /// Solutions are post processed by the given `processing`, so they have the same form as the final ones.
+    /// Errors are reported using environment's logger, so the search is not interrupted.
+    pub fn save<C, O>(
+        &self,
+        heuristic_ctx: &C,
+        heuristic: &dyn HyperHeuristic<Context = C, Objective = O, Solution = S>,
+        processing: &dyn HeuristicSolutionProcessing<Solution = S>,
+    ) where
+        C: HeuristicContext<Objective = O, Solution = S>,
+        O: HeuristicObjective<Solution = S>,
+    {
+        let environment = heuristic_ctx.environment();
+        let generation = heuristic_ctx.statistics().generation;
+
+        let checkpoint = EvolutionCheckpoint {
+            generation,
+            solutions: heuristic_ctx.snapshot().map(|solution| processing.post_process(solution.deep_copy())).collect(),
+            heuristic: heuristic.get_state(),
+            seed: environment.random.get_seed(),
+        };
+
+        if let Err(err) = (self.handler)(checkpoint) {
+            (environment.logger)(&format!("cannot save checkpoint at generation {generation}: {err}"));
+        }
+    }
+}
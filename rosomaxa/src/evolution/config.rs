@@ -71,7 +71,23 @@ where
     /// A heuristic context creating pre processing.
     pub context: Vec<Box<dyn HeuristicContextProcessing<Context = C, Objective = O, Solution = S> + Send + Sync>>,
     /// A solution post processing.
-    pub solution: Vec<Box<dyn HeuristicSolutionProcessing<Solution = S> + Send + Sync>>,
+    pub solution: Vec<SolutionProcessingHook<S>>,
+}
+
+impl<C, O, S> ProcessingConfig<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution + 'static,
+{
+    /// Returns solution post processing which can be shared with other components. Solution hooks
+    /// are replaced with a shared one, so the final solutions are post processed in the same way.
+    pub fn share_solution_processing(&mut self) -> SharedSolutionProcessing<S> {
+        let processing = SharedSolutionProcessing::new(std::mem::take(&mut self.solution));
+        self.solution = vec![Box::new(processing.clone())];
+
+        processing
+    }
 }
 
 /// Provides configurable way to build evolution configuration using fluent interface style.
@@ -85,10 +101,11 @@ where
     max_generations: Option<usize>,
     max_time: Option<usize>,
     min_cv: Option<(String, usize, f64, bool, K)>,
+    max_stagnation: Option<(String, usize, Vec<f64>, K)>,
     target_proximity: Option<(Vec<f64>, f64)>,
     heuristic: Option<Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>>,
     context: Option<C>,
-    termination: Option<Box<dyn Termination<Context = C, Objective = O>>>,
+    terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>>,
     strategy: Option<Box<dyn EvolutionStrategy<Context = C, Objective = O, Solution = S>>>,
 
     search_operators: Option<HeuristicSearchOperators<C, O, S>>,
@@ -96,6 +113,12 @@ where
 
     objective: Option<Arc<dyn HeuristicObjective<Solution = S>>>,
 
+    checkpoint: Option<CheckpointConfig<S>>,
+    resume: Option<EvolutionCheckpoint<S>>,
+    progress: Option<ProgressListener<S>>,
+    heuristic_priors: Option<HyperHeuristicState>,
+    heuristic_export: Option<HyperHeuristicStateHandler>,
+
     initial: InitialConfig<C, O, S>,
     processing: ProcessingConfig<C, O, S>,
 }
@@ -112,14 +135,20 @@ where
             max_generations: None,
             max_time: None,
             min_cv: None,
+            max_stagnation: None,
             target_proximity: None,
             heuristic: None,
             context: None,
-            termination: None,
+            terminations: Vec::default(),
             strategy: None,
             search_operators: None,
             diversify_operators: None,
             objective: None,
+            checkpoint: None,
+            resume: None,
+            progress: None,
+            heuristic_priors: None,
+            heuristic_export: None,
             initial: InitialConfig { operators: vec![], max_size: 4, quota: 0.05, individuals: vec![] },
             processing: ProcessingConfig { context: vec![], solution: vec![] },
         }
@@ -151,6 +180,15 @@ where
         self
     }
 
+    /// Sets termination criteria which stops evolution when the best known fitness is not improved
+    /// within given amount of generations (`sample`) or seconds (`period`). Relative tolerance is specified
+    /// per objective. Default is None.
+    pub fn with_max_stagnation(mut self, max_stagnation: Option<(String, usize, Vec<f64>)>, key: K) -> Self {
+        self.max_stagnation =
+            max_stagnation.map(|max_stagnation| (max_stagnation.0, max_stagnation.1, max_stagnation.2, key));
+        self
+    }
+
     /// Sets target fitness and distance threshold as termination criteria.
     pub fn with_target_proximity(mut self, target_proximity: Option<(Vec<f64>, f64)>) -> Self {
         self.target_proximity = target_proximity;
@@ -194,9 +232,12 @@ where
         self
     }
 
-    /// Sets termination.
-    pub fn with_termination(mut self, termination: Box<dyn Termination<Context = C, Objective = O>>) -> Self {
-        self.termination = Some(termination);
+    /// Adds a custom termination criteria which is used along with other configured ones.
+    pub fn with_termination(
+        mut self,
+        termination: Box<dyn Termination<Context = C, Objective = O> + Send + Sync>,
+    ) -> Self {
+        self.terminations.push(termination);
         self
     }
 
@@ -230,6 +271,39 @@ where
         self
     }
 
+    /// Sets checkpoint configuration used to save the search state periodically. Default is None.
+    pub fn with_checkpoint(mut self, checkpoint: Option<CheckpointConfig<S>>) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Sets a checkpoint to resume the search from. Its solutions are used as initial population, while
+    /// generation counter and heuristic state are restored. Default is None.
+    pub fn with_resume(mut self, resume: Option<EvolutionCheckpoint<S>>) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// Sets a listener which is notified each time the best known solution is improved. Default is None.
+    pub fn with_progress(mut self, progress: Option<ProgressListener<S>>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Sets heuristic state learned previously, e.g. on a similar problem, to start the search with.
+    /// When the search is resumed from a checkpoint, its heuristic state has a priority. Default is None.
+    pub fn with_heuristic_priors(mut self, priors: Option<HyperHeuristicState>) -> Self {
+        self.heuristic_priors = priors;
+        self
+    }
+
+    /// Sets a handler which receives heuristic state learned by the end of the search, so it can be
+    /// used as priors for the next search. Default is None.
+    pub fn with_heuristic_export(mut self, handler: Option<HyperHeuristicStateHandler>) -> Self {
+        self.heuristic_export = handler;
+        self
+    }
+
     /// Gets termination criterias.
     #[allow(clippy::type_complexity)]
     fn get_termination(
@@ -237,15 +311,18 @@ where
         max_generations: Option<usize>,
         max_time: Option<usize>,
         min_cv: Option<(String, usize, f64, bool, K)>,
+        max_stagnation: Option<(String, usize, Vec<f64>, K)>,
         target_proximity: Option<(Vec<f64>, f64)>,
+        custom_terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>>,
     ) -> Result<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>, GenericError> {
-        let terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> = match (
+        let mut terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> = match (
             max_generations,
             max_time,
             &min_cv,
+            &max_stagnation,
             &target_proximity,
         ) {
-            (None, None, None, None) => {
+            (None, None, None, None, None) => {
                 (logger)("configured to use default max-generations (3000) and max-time (300secs)");
                 vec![Box::new(MaxGeneration::new(3000)), Box::new(MaxTime::new(300.))]
             }
@@ -284,6 +361,22 @@ where
                     terminations.push(variation)
                 }
 
+                if let Some((interval_type, value, tolerance, key)) = max_stagnation.clone() {
+                    (logger)(
+                        format!("configured to use max stagnation {interval_type} with value: {value}, tolerance: {tolerance:?}")
+                            .as_str(),
+                    );
+
+                    let stagnation: Box<dyn Termination<Context = C, Objective = O> + Send + Sync> =
+                        match interval_type.as_str() {
+                            "sample" => Box::new(MaxStagnation::<C, O, S, K>::new_with_sample(value, tolerance, key)),
+                            "period" => Box::new(MaxStagnation::<C, O, S, K>::new_with_period(value, tolerance, key)),
+                            _ => return Err(format!("unknown stagnation interval type: {interval_type}").into()),
+                        };
+
+                    terminations.push(stagnation)
+                }
+
                 if let Some((target_fitness, distance_threshold)) = target_proximity.clone() {
                     (logger)(
                             format!(
@@ -298,23 +391,63 @@ where
             }
         };
 
+        custom_terminations.into_iter().for_each(|termination| {
+            (logger)("configured to use custom termination");
+            terminations.push(termination);
+        });
+
         Ok(Box::new(CompositeTermination::new(terminations)))
     }
 
     /// Builds the evolution config.
     pub fn build(self) -> Result<EvolutionConfig<C, O, S>, GenericError> {
-        let context = self.context.ok_or_else(|| "missing heuristic context".to_string())?;
+        let mut context = self.context.ok_or_else(|| "missing heuristic context".to_string())?;
         let logger = context.environment().logger.clone();
-        let termination =
-            Self::get_termination(&logger, self.max_generations, self.max_time, self.min_cv, self.target_proximity)?;
+        let termination = Self::get_termination(
+            &logger,
+            self.max_generations,
+            self.max_time,
+            self.min_cv,
+            self.max_stagnation,
+            self.target_proximity,
+            self.terminations,
+        )?;
+
+        if self.strategy.is_some() && (self.checkpoint.is_some() || self.resume.is_some()) {
+            return Err("checkpoint and resume are not supported by a custom strategy".into());
+        }
+
+        if self.strategy.is_some() && self.progress.is_some() {
+            return Err("progress listener is not supported by a custom strategy".into());
+        }
+
+        if self.strategy.is_some() && (self.heuristic_priors.is_some() || self.heuristic_export.is_some()) {
+            return Err("heuristic priors and export are not supported by a custom strategy".into());
+        }
+
+        let mut initial = self.initial;
+        let mut processing = self.processing;
+        let resume = self.resume.map(|resume| {
+            (logger)(&format!("resuming the search from generation {}", resume.generation));
+            context.on_resume(resume.generation);
+
+            // NOTE do not build new initial solutions as population is restored from the checkpoint
+            initial.individuals =
+                resume.solutions.into_iter().chain(std::mem::take(&mut initial.individuals)).collect();
+            if !initial.individuals.is_empty() {
+                initial.max_size = initial.individuals.len();
+            }
+
+            resume.heuristic
+        });
 
         Ok(EvolutionConfig {
-            initial: self.initial,
+            initial,
             strategy: if let Some(strategy) = self.strategy {
                 (logger)("configured to use a custom strategy");
                 strategy
             } else {
-                let heuristic = if let Some(heuristic) = self.heuristic {
+                let mut heuristic = if let Some(heuristic) = self.heuristic {
                     (logger)("configured to use custom heuristic");
                     heuristic
                 } else {
@@ -325,11 +458,27 @@ where
                         context.environment(),
                     ))
                 };
-                Box::new(strategies::Iterative::new(heuristic, 1))
+
+                if let Some(priors) = self.heuristic_priors {
+                    (logger)(&format!("configured to use heuristic priors with {} parameters", priors.len()));
+                    heuristic.set_state(&priors);
+                }
+
+                if let Some(state) = resume {
+                    heuristic.set_state(&state);
+                }
+
+                Box::new(
+                    strategies::Iterative::new(heuristic, 1)
+                        .with_checkpoint(self.checkpoint)
+                        .with_progress(self.progress)
+                        .with_state_export(self.heuristic_export)
+                        .with_solution_processing(processing.share_solution_processing()),
+                )
             },
             context,
             termination,
-            processing: self.processing,
+            processing,
         })
     }
 }
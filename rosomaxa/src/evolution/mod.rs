@@ -1,10 +1,17 @@
 //! Contains functionality to run evolution simulation.
 
 use crate::prelude::*;
+use std::sync::Arc;
+
+mod checkpoint;
+pub use self::checkpoint::*;
 
 mod config;
 pub use self::config::*;
 
+mod progress;
+pub use self::progress::*;
+
 mod simulator;
 pub use self::simulator::*;
 
@@ -38,3 +45,40 @@ pub trait HeuristicSolutionProcessing {
     /// Post processes solution.
     fn post_process(&self, solution: Self::Solution) -> Self::Solution;
 }
+
+/// A solution post processing hook.
+pub type SolutionProcessingHook<S> = Box<dyn HeuristicSolutionProcessing<Solution = S> + Send + Sync>;
+
+/// Applies solution post processing hooks in their order. Can be cloned to post process solutions which are
+/// exposed while the search is in progress (e.g. intermediate best known solutions) in the same way as the
+/// final ones.
+pub struct SharedSolutionProcessing<S> {
+    hooks: Arc<Vec<SolutionProcessingHook<S>>>,
+}
+
+impl<S> SharedSolutionProcessing<S> {
+    /// Creates a new instance of `SharedSolutionProcessing`.
+    pub fn new(hooks: Vec<SolutionProcessingHook<S>>) -> Self {
+        Self { hooks: Arc::new(hooks) }
+    }
+}
+
+impl<S> Clone for SharedSolutionProcessing<S> {
+    fn clone(&self) -> Self {
+        Self { hooks: self.hooks.clone() }
+    }
+}
+
+impl<S> Default for SharedSolutionProcessing<S> {
+    fn default() -> Self {
+        Self::new(Vec::default())
+    }
+}
+
+impl<S: HeuristicSolution> HeuristicSolutionProcessing for SharedSolutionProcessing<S> {
+    type Solution = S;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        self.hooks.iter().fold(solution, |solution, hook| hook.post_process(solution))
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/progress_test.rs"]
+mod progress_test;
+
+use crate::evolution::HeuristicSolutionProcessing;
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// Represents an event which is emitted when the best known solution is improved.
+pub struct ProgressEvent<'a, S> {
+    /// A generation number.
+    pub generation: usize,
+    /// Elapsed seconds since algorithm start.
+    pub elapsed: f64,
+    /// A new best known solution.
+    pub solution: &'a S,
+}
+
+/// A function which handles progress events, e.g. writes them to a file.
+pub type ProgressListener<S> = Box<dyn Fn(ProgressEvent<'_, S>) + Send + Sync>;
+
+/// Tracks the best known solution and notifies the listener about its improvements.
+pub struct ProgressTracker<S> {
+    listener: ProgressListener<S>,
+    best_known: Option<S>,
+}
+
+impl<S> ProgressTracker<S>
+where
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `ProgressTracker`.
+    pub fn new(listener: ProgressListener<S>) -> Self {
+        Self { listener, best_known: None }
+    }
+
+    /// Checks whether the best known solution is improved and notifies the listener if it is.
+    /// The listener receives a copy of the solution post processed by the given `processing`.
+    pub fn track<C, O>(&mut self, heuristic_ctx: &C, processing: &dyn HeuristicSolutionProcessing<Solution = S>)
+    where
+        C: HeuristicContext<Objective = O, Solution = S>,
+        O: HeuristicObjective<Solution = S>,
+    {
+        let Some(best) = heuristic_ctx.ranked().next() else {
+            return;
+        };
+
+        let is_improved = match self.best_known.as_ref() {
+            Some(best_known) => heuristic_ctx.objective().total_order(best, best_known) == Ordering::Less,
+            None => true,
+        };
+
+        if is_improved {
+            let statistics = heuristic_ctx.statistics();
+            let solution = processing.post_process(best.deep_copy());
+            (self.listener)(ProgressEvent {
+                generation: statistics.generation,
+                elapsed: statistics.time.elapsed_secs_as_f64(),
+                solution: &solution,
+            });
+
+            self.best_known = Some(best.deep_copy());
+        }
+    }
+}
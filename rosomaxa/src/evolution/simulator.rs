@@ -1,6 +1,6 @@
 use crate::evolution::EvolutionResult;
 use crate::prelude::*;
-use crate::utils::Timer;
+use crate::utils::{with_isolated_random_stream, Timer};
 
 /// An entity which simulates evolution process.
 pub struct EvolutionSimulator<C, O, S>
@@ -30,6 +30,10 @@ where
     /// Runs evolution for given `problem` using evolution `config`.
     /// Returns populations filled with solutions.
     pub fn run(self) -> EvolutionResult<S> {
+        with_isolated_random_stream(|| self.run_evolution())
+    }
+
+    fn run_evolution(self) -> EvolutionResult<S> {
         let mut config = self.config;
 
         let hooks = config.processing;
@@ -0,0 +1,198 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/evolution/strategies/island_test.rs"]
+mod island_test;
+
+use super::*;
+use crate::utils::{parallel_foreach_mut, Timer};
+
+/// A hyper heuristic which can be used by an island.
+pub type IslandHeuristic<C, O, S> = Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S> + Send + Sync>;
+
+/// An island model evolution algorithm: it maintains multiple independent populations (islands) which are
+/// evolved in parallel, possibly with different hyper heuristics, and periodically exchange their best
+/// individuals using a ring topology. The first island uses heuristic context passed to the strategy and
+/// controls termination, its population is also used to return the final result.
+pub struct IslandModel<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    heuristics: Vec<IslandHeuristic<C, O, S>>,
+    contexts: Vec<C>,
+    migration_interval: usize,
+    migration_size: usize,
+    desired_solutions_amount: usize,
+}
+
+impl<C, O, S> IslandModel<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `IslandModel`. The first heuristic is used by the main island, the rest are
+    /// used together with the given contexts by additional islands, so amount of contexts should be one less
+    /// than amount of heuristics. Each `migration_interval` generations `migration_size` best individuals of
+    /// each island are sent to the next one.
+    pub fn new(
+        heuristics: Vec<IslandHeuristic<C, O, S>>,
+        contexts: Vec<C>,
+        migration_interval: usize,
+        migration_size: usize,
+        desired_solutions_amount: usize,
+    ) -> Result<Self, GenericError> {
+        if heuristics.is_empty() || heuristics.len() != contexts.len() + 1 {
+            return Err(format!(
+                "amount of island heuristics ({}) should be one more than amount of contexts ({})",
+                heuristics.len(),
+                contexts.len()
+            )
+            .into());
+        }
+
+        if migration_interval == 0 {
+            return Err("migration interval should be greater than zero".into());
+        }
+
+        Ok(Self { heuristics, contexts, migration_interval, migration_size, desired_solutions_amount })
+    }
+}
+
+impl<C, O, S> EvolutionStrategy for IslandModel<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn run(
+        &mut self,
+        heuristic_ctx: Self::Context,
+        termination: Box<dyn Termination<Context = Self::Context, Objective = Self::Objective>>,
+    ) -> EvolutionResult<Self::Solution> {
+        let mut islands = std::iter::once(heuristic_ctx)
+            .chain(std::mem::take(&mut self.contexts))
+            .zip(self.heuristics.iter_mut())
+            .map(|(context, heuristic)| Island { context, heuristic, migrants: Vec::default() })
+            .collect::<Vec<_>>();
+
+        // NOTE additional islands start from initial solutions of the main one
+        let initial = islands[0].context.ranked().map(|solution| solution.deep_copy()).collect::<Vec<_>>();
+        islands.iter_mut().skip(1).for_each(|island| {
+            initial.iter().for_each(|solution| island.context.on_initial(solution.deep_copy(), Timer::start()))
+        });
+
+        let mut generations_since_migration = 0;
+
+        loop {
+            let is_terminated = termination.is_termination(&mut islands[0].context);
+            let is_quota_reached =
+                islands[0].context.environment().quota.as_ref().is_some_and(|quota| quota.is_reached());
+
+            if is_terminated || is_quota_reached {
+                break;
+            }
+
+            let termination_estimate = termination.estimate(&islands[0].context);
+
+            parallel_foreach_mut(islands.as_mut_slice(), |island| island.run_generation(termination_estimate));
+
+            generations_since_migration += 1;
+            if generations_since_migration == self.migration_interval {
+                generations_since_migration = 0;
+                migrate(islands.as_mut_slice(), self.migration_size);
+            }
+        }
+
+        // NOTE give a chance to report internal state of heuristic
+        (islands[0].context.environment().logger)(&format!("{}", islands[0].heuristic));
+
+        let mut islands = islands.into_iter();
+        let main = islands.next().expect("no main island");
+        let others = islands
+            .flat_map(|island| {
+                island
+                    .context
+                    .ranked()
+                    .take(self.desired_solutions_amount)
+                    .map(|solution| solution.deep_copy())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let (mut population, telemetry_metrics) = main.context.on_result()?;
+        population.add_all(others);
+
+        let solutions =
+            population.ranked().map(|solution| solution.deep_copy()).take(self.desired_solutions_amount).collect();
+
+        Ok((solutions, telemetry_metrics))
+    }
+}
+
+struct Island<'a, C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    context: C,
+    heuristic: &'a mut IslandHeuristic<C, O, S>,
+    migrants: Vec<S>,
+}
+
+impl<C, O, S> Island<'_, C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn run_generation(&mut self, termination_estimate: f64) {
+        let generation_time = Timer::start();
+        let heuristic_ctx = &self.context;
+
+        let parents = heuristic_ctx.selected().collect::<Vec<_>>();
+
+        let diverse_offspring = if heuristic_ctx.selection_phase() == SelectionPhase::Exploitation {
+            Vec::default()
+        } else {
+            self.heuristic.diversify_many(heuristic_ctx, parents.clone())
+        };
+
+        let search_offspring = self.heuristic.search_many(heuristic_ctx, parents);
+
+        let offspring = search_offspring
+            .into_iter()
+            .chain(diverse_offspring)
+            .chain(std::mem::take(&mut self.migrants))
+            .collect::<Vec<_>>();
+
+        self.context.on_generation(offspring, termination_estimate, generation_time);
+    }
+}
+
+/// Sends copies of the best individuals of each island to the next island in the ring.
+fn migrate<C, O, S>(islands: &mut [Island<'_, C, O, S>], migration_size: usize)
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    if islands.len() < 2 {
+        return;
+    }
+
+    let emigrants = islands
+        .iter()
+        .map(|island| {
+            island.context.ranked().take(migration_size).map(|solution| solution.deep_copy()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let size = islands.len();
+    emigrants.into_iter().enumerate().for_each(|(idx, emigrants)| islands[(idx + 1) % size].migrants.extend(emigrants));
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::hyper::HyperHeuristicStateHandler;
 use crate::utils::Timer;
 
 /// A simple evolution algorithm which maintains a single population and improves it iteratively.
@@ -10,6 +11,10 @@ where
 {
     desired_solutions_amount: usize,
     heuristic: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+    checkpoint: Option<CheckpointConfig<S>>,
+    progress: Option<ProgressTracker<S>>,
+    state_export: Option<HyperHeuristicStateHandler>,
+    solution_processing: SharedSolutionProcessing<S>,
 }
 
 impl<C, O, S> Iterative<C, O, S>
@@ -23,7 +28,40 @@ where
         heuristic: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
         desired_solutions_amount: usize,
     ) -> Self {
-        Self { heuristic, desired_solutions_amount }
+        Self {
+            heuristic,
+            desired_solutions_amount,
+            checkpoint: None,
+            progress: None,
+            state_export: None,
+            solution_processing: SharedSolutionProcessing::default(),
+        }
+    }
+
+    /// Sets checkpoint configuration used to save the search state periodically.
+    pub fn with_checkpoint(mut self, checkpoint: Option<CheckpointConfig<S>>) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Sets a listener which is notified each time the best known solution is improved.
+    pub fn with_progress(mut self, listener: Option<ProgressListener<S>>) -> Self {
+        self.progress = listener.map(ProgressTracker::new);
+        self
+    }
+
+    /// Sets a handler which receives heuristic state learned by the end of the search.
+    /// Errors are reported using environment's logger.
+    pub fn with_state_export(mut self, handler: Option<HyperHeuristicStateHandler>) -> Self {
+        self.state_export = handler;
+        self
+    }
+
+    /// Sets solution post processing which is applied to solutions exposed while the search is in progress:
+    /// progress events and checkpoints.
+    pub fn with_solution_processing(mut self, solution_processing: SharedSolutionProcessing<S>) -> Self {
+        self.solution_processing = solution_processing;
+        self
     }
 }
 
@@ -44,6 +82,7 @@ where
     ) -> EvolutionResult<Self::Solution> {
         let mut heuristic_ctx = heuristic_ctx;
         let heuristic = &mut self.heuristic;
+        let mut generations_since_checkpoint = 0;
 
         loop {
             let is_terminated = termination.is_termination(&mut heuristic_ctx);
@@ -70,11 +109,29 @@ where
             let termination_estimate = termination.estimate(&heuristic_ctx);
 
             heuristic_ctx.on_generation(offspring, termination_estimate, generation_time);
+
+            if let Some(progress) = self.progress.as_mut() {
+                progress.track(&heuristic_ctx, &self.solution_processing);
+            }
+
+            if let Some(checkpoint) = self.checkpoint.as_ref() {
+                generations_since_checkpoint += 1;
+                if generations_since_checkpoint == checkpoint.interval() {
+                    generations_since_checkpoint = 0;
+                    checkpoint.save(&heuristic_ctx, heuristic.as_ref(), &self.solution_processing);
+                }
+            }
         }
 
         // NOTE give a chance to report internal state of heuristic
         (heuristic_ctx.environment().logger)(&format!("{heuristic}"));
 
+        if let Some(handler) = self.state_export.as_ref() {
+            if let Err(err) = handler(heuristic.get_state()) {
+                (heuristic_ctx.environment().logger)(&format!("cannot export heuristic state: {err}"));
+            }
+        }
+
         let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
 
         let solutions =
@@ -2,6 +2,9 @@
 
 use super::*;
 
+mod island;
+pub use self::island::{IslandHeuristic, IslandModel};
+
 mod iterative;
 pub use self::iterative::Iterative;
 
@@ -103,6 +103,7 @@ where
     improvement_tracker: ImprovementTracker,
     speed_tracker: SpeedTracker,
     next_generation: Option<usize>,
+    start_generation: usize,
     _marker: (PhantomData<O>, PhantomData<S>),
 }
 
@@ -121,6 +122,7 @@ where
             improvement_tracker: ImprovementTracker::new(1000),
             speed_tracker: SpeedTracker::default(),
             next_generation: None,
+            start_generation: 0,
             _marker: Default::default(),
         }
     }
@@ -143,6 +145,13 @@ where
         };
     }
 
+    /// Continues generation counting after the given (last completed) one, e.g. when the search is resumed.
+    pub fn on_resume(&mut self, generation: usize) {
+        self.next_generation = Some(generation + 1);
+        self.start_generation = generation + 1;
+        self.statistics.generation = generation;
+    }
+
     /// Reports generation statistics.
     pub fn on_generation(
         &mut self,
@@ -154,8 +163,9 @@ where
         let generation = self.next_generation.unwrap_or(0);
 
         self.metrics.generations = generation;
-        self.improvement_tracker.track(generation, is_improved);
-        self.speed_tracker.track(generation, &self.time, termination_estimate);
+        // NOTE trackers estimate ratios within the current run only
+        self.improvement_tracker.track(generation - self.start_generation, is_improved);
+        self.speed_tracker.track(generation - self.start_generation, &self.time, termination_estimate);
         self.next_generation = Some(generation + 1);
 
         self.statistics = HeuristicStatistics {
@@ -292,6 +302,12 @@ where
         }
     }
 
+    /// Sets whether statistics should not depend on time measurements, e.g. when runs should be repeatable.
+    /// In this case, the search speed is always reported as moderate without a generation duration median.
+    pub fn set_repeatable(&mut self, is_repeatable: bool) {
+        self.speed_tracker.is_repeatable = is_repeatable;
+    }
+
     /// Returns current statistics.
     pub fn get_statistics(&self) -> &HeuristicStatistics {
         &self.statistics
@@ -368,6 +384,7 @@ struct SpeedTracker {
     last_time: f64,
     median: RemedianUsize,
     speed: HeuristicSpeed,
+    is_repeatable: bool,
 }
 
 impl Default for SpeedTracker {
@@ -378,6 +395,7 @@ impl Default for SpeedTracker {
             last_time: 0.,
             median: RemedianUsize::new(11, |a, b| a.cmp(b)),
             speed: HeuristicSpeed::Unknown,
+            is_repeatable: false,
         }
     }
 }
@@ -390,12 +408,15 @@ impl SpeedTracker {
             self.initial_time = elapsed;
             self.last_time = elapsed;
         } else {
-            let duration = ((elapsed - self.last_time) / 1000.).round() as usize;
-            self.median.add_observation(duration);
+            // NOTE time measurements are ignored when run should be repeatable
+            if !self.is_repeatable {
+                let duration = ((elapsed - self.last_time) / 1000.).round() as usize;
+                self.median.add_observation(duration);
+            }
             self.last_time = elapsed;
 
             // average gen/sec speed excluding initial solutions
-            let average = if elapsed > self.initial_time {
+            let average = if elapsed > self.initial_time && !self.is_repeatable {
                 generation as f64 / ((elapsed - self.initial_time) / 1_000_000.)
             } else {
                 1000.
@@ -104,6 +104,14 @@ impl HeuristicContext for VectorContext {
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
         self.inner_context.on_result()
     }
+
+    fn snapshot<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Solution> + 'a> {
+        self.inner_context.snapshot()
+    }
+
+    fn on_resume(&mut self, generation: usize) {
+        self.inner_context.on_resume(generation)
+    }
 }
 
 impl Stateful for VectorContext {
@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path = "../../tests/unit/hyper/acceptance_test.rs"]
+mod acceptance_test;
+
+use crate::utils::Random;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Keeps information needed to decide whether a candidate solution is accepted.
+pub struct AcceptanceContext<'a> {
+    /// Fitness of the best known solution.
+    pub best: &'a [f64],
+    /// Fitness of the current solution.
+    pub current: &'a [f64],
+    /// Fitness of the candidate solution.
+    pub candidate: &'a [f64],
+    /// A progress till algorithm's termination in range `[0, 1]`.
+    pub termination_estimate: f64,
+}
+
+/// Specifies an acceptance criterion which decides whether a candidate solution replaces the current one.
+pub trait AcceptanceCriterion: Send + Sync {
+    /// Returns true if the candidate solution should replace the current one.
+    fn accept(&mut self, context: &AcceptanceContext) -> bool;
+}
+
+/// Accepts worse solutions with a probability which decreases with temperature. The temperature
+/// decreases exponentially from initial to final value depending on the search progress.
+pub struct SimulatedAnnealing {
+    initial_temperature: f64,
+    final_temperature: f64,
+    random: Arc<dyn Random + Send + Sync>,
+}
+
+impl SimulatedAnnealing {
+    /// Creates a new instance of `SimulatedAnnealing`. Temperatures are specified in terms of relative
+    /// fitness difference: e.g. with temperature `0.01` a solution which is 1% worse is accepted with
+    /// probability `1/e`.
+    pub fn new(initial_temperature: f64, final_temperature: f64, random: Arc<dyn Random + Send + Sync>) -> Self {
+        assert!(initial_temperature > 0. && final_temperature > 0.);
+        assert!(initial_temperature >= final_temperature);
+
+        Self { initial_temperature, final_temperature, random }
+    }
+}
+
+impl AcceptanceCriterion for SimulatedAnnealing {
+    fn accept(&mut self, context: &AcceptanceContext) -> bool {
+        let delta = get_relative_delta(context.candidate, context.current);
+        if delta <= 0. {
+            return true;
+        }
+
+        let ratio = self.final_temperature / self.initial_temperature;
+        let temperature = self.initial_temperature * ratio.powf(context.termination_estimate.clamp(0., 1.));
+
+        self.random.is_hit((-delta / temperature).exp())
+    }
+}
+
+/// Accepts a candidate solution if it is not worse than the best known one by more than the threshold.
+/// The threshold decreases linearly to zero depending on the search progress.
+pub struct RecordToRecord {
+    threshold: f64,
+}
+
+impl RecordToRecord {
+    /// Creates a new instance of `RecordToRecord` with given initial relative threshold.
+    pub fn new(threshold: f64) -> Self {
+        assert!(threshold >= 0.);
+
+        Self { threshold }
+    }
+}
+
+impl AcceptanceCriterion for RecordToRecord {
+    fn accept(&mut self, context: &AcceptanceContext) -> bool {
+        let threshold = self.threshold * (1. - context.termination_estimate.clamp(0., 1.));
+
+        get_relative_delta(context.candidate, context.best) <= threshold
+    }
+}
+
+/// Late acceptance hill climbing: accepts a candidate solution if it is not worse than the current one
+/// or than the current solution from some iterations ago.
+pub struct LateAcceptance {
+    length: usize,
+    history: VecDeque<Vec<f64>>,
+}
+
+impl LateAcceptance {
+    /// Creates a new instance of `LateAcceptance` with given history length.
+    pub fn new(length: usize) -> Self {
+        assert!(length > 0);
+
+        Self { length, history: VecDeque::with_capacity(length) }
+    }
+}
+
+impl AcceptanceCriterion for LateAcceptance {
+    fn accept(&mut self, context: &AcceptanceContext) -> bool {
+        let is_accepted = get_relative_delta(context.candidate, context.current) <= 0.
+            || self.history.front().is_some_and(|late| get_relative_delta(context.candidate, late) <= 0.);
+
+        let current = if is_accepted { context.candidate } else { context.current };
+        if self.history.len() == self.length {
+            self.history.pop_front();
+        }
+        self.history.push_back(current.to_vec());
+
+        is_accepted
+    }
+}
+
+/// Returns a relative difference between fitness values of two solutions using the first fitness
+/// component which differs. Positive value means that the first solution is worse.
+pub fn get_relative_delta(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(&a, &b)| (a - b) / b.abs().max(1.))
+        .find(|delta| delta.abs() > f64::EPSILON)
+        .unwrap_or_default()
+}
@@ -0,0 +1,228 @@
+#[cfg(test)]
+#[path = "../../tests/unit/hyper/adaptive_selective_test.rs"]
+mod adaptive_selective_test;
+
+use super::*;
+use std::cmp::Ordering;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+/// A score given to the operator when it finds a new best known solution.
+const NEW_BEST_SCORE: f64 = 33.;
+/// A score given to the operator when it improves the current solution.
+const IMPROVEMENT_SCORE: f64 = 9.;
+/// A score given to the operator when its solution is accepted.
+const ACCEPTED_SCORE: f64 = 13.;
+/// A minimum weight of the operator which keeps a chance to select it.
+const MIN_WEIGHT: f64 = 1E-2;
+
+/// An adaptive large neighbourhood search (ALNS) hyper heuristic: it keeps a current solution and selects
+/// operators using roulette wheel with weights which are periodically updated from operator scores
+/// collected within a segment of iterations. Whether a new solution replaces the current one is decided by
+/// a pluggable acceptance criterion. The idea is described in [`Ropke & Pisinger (2006)`].
+///
+/// [`Ropke & Pisinger (2006)`]: https://doi.org/10.1287/trsc.1050.0135
+pub struct AdaptiveSelective<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    operators: Vec<AdaptiveOperator<C, O, S>>,
+    diversify_operators: HeuristicDiversifyOperators<C, O, S>,
+    acceptance: Box<dyn AcceptanceCriterion>,
+    current: Option<S>,
+    segment_size: usize,
+    reaction_factor: f64,
+    segment_iterations: usize,
+}
+
+impl<C, O, S> HyperHeuristic for AdaptiveSelective<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn search(&mut self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        self.search_many(heuristic_ctx, vec![solution])
+    }
+
+    fn search_many(&mut self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        let current = match (self.current.take(), solutions.first()) {
+            (Some(current), _) => current,
+            (None, Some(solution)) => solution.deep_copy(),
+            (None, None) => return Vec::default(),
+        };
+
+        let random = heuristic_ctx.environment().random.as_ref();
+        let operator_indices = (0..solutions.len()).map(|_| self.select_operator(random)).collect::<Vec<_>>();
+
+        let candidates = parallel_into_collect(operator_indices.into_iter().enumerate().collect(), |(idx, op_idx)| {
+            heuristic_ctx
+                .environment()
+                .parallelism
+                .thread_pool_execute(idx, || (op_idx, self.operators[op_idx].operator.search(heuristic_ctx, &current)))
+        });
+
+        let current = candidates.iter().fold(current, |current, (op_idx, candidate)| {
+            let (is_accepted, score) = self.evaluate(heuristic_ctx, &current, candidate);
+
+            let operator = &mut self.operators[*op_idx];
+            operator.score += score;
+            operator.uses += 1;
+
+            self.segment_iterations += 1;
+            if self.segment_iterations == self.segment_size {
+                self.segment_iterations = 0;
+                self.update_weights();
+            }
+
+            if is_accepted {
+                candidate.deep_copy()
+            } else {
+                current
+            }
+        });
+
+        self.current = Some(current);
+
+        candidates.into_iter().map(|(_, candidate)| candidate).collect()
+    }
+
+    fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        let probability = get_diversify_probability(heuristic_ctx);
+        if heuristic_ctx.environment().random.is_hit(probability) {
+            diversify_solution(heuristic_ctx, solution, self.diversify_operators.as_slice())
+        } else {
+            Vec::default()
+        }
+    }
+
+    fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        diversify_solutions(heuristic_ctx, solutions, self.diversify_operators.as_slice())
+    }
+}
+
+impl<C, O, S> AdaptiveSelective<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `AdaptiveSelective` heuristic. Initial operator weights are taken from
+    /// search operators definition. `segment_size` specifies amount of iterations after which weights are
+    /// updated, `reaction_factor` controls how quickly weights react to operator scores.
+    pub fn new(
+        search_operators: HeuristicSearchOperators<C, O, S>,
+        diversify_operators: HeuristicDiversifyOperators<C, O, S>,
+        acceptance: Box<dyn AcceptanceCriterion>,
+        segment_size: usize,
+        reaction_factor: f64,
+    ) -> Self {
+        assert!(!search_operators.is_empty());
+        assert!(!diversify_operators.is_empty());
+        assert!(segment_size > 0);
+        assert!((0. ..=1.).contains(&reaction_factor));
+
+        let operators = search_operators
+            .into_iter()
+            .map(|(operator, _, weight)| AdaptiveOperator {
+                operator,
+                weight: weight.max(MIN_WEIGHT),
+                score: 0.,
+                uses: 0,
+            })
+            .collect();
+
+        Self {
+            operators,
+            diversify_operators,
+            acceptance,
+            current: None,
+            segment_size,
+            reaction_factor,
+            segment_iterations: 0,
+        }
+    }
+
+    /// Selects operator using roulette wheel.
+    fn select_operator(&self, random: &(dyn Random + Send + Sync)) -> usize {
+        let total = self.operators.iter().map(|operator| operator.weight).sum::<f64>();
+        let mut value = random.uniform_real(0., total);
+
+        self.operators
+            .iter()
+            .position(|operator| {
+                value -= operator.weight;
+                value <= 0.
+            })
+            .unwrap_or(self.operators.len() - 1)
+    }
+
+    /// Decides whether candidate solution is accepted and returns a score of its operator.
+    fn evaluate(&mut self, heuristic_ctx: &C, current: &S, candidate: &S) -> (bool, f64) {
+        let objective = heuristic_ctx.objective();
+        let best = heuristic_ctx.ranked().next();
+
+        let is_improvement = objective.total_order(candidate, current) == Ordering::Less;
+        let is_new_best =
+            best.map(|best| objective.total_order(candidate, best) == Ordering::Less).unwrap_or(is_improvement);
+
+        let current_fitness = current.fitness().collect::<Vec<_>>();
+        let candidate_fitness = candidate.fitness().collect::<Vec<_>>();
+        let best_fitness =
+            best.map(|best| best.fitness().collect::<Vec<_>>()).unwrap_or_else(|| current_fitness.clone());
+
+        let is_accepted = self.acceptance.accept(&AcceptanceContext {
+            best: best_fitness.as_slice(),
+            current: current_fitness.as_slice(),
+            candidate: candidate_fitness.as_slice(),
+            termination_estimate: heuristic_ctx.statistics().termination_estimate,
+        }) || is_improvement;
+
+        let score = match (is_new_best, is_improvement, is_accepted) {
+            (true, _, _) => NEW_BEST_SCORE,
+            (_, true, _) => IMPROVEMENT_SCORE,
+            (_, _, true) => ACCEPTED_SCORE,
+            _ => 0.,
+        };
+
+        (is_accepted, score)
+    }
+
+    /// Updates operator weights using scores collected within the segment and resets the scores.
+    fn update_weights(&mut self) {
+        let reaction_factor = self.reaction_factor;
+
+        self.operators.iter_mut().filter(|operator| operator.uses > 0).for_each(|operator| {
+            let performance = operator.score / operator.uses as f64;
+            operator.weight =
+                (operator.weight * (1. - reaction_factor) + reaction_factor * performance).max(MIN_WEIGHT);
+            operator.score = 0.;
+            operator.uses = 0;
+        });
+    }
+}
+
+impl<C, O, S> Display for AdaptiveSelective<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn fmt(&self, _: &mut Formatter<'_>) -> std::fmt::Result {
+        // NOTE don't do anything at the moment
+        Ok(())
+    }
+}
+
+struct AdaptiveOperator<C, O, S> {
+    operator: Arc<dyn HeuristicSearchOperator<Context = C, Objective = O, Solution = S> + Send + Sync>,
+    weight: f64,
+    score: f64,
+    uses: usize,
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+#[path = "../../tests/unit/hyper/crossover_test.rs"]
+mod crossover_test;
+
+use super::*;
+
+/// A search operator which recombines a given solution with another individual from the population
+/// using a crossover operator. As it implements `HeuristicSearchOperator`, it can be used by any of
+/// hyper heuristics (e.g. `StaticSelective` or `DynamicSelective`) as other search operators.
+pub struct CrossoverSearch<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    crossover: Arc<dyn HeuristicCrossoverOperator<Context = C, Objective = O, Solution = S> + Send + Sync>,
+}
+
+impl<C, O, S> CrossoverSearch<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `CrossoverSearch`.
+    pub fn new(
+        crossover: Arc<dyn HeuristicCrossoverOperator<Context = C, Objective = O, Solution = S> + Send + Sync>,
+    ) -> Self {
+        Self { crossover }
+    }
+}
+
+impl<C, O, S> HeuristicSearchOperator for CrossoverSearch<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        // NOTE the second parent is selected uniformly from the population, excluding given solution
+        let candidates = heuristic_ctx.ranked().filter(|other| !std::ptr::eq(*other, solution)).collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            return solution.deep_copy();
+        }
+
+        let random = heuristic_ctx.environment().random.as_ref();
+        let other = candidates[random.uniform_int(0, candidates.len() as i32 - 1) as usize];
+
+        self.crossover.crossover(heuristic_ctx, solution, other)
+    }
+}
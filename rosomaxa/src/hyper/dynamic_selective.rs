@@ -84,6 +84,14 @@ where
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
         diversify_solutions(heuristic_ctx, solutions, self.diversify_operators.as_slice())
     }
+
+    fn get_state(&self) -> HyperHeuristicState {
+        self.agent.get_state()
+    }
+
+    fn set_state(&mut self, state: &HyperHeuristicState) {
+        self.agent.set_state(state)
+    }
 }
 
 impl<C, O, S> DynamicSelective<C, O, S>
@@ -156,7 +164,10 @@ where
             Timer::measure_duration(|| self.operator.search(context.heuristic_ctx, context.solution));
 
         let is_new_best = compare_to_best(context.heuristic_ctx, &new_solution) == Ordering::Less;
-        let duration = duration.as_millis() as usize;
+        // NOTE ignore duration when run should be repeatable: reward should not depend on time measurements.
+        //      As a result, slow operators are not penalized in repeatable runs, see `estimate_reward_perf_multiplier`
+        let duration =
+            if context.heuristic_ctx.environment().random.is_repeatable() { 0 } else { duration.as_millis() as usize };
 
         let base_reward = estimate_distance_reward(context.heuristic_ctx, context.solution, &new_solution);
         let reward_multiplier = estimate_reward_perf_multiplier(&context, duration, is_new_best);
@@ -261,6 +272,38 @@ where
         self.tracker.observe_sample(generation, feedback.sample.clone())
     }
 
+    /// Returns learned slot machine parameters keyed by search state and operator name.
+    pub fn get_state(&self) -> HyperHeuristicState {
+        let mut state = self
+            .slot_machines
+            .iter()
+            .flat_map(|(search_state, slots)| {
+                slots.iter().map(move |(slot, name)| {
+                    let (alpha, beta, mu, v, n) = slot.get_params();
+                    (format!("{search_state}/{name}"), vec![alpha, beta, mu, v, n as f64])
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE keep order stable as slot machines are stored in hash map
+        state.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        state
+    }
+
+    /// Restores learned slot machine parameters.
+    pub fn set_state(&mut self, state: &HyperHeuristicState) {
+        let state = state.iter().map(|(key, values)| (key.as_str(), values.as_slice())).collect::<HashMap<_, _>>();
+
+        self.slot_machines.iter_mut().for_each(|(search_state, slots)| {
+            slots.iter_mut().for_each(|(slot, name)| {
+                if let Some(&&[alpha, beta, mu, v, n]) = state.get(format!("{search_state}/{name}").as_str()) {
+                    slot.set_params((alpha, beta, mu, v, n as usize));
+                }
+            })
+        });
+    }
+
     /// Updates statistics about heuristic internal parameters.
     pub fn save_params(&mut self, generation: usize) {
         if !self.tracker.telemetry_enabled() {
@@ -1,5 +1,14 @@
 //! This module contains a hyper-heuristic logic.
 
+mod acceptance;
+pub use self::acceptance::*;
+
+mod adaptive_selective;
+pub use self::adaptive_selective::*;
+
+mod crossover;
+pub use self::crossover::*;
+
 mod dynamic_selective;
 pub use self::dynamic_selective::*;
 
@@ -25,6 +34,24 @@ pub trait HeuristicSearchOperator {
     fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution;
 }
 
+/// A heuristic operator which recombines two parent solutions into a new one.
+pub trait HeuristicCrossoverOperator {
+    /// A heuristic context type.
+    type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
+    /// A heuristic objective type.
+    type Objective: HeuristicObjective<Solution = Self::Solution>;
+    /// A heuristic solution type.
+    type Solution: HeuristicSolution;
+
+    /// Creates an offspring from two parents. The first parent is used as a base for the offspring.
+    fn crossover(
+        &self,
+        heuristic_ctx: &Self::Context,
+        first: &Self::Solution,
+        second: &Self::Solution,
+    ) -> Self::Solution;
+}
+
 /// A heuristic operator which is supposed to diversify passed solution.
 pub trait HeuristicDiversifyOperator {
     /// A heuristic context type.
@@ -38,6 +65,14 @@ pub trait HeuristicDiversifyOperator {
     fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution>;
 }
 
+/// Represents an internal state of hyper heuristic as a list of named parameter values. It can be used
+/// to save learned state and restore it later, e.g. to resume the search.
+pub type HyperHeuristicState = Vec<(String, Vec<f64>)>;
+
+/// A function which handles hyper heuristic state learned by the end of the search, e.g. saves it
+/// to be used as priors for the next search of a similar problem.
+pub type HyperHeuristicStateHandler = Box<dyn Fn(HyperHeuristicState) -> Result<(), GenericError> + Send + Sync>;
+
 /// Represents a hyper heuristic functionality.
 pub trait HyperHeuristic: Display {
     /// A heuristic context type.
@@ -62,6 +97,16 @@ pub trait HyperHeuristic: Display {
     /// As the `diversify` method requires a mutable reference, implementations of `diversify_many` is
     /// supposed to run its logic in parallel for each solution.
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution>;
+
+    /// Returns internal state which can be used to restore heuristic later.
+    /// Default implementation has no state.
+    fn get_state(&self) -> HyperHeuristicState {
+        HyperHeuristicState::default()
+    }
+
+    /// Restores internal state returned previously by `get_state`. Unknown entries are ignored.
+    /// Default implementation does nothing.
+    fn set_state(&mut self, _state: &HyperHeuristicState) {}
 }
 
 /// Gets probability to run diversify search.
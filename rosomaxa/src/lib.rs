@@ -112,6 +112,17 @@ pub trait HeuristicContext: Send + Sync {
 
     /// Returns final population and telemetry metrics
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution>;
+
+    /// Returns solutions which can be used to restore the search later.
+    /// Default implementation returns ranked solutions.
+    fn snapshot<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Solution> + 'a> {
+        self.ranked()
+    }
+
+    /// Restores search progress, such as generation counter, when the search is resumed from the given
+    /// (last completed) generation.
+    /// Default implementation does nothing.
+    fn on_resume(&mut self, _generation: usize) {}
 }
 
 /// A refinement statistics to track evolution progress.
@@ -173,7 +184,9 @@ where
         telemetry_mode: TelemetryMode,
         environment: Arc<Environment>,
     ) -> Self {
-        let telemetry = Telemetry::new(telemetry_mode);
+        let mut telemetry = Telemetry::new(telemetry_mode);
+        telemetry.set_repeatable(environment.random.is_repeatable());
+
         Self { objective, population, telemetry, environment }
     }
 
@@ -233,6 +246,14 @@ where
 
         Ok((self.population, telemetry.take_metrics()))
     }
+
+    fn snapshot<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Solution> + 'a> {
+        self.population.snapshot()
+    }
+
+    fn on_resume(&mut self, generation: usize) {
+        self.telemetry.on_resume(generation);
+    }
 }
 
 /// Defines instant refinement speed type.
@@ -55,6 +55,12 @@ pub trait HeuristicPopulation: Display + Send + Sync {
     /// Returns all individuals in arbitrary order.
     fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a>;
 
+    /// Returns individuals which represent population state and can be used to restore it later,
+    /// e.g. to resume the search. Default implementation returns ranked individuals.
+    fn snapshot<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        self.ranked()
+    }
+
     /// Returns population size.
     fn size(&self) -> usize;
 
@@ -0,0 +1,131 @@
+#[cfg(test)]
+#[path = "../../tests/unit/termination/max_stagnation_test.rs"]
+mod max_stagnation_test;
+
+use super::*;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A termination criteria which stops the search when the best known fitness is not improved within
+/// given amount of generations or time. Fitness changes which are within relative tolerance specified
+/// per objective are not considered as improvement.
+pub struct MaxStagnation<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    interval_type: IntervalType,
+    tolerance: Vec<f64>,
+    key: K,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+enum IntervalType {
+    Sample(usize),
+    Period(u128),
+}
+
+/// Keeps the last improved fitness with generation and time when it was found.
+struct StagnationState {
+    fitness: Vec<f64>,
+    generation: usize,
+    time: u128,
+}
+
+impl<C, O, S, K> MaxStagnation<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    /// Creates a new instance of `MaxStagnation` which tracks amount of generations without improvement.
+    /// If tolerance is specified for less objectives than used, the last value is applied to the rest.
+    pub fn new_with_sample(generations: usize, tolerance: Vec<f64>, key: K) -> Self {
+        assert_ne!(generations, 0);
+        Self::new(IntervalType::Sample(generations), tolerance, key)
+    }
+
+    /// Creates a new instance of `MaxStagnation` which tracks time (in seconds) without improvement.
+    /// If tolerance is specified for less objectives than used, the last value is applied to the rest.
+    pub fn new_with_period(period: usize, tolerance: Vec<f64>, key: K) -> Self {
+        assert_ne!(period, 0);
+        Self::new(IntervalType::Period(period as u128 * 1000), tolerance, key)
+    }
+
+    fn new(interval_type: IntervalType, tolerance: Vec<f64>, key: K) -> Self {
+        Self { interval_type, tolerance, key, _marker: (Default::default(), Default::default(), Default::default()) }
+    }
+
+    fn update_and_check(&self, heuristic_ctx: &mut C, fitness: Vec<f64>) -> bool {
+        let generation = heuristic_ctx.statistics().generation;
+        let time = heuristic_ctx.statistics().time.elapsed_millis();
+
+        self.update_and_check_at(heuristic_ctx, fitness, generation, time)
+    }
+
+    /// Updates stagnation state using given generation and elapsed time (in milliseconds).
+    fn update_and_check_at(&self, heuristic_ctx: &mut C, fitness: Vec<f64>, generation: usize, time: u128) -> bool {
+        let state = heuristic_ctx.state_mut::<StagnationState, _>(self.key.clone(), || StagnationState {
+            fitness: fitness.clone(),
+            generation,
+            time,
+        });
+
+        if self.is_improvement(state.fitness.as_slice(), fitness.as_slice()) {
+            *state = StagnationState { fitness, generation, time };
+            return false;
+        }
+
+        match &self.interval_type {
+            IntervalType::Sample(sample) => generation.saturating_sub(state.generation) >= *sample,
+            IntervalType::Period(period) => time.saturating_sub(state.time) >= *period,
+        }
+    }
+
+    fn is_improvement(&self, best: &[f64], fitness: &[f64]) -> bool {
+        // NOTE objectives are ordered by their priority, so they are compared lexicographically
+        best.iter()
+            .zip(fitness.iter())
+            .enumerate()
+            .find_map(|(idx, (&best, &value))| {
+                let tolerance = self.tolerance.get(idx).or(self.tolerance.last()).copied().unwrap_or_default();
+                let threshold = best.abs() * tolerance;
+
+                if value < best - threshold {
+                    Some(true)
+                } else if value > best + threshold {
+                    Some(false)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(false)
+    }
+}
+
+impl<C, O, S, K> Termination for MaxStagnation<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    type Context = C;
+    type Objective = O;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        let fitness = heuristic_ctx.ranked().next().map(|best| best.fitness().collect::<Vec<_>>());
+
+        match fitness {
+            Some(fitness) => self.update_and_check(heuristic_ctx, fitness),
+            None => false,
+        }
+    }
+
+    fn estimate(&self, _: &Self::Context) -> f64 {
+        0.
+    }
+}
@@ -30,6 +30,12 @@ pub use self::max_time::MaxTime;
 mod target_proximity;
 pub use self::target_proximity::TargetProximity;
 
+mod max_stagnation;
+pub use self::max_stagnation::MaxStagnation;
+
+mod target_cost;
+pub use self::target_cost::{SolutionCostFn, TargetCost};
+
 /// A trait which encapsulates multiple termination criteria.
 pub struct CompositeTermination<C, O, S>
 where
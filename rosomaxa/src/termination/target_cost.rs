@@ -0,0 +1,57 @@
+#[cfg(test)]
+#[path = "../../tests/unit/termination/target_cost_test.rs"]
+mod target_cost_test;
+
+use super::*;
+use std::marker::PhantomData;
+
+/// Specifies a function which returns a cost of the solution or `None` if the cost cannot be compared
+/// with the target, e.g. when the solution is not feasible.
+pub type SolutionCostFn<S> = Box<dyn Fn(&S) -> Option<f64> + Send + Sync>;
+
+/// Provides way to stop algorithm when the best known solution reaches the target cost.
+pub struct TargetCost<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    target_cost: f64,
+    cost_fn: SolutionCostFn<S>,
+    _marker: (PhantomData<C>, PhantomData<O>),
+}
+
+impl<C, O, S> TargetCost<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `TargetCost`.
+    pub fn new(target_cost: f64, cost_fn: SolutionCostFn<S>) -> Self {
+        Self { target_cost, cost_fn, _marker: (Default::default(), Default::default()) }
+    }
+}
+
+impl<C, O, S> Termination for TargetCost<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        // NOTE ignore pareto front, use the first solution only for comparison
+        heuristic_ctx
+            .ranked()
+            .next()
+            .and_then(|solution| (self.cost_fn)(solution))
+            .is_some_and(|cost| cost <= self.target_cost)
+    }
+
+    fn estimate(&self, _: &Self::Context) -> f64 {
+        0.
+    }
+}
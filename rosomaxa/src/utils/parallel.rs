@@ -12,6 +12,7 @@ pub use self::actual::ThreadPool;
 mod actual {
     extern crate rayon;
     use self::rayon::{ThreadPool as RayonThreadPool, ThreadPoolBuilder};
+    use crate::utils::{get_child_stream_key, get_task_stream_key, with_random_stream, Random};
     use rayon::prelude::*;
 
     /// Represents a thread pool wrapper.
@@ -27,13 +28,14 @@ mod actual {
             }
         }
 
-        /// Executes given operation on thread pool.
+        /// Executes given operation on thread pool within its own random stream.
         pub fn execute<OP, R>(&self, op: OP) -> R
         where
             OP: FnOnce() -> R + Send,
             R: Send,
         {
-            self.inner.install(op)
+            let key = get_child_stream_key();
+            self.inner.install(move || with_random_stream(key, op))
         }
     }
 
@@ -44,7 +46,12 @@ mod actual {
         F: Fn(&T) -> R + Sync + Send,
         R: Send,
     {
-        source.par_iter().map(map_op).collect()
+        let key = get_child_stream_key();
+        source
+            .par_iter()
+            .enumerate()
+            .map(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || map_op(item)))
+            .collect()
     }
 
     /// Maps collection and collects results into vector in parallel.
@@ -54,11 +61,23 @@ mod actual {
         F: Fn(T) -> R + Sync + Send,
         R: Send,
     {
-        source.into_par_iter().map(map_op).collect()
+        let key = get_child_stream_key();
+        source
+            .into_par_iter()
+            .enumerate()
+            .map(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || map_op(item)))
+            .collect()
     }
 
-    /// Performs map reduce operations in parallel.
-    pub fn map_reduce<'a, T, S, FM, FR, FD, R>(source: &'a S, map_op: FM, default_op: FD, reduce_op: FR) -> R
+    /// Performs map reduce operations in parallel. If random is repeatable, then map operations run within
+    /// their own random streams and results are reduced sequentially in the order of source items.
+    pub fn map_reduce<'a, T, S, FM, FR, FD, R>(
+        source: &'a S,
+        map_op: FM,
+        default_op: FD,
+        reduce_op: FR,
+        random: &dyn Random,
+    ) -> R
     where
         T: Send + Sync,
         S: IntoParallelRefIterator<'a, Item = T> + ?Sized,
@@ -67,7 +86,23 @@ mod actual {
         FD: Fn() -> R + Sync + Send,
         R: Send,
     {
-        source.par_iter().map(map_op).reduce(default_op, reduce_op)
+        if !random.is_repeatable() {
+            return source.par_iter().map(map_op).reduce(default_op, reduce_op);
+        }
+
+        // NOTE items are collected first as source iterator is not required to be indexed, but
+        //      task index is needed to derive random stream deterministically. Reduce is applied
+        //      sequentially as splitting is not deterministic and reduce operation can use randomness
+        let key = get_child_stream_key();
+        source
+            .par_iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .enumerate()
+            .map(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || map_op(item)))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .fold(default_op(), reduce_op)
     }
 
     /// Performs mutable foreach in parallel.
@@ -76,12 +111,18 @@ mod actual {
         T: Send + Sync,
         F: Fn(&mut T) + Send + Sync,
     {
-        source.par_iter_mut().for_each(action)
+        let key = get_child_stream_key();
+        source
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || action(item)))
     }
 }
 
 #[cfg(target_arch = "wasm32")]
 mod actual {
+    use crate::utils::{get_child_stream_key, get_task_stream_key, with_random_stream, Random};
+
     /// Represents a thread pool wrapper.
     pub struct ThreadPool;
 
@@ -91,13 +132,13 @@ mod actual {
             Self {}
         }
 
-        /// Executes given operation on thread pool (dummy).
+        /// Executes given operation on thread pool (dummy) within its own random stream.
         pub fn execute<OP, R>(&self, op: OP) -> R
         where
             OP: FnOnce() -> R + Send,
             R: Send,
         {
-            op()
+            with_random_stream(get_child_stream_key(), op)
         }
     }
 
@@ -108,7 +149,12 @@ mod actual {
         F: Fn(&T) -> R + Sync + Send,
         R: Send,
     {
-        source.iter().map(map_op).collect()
+        let key = get_child_stream_key();
+        source
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || map_op(item)))
+            .collect()
     }
 
     /// Map collections and collects results into vector synchronously.
@@ -118,11 +164,22 @@ mod actual {
         F: Fn(T) -> R + Sync + Send,
         R: Send,
     {
-        source.into_iter().map(map_op).collect()
+        let key = get_child_stream_key();
+        source
+            .into_iter()
+            .enumerate()
+            .map(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || map_op(item)))
+            .collect()
     }
 
     /// Performs map reduce operations synchronously.
-    pub fn map_reduce<T, S, FM, FR, FD, R>(source: S, map_op: FM, default_op: FD, reduce_op: FR) -> R
+    pub fn map_reduce<T, S, FM, FR, FD, R>(
+        source: S,
+        map_op: FM,
+        default_op: FD,
+        reduce_op: FR,
+        _random: &dyn Random,
+    ) -> R
     where
         T: Send + Sync,
         S: IntoIterator<Item = T>,
@@ -131,7 +188,12 @@ mod actual {
         FD: Fn() -> R + Sync + Send,
         R: Send,
     {
-        source.into_iter().map(map_op).fold(default_op(), reduce_op)
+        let key = get_child_stream_key();
+        source
+            .into_iter()
+            .enumerate()
+            .map(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || map_op(item)))
+            .fold(default_op(), reduce_op)
     }
 
     /// Performs mutable foreach in parallel.
@@ -140,6 +202,10 @@ mod actual {
         T: Send + Sync,
         F: Fn(&mut T) + Send + Sync,
     {
-        source.iter_mut().for_each(action)
+        let key = get_child_stream_key();
+        source
+            .iter_mut()
+            .enumerate()
+            .for_each(|(idx, item)| with_random_stream(get_task_stream_key(key, idx), || action(item)))
     }
 }
@@ -5,8 +5,10 @@ mod random_test;
 use rand::prelude::*;
 use rand::Error;
 use rand_distr::{Gamma, Normal};
+use rustc_hash::FxHashMap;
 use std::cell::RefCell;
 use std::cmp::Ordering;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Arc;
 
 /// Provides the way to sample from different distributions.
@@ -39,6 +41,17 @@ pub trait Random {
 
     /// Returns RNG.
     fn get_rng(&self) -> RandomGen;
+
+    /// Returns true if random generation is repeatable (e.g. uses a fixed seed). In this case, heuristics
+    /// should not adapt their behavior based on time measurements to keep runs reproducible.
+    fn is_repeatable(&self) -> bool {
+        false
+    }
+
+    /// Returns a seed used by repeatable random generation, if any.
+    fn get_seed(&self) -> Option<u64> {
+        None
+    }
 }
 
 /// Provides way to sample from different distributions.
@@ -69,13 +82,19 @@ impl DistributionSampler for DefaultDistributionSampler {
 /// A default random implementation.
 #[derive(Default)]
 pub struct DefaultRandom {
-    use_repeatable: bool,
+    seed: Option<(u64, u64)>,
 }
 
 impl DefaultRandom {
     /// Creates an instance of `DefaultRandom` with repeatable (predictable) random generation.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self::new_with_seed(0)
+    }
+
+    /// Creates an instance of `DefaultRandom` with repeatable random generation which uses given seed.
+    /// Each instance starts its own random streams, so the same sequence of calls produces the same values.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { seed: Some((seed, get_next_instance_id())) }
     }
 }
 
@@ -117,7 +136,15 @@ impl Random for DefaultRandom {
     }
 
     fn get_rng(&self) -> RandomGen {
-        RandomGen { use_repeatable: self.use_repeatable }
+        RandomGen { seed: self.seed }
+    }
+
+    fn is_repeatable(&self) -> bool {
+        self.seed.is_some()
+    }
+
+    fn get_seed(&self) -> Option<u64> {
+        self.seed.map(|(seed, _)| seed)
     }
 }
 
@@ -125,60 +152,149 @@ thread_local! {
     /// Random generator seeded from thread_rng to make runs non-repeatable.
     static RANDOMIZED_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_rng(thread_rng()).expect("cannot get RNG from thread rng"));
 
-    /// Random generator seeded with 0 SmallRng to make runs repeatable.
-    static REPEATABLE_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(0));
+    /// A random stream used by seeded random generators on the current thread.
+    static SEEDED_STREAM: RefCell<RandomStream> = RefCell::new(RandomStream::new_root());
+}
+
+/// A global counter used to distinguish instances of seeded random generators.
+static INSTANCE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn get_next_instance_id() -> u64 {
+    INSTANCE_COUNTER.fetch_add(1, AtomicOrdering::Relaxed)
+}
+
+/// Keeps random generators of a random stream. Each parallel task runs within its own stream which key is
+/// derived from the parent stream key, so random values do not depend on how tasks are scheduled across threads.
+struct RandomStream {
+    key: u64,
+    children: u64,
+    is_root: bool,
+    rngs: FxHashMap<(u64, u64), SmallRng>,
+}
+
+impl RandomStream {
+    fn new(key: u64) -> Self {
+        Self { key, children: 0, is_root: false, rngs: FxHashMap::default() }
+    }
+
+    fn new_root() -> Self {
+        Self { is_root: true, ..Self::new(0) }
+    }
+}
+
+/// Returns a key of a new child stream of the current thread's random stream. Keys of parallel tasks should be
+/// derived from it using [`get_task_stream_key`].
+pub(crate) fn get_child_stream_key() -> u64 {
+    SEEDED_STREAM.with(|stream| {
+        let mut stream = stream.borrow_mut();
+        stream.children += 1;
+        mix_keys(stream.key, stream.children)
+    })
+}
+
+/// Returns a random stream key of parallel task with given index.
+pub(crate) fn get_task_stream_key(parent_key: u64, task_idx: usize) -> u64 {
+    mix_keys(parent_key, task_idx as u64)
+}
+
+/// Runs given function within a random stream with given key and restores the previous stream after.
+pub(crate) fn with_random_stream<T>(key: u64, func: impl FnOnce() -> T) -> T {
+    let _guard = RandomStreamGuard::replace(RandomStream::new(key));
+
+    func()
+}
+
+/// Runs given function within a fresh root random stream if it is called outside of any parallel task, so
+/// subsequent runs within the same thread derive the same random streams.
+pub(crate) fn with_isolated_random_stream<T>(func: impl FnOnce() -> T) -> T {
+    let is_root = SEEDED_STREAM.with(|stream| stream.borrow().is_root);
+    let _guard = is_root.then(|| RandomStreamGuard::replace(RandomStream::new_root()));
+
+    func()
+}
+
+/// Replaces the random stream of the current thread and restores the previous one when dropped,
+/// so the previous stream is restored even if the function running within the new stream panics.
+struct RandomStreamGuard {
+    previous: Option<RandomStream>,
+}
+
+impl RandomStreamGuard {
+    fn replace(stream: RandomStream) -> Self {
+        Self { previous: Some(SEEDED_STREAM.with(|current| current.replace(stream))) }
+    }
+}
+
+impl Drop for RandomStreamGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            // NOTE thread local storage might be already destroyed when the thread exits
+            let _ = SEEDED_STREAM.try_with(|current| current.replace(previous));
+        }
+    }
+}
+
+/// Mixes two keys using splitmix64 finalizer.
+fn mix_keys(left: u64, right: u64) -> u64 {
+    let mut value = left ^ right.wrapping_add(0x9E37_79B9_7F4A_7C15).wrapping_mul(0xD1B5_4A32_D192_ED03);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    value ^ (value >> 31)
 }
 
 /// Provides underlying random generator API.
 #[derive(Clone, Debug)]
 pub struct RandomGen {
-    use_repeatable: bool,
+    seed: Option<(u64, u64)>,
 }
 
 impl RandomGen {
     /// Creates an instance of `RandomGen` using random generator with fixed seed.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self::new_with_seed(0)
+    }
+
+    /// Creates an instance of `RandomGen` using random generator with given seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { seed: Some((seed, get_next_instance_id())) }
     }
 
     /// Creates an instance of `RandomGen` using random generator with randomized seed.
     pub fn new_randomized() -> Self {
-        Self { use_repeatable: false }
+        Self { seed: None }
     }
-}
 
-impl RngCore for RandomGen {
-    fn next_u32(&mut self) -> u32 {
+    fn with_rng<T>(&self, func: impl FnOnce(&mut SmallRng) -> T) -> T {
         // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u32())
+        if let Some((seed, instance)) = self.seed {
+            SEEDED_STREAM.with(|stream| {
+                let mut stream = stream.borrow_mut();
+                let key = stream.key;
+                // NOTE stream keys are already mixed and the root stream has zero key, so it keeps the seed as is
+                func(stream.rngs.entry((seed, instance)).or_insert_with(|| SmallRng::seed_from_u64(seed ^ key)))
+            })
         } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u32())
+            RANDOMIZED_RNG.with(|t| func(&mut t.borrow_mut()))
         }
     }
+}
+
+impl RngCore for RandomGen {
+    fn next_u32(&mut self) -> u32 {
+        self.with_rng(|rng| rng.next_u32())
+    }
 
     fn next_u64(&mut self) -> u64 {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u64())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u64())
-        }
+        self.with_rng(|rng| rng.next_u64())
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.fill_bytes(dest))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.try_fill_bytes(dest))
     }
 }
 
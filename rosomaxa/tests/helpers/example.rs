@@ -1,3 +1,4 @@
+use crate::evolution::HeuristicSolutionProcessing;
 use crate::example::*;
 use crate::utils::Environment;
 use crate::{get_default_population, get_default_selection_size, TelemetryMode};
@@ -11,6 +12,19 @@ pub fn create_example_objective() -> Arc<VectorObjective> {
     Arc::new(VectorObjective::new(fitness_fn, weight_fn))
 }
 
+/// A solution post processing which negates solution data, so processed solutions are easy to recognize.
+pub struct NegatingProcessing;
+
+impl HeuristicSolutionProcessing for NegatingProcessing {
+    type Solution = VectorSolution;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let data = solution.data.iter().map(|value| -value).collect();
+
+        VectorSolution::new_with_objective(data, create_example_objective().as_ref())
+    }
+}
+
 /// A helper method to create an example of VectorContext.
 pub fn create_default_heuristic_context() -> VectorContext {
     create_heuristic_context_with_solutions(vec![])
@@ -0,0 +1,228 @@
+use super::*;
+use crate::evolution::{
+    EvolutionConfigBuilder, EvolutionSimulator, InitialOperators, ProcessingConfig, SharedSolutionProcessing,
+};
+use crate::example::*;
+use crate::helpers::example::{create_default_heuristic_context, create_example_objective, NegatingProcessing};
+use crate::hyper::{DynamicSelective, HeuristicDiversifyOperator, HeuristicSearchOperator, HyperHeuristicState};
+use crate::utils::{DefaultRandom, Environment};
+use crate::{get_default_population, get_default_selection_size};
+use std::sync::{Arc, Mutex};
+
+struct HalvingOperator;
+
+impl HeuristicSearchOperator for HalvingOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let data = solution.data.iter().map(|value| value + (1. - value) / 2.).collect();
+
+        VectorSolution::new_with_objective(data, create_example_objective().as_ref())
+    }
+}
+
+impl HeuristicDiversifyOperator for HalvingOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        vec![self.search(heuristic_ctx, solution)]
+    }
+}
+
+type Checkpoints = Arc<Mutex<Vec<EvolutionCheckpoint<VectorSolution>>>>;
+
+fn create_config_builder(
+    max_generations: usize,
+) -> EvolutionConfigBuilder<VectorContext, VectorObjective, VectorSolution, i32> {
+    let context = create_default_heuristic_context();
+    let operator: Arc<dyn HeuristicSearchOperator<Context = _, Objective = _, Solution = _> + Send + Sync> =
+        Arc::new(HalvingOperator);
+    let heuristic = DynamicSelective::new(
+        vec![(operator, "halving".to_string(), 1.)],
+        vec![Arc::new(HalvingOperator)],
+        context.environment(),
+    );
+    let initial_operators: InitialOperators<_, _, _> = vec![(Box::new(VectorInitialOperator::new(vec![0.5, 0.5])), 1)];
+
+    EvolutionConfigBuilder::default()
+        .with_heuristic(Box::new(heuristic))
+        .with_objective(create_example_objective())
+        .with_context(context)
+        .with_initial(1, 0.05, initial_operators)
+        .with_max_generations(Some(max_generations))
+}
+
+fn run_evolution(
+    max_generations: usize,
+    interval: usize,
+    resume: Option<EvolutionCheckpoint<VectorSolution>>,
+) -> Vec<EvolutionCheckpoint<VectorSolution>> {
+    let checkpoints = Checkpoints::default();
+    let checkpoint = CheckpointConfig::new(interval, {
+        let checkpoints = checkpoints.clone();
+        Box::new(move |checkpoint| {
+            checkpoints.lock().unwrap().push(checkpoint);
+            Ok(())
+        })
+    })
+    .unwrap();
+
+    let config =
+        create_config_builder(max_generations).with_checkpoint(Some(checkpoint)).with_resume(resume).build().unwrap();
+    EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    let mut checkpoints = checkpoints.lock().unwrap();
+    std::mem::take(&mut checkpoints)
+}
+
+fn run_evolution_with_priors(max_generations: usize, priors: Option<HyperHeuristicState>) -> HyperHeuristicState {
+    let exported = Arc::new(Mutex::new(HyperHeuristicState::default()));
+
+    let config = create_config_builder(max_generations)
+        .with_heuristic_priors(priors)
+        .with_heuristic_export(Some({
+            let exported = exported.clone();
+            Box::new(move |state| {
+                *exported.lock().unwrap() = state;
+                Ok(())
+            })
+        }))
+        .build()
+        .unwrap();
+    EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    let mut exported = exported.lock().unwrap();
+    std::mem::take(&mut exported)
+}
+
+fn get_usage(checkpoint: &EvolutionCheckpoint<VectorSolution>, key: &str) -> f64 {
+    get_state_usage(&checkpoint.heuristic, key)
+}
+
+fn get_state_usage(state: &HyperHeuristicState, key: &str) -> f64 {
+    state.iter().find(|(name, _)| name == key).map(|(_, values)| values[4]).unwrap()
+}
+
+#[test]
+fn cannot_create_checkpoint_config_with_zero_interval() {
+    let result = CheckpointConfig::<VectorSolution>::new(0, Box::new(|_| Ok(())));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_save_checkpoint_periodically() {
+    let checkpoints = run_evolution(10, 3, None);
+
+    assert_eq!(checkpoints.iter().map(|checkpoint| checkpoint.generation).collect::<Vec<_>>(), vec![2, 5, 8]);
+    checkpoints.iter().for_each(|checkpoint| {
+        assert!(!checkpoint.solutions.is_empty());
+        assert_eq!(checkpoint.heuristic.len(), 2);
+    });
+    let usage = |checkpoint| get_usage(checkpoint, "best/halving") + get_usage(checkpoint, "diverse/halving");
+    assert!(usage(&checkpoints[2]) > usage(&checkpoints[0]));
+    assert!(checkpoints.iter().all(|checkpoint| checkpoint.seed.is_none()));
+}
+
+#[test]
+fn can_save_seed_of_seeded_search_without_consuming_random() {
+    let environment = Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(7)), ..Default::default() });
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let population = get_default_population(objective.clone(), environment.clone(), selection_size);
+    let context = VectorContext::new(objective, population, TelemetryMode::None, environment.clone());
+    let heuristic = DynamicSelective::new(
+        vec![(Arc::new(HalvingOperator), "halving".to_string(), 1.)],
+        vec![Arc::new(HalvingOperator)],
+        environment.as_ref(),
+    );
+    let seeds = Arc::new(Mutex::new(Vec::new()));
+    let checkpoint = CheckpointConfig::new(1, {
+        let seeds = seeds.clone();
+        Box::new(move |checkpoint| {
+            seeds.lock().unwrap().push(checkpoint.seed);
+            Ok(())
+        })
+    })
+    .unwrap();
+    let expected = DefaultRandom::new_with_seed(7).uniform_int(0, 1000);
+
+    checkpoint.save(&context, &heuristic, &SharedSolutionProcessing::default());
+
+    assert_eq!(environment.random.uniform_int(0, 1000), expected);
+    assert_eq!(*seeds.lock().unwrap(), vec![Some(7)]);
+}
+
+#[test]
+fn can_post_process_checkpoint_solutions() {
+    let checkpoints = Checkpoints::default();
+    let checkpoint = CheckpointConfig::new(5, {
+        let checkpoints = checkpoints.clone();
+        Box::new(move |checkpoint| {
+            checkpoints.lock().unwrap().push(checkpoint);
+            Ok(())
+        })
+    })
+    .unwrap();
+    let processing = ProcessingConfig { context: vec![], solution: vec![Box::new(NegatingProcessing)] };
+
+    let config =
+        create_config_builder(10).with_processing(processing).with_checkpoint(Some(checkpoint)).build().unwrap();
+    EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    let checkpoints = checkpoints.lock().unwrap();
+    assert!(!checkpoints.is_empty());
+    checkpoints.iter().flat_map(|checkpoint| checkpoint.solutions.iter()).for_each(|solution| {
+        assert!(solution.data.iter().all(|value| *value < 0.));
+    });
+}
+
+#[test]
+fn can_resume_from_checkpoint() {
+    let resume = EvolutionCheckpoint {
+        generation: 8,
+        solutions: vec![VectorSolution::new_with_objective(vec![0.9, 0.9], create_example_objective().as_ref())],
+        heuristic: vec![
+            ("best/halving".to_string(), vec![10., 1., 1., 1., 100.]),
+            ("diverse/halving".to_string(), vec![10., 1., 1., 1., 100.]),
+        ],
+        seed: None,
+    };
+
+    let checkpoints = run_evolution(10, 1, Some(resume));
+
+    assert_eq!(checkpoints.iter().map(|checkpoint| checkpoint.generation).collect::<Vec<_>>(), vec![9, 10]);
+    let usage = get_usage(&checkpoints[0], "best/halving") + get_usage(&checkpoints[0], "diverse/halving");
+    assert!(usage > 200.);
+    let best = checkpoints[0].solutions.first().unwrap();
+    assert!(best.data.iter().all(|&value| value >= 0.9));
+}
+
+#[test]
+fn can_export_heuristic_state() {
+    let state = run_evolution_with_priors(10, None);
+
+    assert_eq!(
+        state.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(),
+        vec!["best/halving", "diverse/halving"]
+    );
+    assert!(get_state_usage(&state, "best/halving") + get_state_usage(&state, "diverse/halving") > 0.);
+}
+
+#[test]
+fn can_start_search_with_heuristic_priors() {
+    let priors = vec![
+        ("best/halving".to_string(), vec![10., 1., 1., 1., 100.]),
+        ("diverse/halving".to_string(), vec![10., 1., 1., 1., 100.]),
+        ("unknown/operator".to_string(), vec![10., 1., 1., 1., 100.]),
+    ];
+
+    let state = run_evolution_with_priors(10, Some(priors));
+
+    assert_eq!(state.len(), 2);
+    assert!(get_state_usage(&state, "best/halving") + get_state_usage(&state, "diverse/halving") > 200.);
+}
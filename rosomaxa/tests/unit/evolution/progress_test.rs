@@ -0,0 +1,102 @@
+use super::*;
+use crate::evolution::strategies::Iterative;
+use crate::evolution::{EvolutionConfigBuilder, EvolutionSimulator, InitialOperators, ProcessingConfig};
+use crate::example::*;
+use crate::helpers::example::{create_default_heuristic_context, create_example_objective, NegatingProcessing};
+use crate::hyper::{DynamicSelective, HeuristicDiversifyOperator, HeuristicSearchOperator};
+use std::sync::{Arc, Mutex};
+
+struct HalvingOperator;
+
+impl HeuristicSearchOperator for HalvingOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let data = solution.data.iter().map(|value| value + (1. - value) / 2.).collect();
+
+        VectorSolution::new_with_objective(data, create_example_objective().as_ref())
+    }
+}
+
+impl HeuristicDiversifyOperator for HalvingOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        vec![self.search(heuristic_ctx, solution)]
+    }
+}
+
+fn create_heuristic(environment: &Environment) -> DynamicSelective<VectorContext, VectorObjective, VectorSolution> {
+    let operator: Arc<dyn HeuristicSearchOperator<Context = _, Objective = _, Solution = _> + Send + Sync> =
+        Arc::new(HalvingOperator);
+
+    DynamicSelective::new(vec![(operator, "halving".to_string(), 1.)], vec![Arc::new(HalvingOperator)], environment)
+}
+
+fn create_builder(
+    max_generations: usize,
+) -> EvolutionConfigBuilder<VectorContext, VectorObjective, VectorSolution, i32> {
+    let context = create_default_heuristic_context();
+    let heuristic = create_heuristic(context.environment());
+    let initial_operators: InitialOperators<_, _, _> = vec![(Box::new(VectorInitialOperator::new(vec![0.5, 0.5])), 1)];
+
+    EvolutionConfigBuilder::default()
+        .with_heuristic(Box::new(heuristic))
+        .with_objective(create_example_objective())
+        .with_context(context)
+        .with_initial(1, 0.05, initial_operators)
+        .with_max_generations(Some(max_generations))
+}
+
+#[test]
+fn can_emit_progress_events_on_improvement() {
+    let events = Arc::new(Mutex::new(Vec::<(usize, f64)>::new()));
+    let listener: ProgressListener<VectorSolution> = {
+        let events = events.clone();
+        Box::new(move |event| events.lock().unwrap().push((event.generation, event.solution.fitness().next().unwrap())))
+    };
+
+    let config = create_builder(10).with_progress(Some(listener)).build().unwrap();
+    EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    let events = events.lock().unwrap();
+    assert!(events.len() > 1);
+    assert_eq!(events[0].0, 0);
+    events.windows(2).for_each(|pair| {
+        assert!(pair[0].0 < pair[1].0);
+        assert!(pair[1].1 < pair[0].1);
+    });
+}
+
+#[test]
+fn can_post_process_progress_solutions() {
+    let events = Arc::new(Mutex::new(Vec::<Vec<f64>>::new()));
+    let listener: ProgressListener<VectorSolution> = {
+        let events = events.clone();
+        Box::new(move |event| events.lock().unwrap().push(event.solution.data.clone()))
+    };
+    let processing = ProcessingConfig { context: vec![], solution: vec![Box::new(NegatingProcessing)] };
+
+    let config = create_builder(10).with_processing(processing).with_progress(Some(listener)).build().unwrap();
+    let (solutions, _) = EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    let events = events.lock().unwrap();
+    assert!(events.len() > 1);
+    assert!(events.iter().flatten().all(|value| *value < 0.));
+    assert_eq!(events.last(), solutions.first().map(|solution| &solution.data));
+}
+
+#[test]
+fn cannot_use_progress_with_custom_strategy() {
+    let config = create_builder(10);
+    let heuristic = create_heuristic(create_default_heuristic_context().environment());
+    let strategy = Box::new(Iterative::new(Box::new(heuristic), 1));
+
+    let result = config.with_strategy(strategy).with_progress(Some(Box::new(|_| {}))).build();
+
+    assert!(result.is_err());
+}
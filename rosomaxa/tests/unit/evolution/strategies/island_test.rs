@@ -0,0 +1,87 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::{create_example_objective, create_heuristic_context_with_solutions};
+use crate::hyper::{DynamicSelective, HeuristicDiversifyOperator};
+use crate::termination::MaxGeneration;
+use std::sync::Arc;
+
+struct HalvingOperator;
+
+impl HeuristicSearchOperator for HalvingOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        // NOTE example objective has a global minimum at (1, 1)
+        let data = solution.data.iter().map(|value| value + (1. - value) / 2.).collect();
+
+        VectorSolution::new_with_objective(data, create_example_objective().as_ref())
+    }
+}
+
+impl HeuristicDiversifyOperator for HalvingOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        vec![self.search(heuristic_ctx, solution)]
+    }
+}
+
+fn create_heuristic() -> IslandHeuristic<VectorContext, VectorObjective, VectorSolution> {
+    let environment = Environment::default();
+    let operator: Arc<dyn HeuristicSearchOperator<Context = _, Objective = _, Solution = _> + Send + Sync> =
+        Arc::new(HalvingOperator);
+
+    Box::new(DynamicSelective::new(
+        vec![(operator, "halving".to_string(), 1.)],
+        vec![Arc::new(HalvingOperator)],
+        &environment,
+    ))
+}
+
+#[test]
+fn cannot_create_island_model_with_wrong_amount_of_contexts() {
+    let contexts = Vec::<VectorContext>::default();
+
+    let result = IslandModel::new(vec![create_heuristic(), create_heuristic()], contexts, 10, 1, 1);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_migrate_best_individuals_in_ring() {
+    let mut heuristics = (0..3).map(|_| create_heuristic()).collect::<Vec<_>>();
+    let mut islands = heuristics
+        .iter_mut()
+        .enumerate()
+        .map(|(idx, heuristic)| Island {
+            context: create_heuristic_context_with_solutions(vec![vec![idx as f64, idx as f64]]),
+            heuristic,
+            migrants: Vec::default(),
+        })
+        .collect::<Vec<_>>();
+
+    migrate(islands.as_mut_slice(), 1);
+
+    let migrants =
+        islands.iter().map(|island| island.migrants.iter().map(|s| s.data.clone()).collect()).collect::<Vec<Vec<_>>>();
+    assert_eq!(migrants, vec![vec![vec![2., 2.]], vec![vec![0., 0.]], vec![vec![1., 1.]]]);
+}
+
+#[test]
+fn can_run_island_model() {
+    let initial = vec![0.5, 0.5];
+    let initial_fitness = (create_example_objective().fitness_fn)(initial.as_slice());
+    let heuristic_ctx = create_heuristic_context_with_solutions(vec![initial]);
+    let contexts = (0..2).map(|_| create_heuristic_context_with_solutions(vec![])).collect();
+    let heuristics = (0..3).map(|_| create_heuristic()).collect();
+    let mut strategy = IslandModel::new(heuristics, contexts, 2, 1, 1).unwrap();
+
+    let (solutions, _) = strategy.run(heuristic_ctx, Box::new(MaxGeneration::new(10))).unwrap();
+
+    assert_eq!(solutions.len(), 1);
+    assert!(solutions[0].fitness().next().unwrap() < initial_fitness);
+}
@@ -40,3 +40,27 @@ fn can_update_statistic() {
     telemetry.on_generation(population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_ignore_speed_when_repeatable() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let population = get_default_population(objective.clone(), environment, selection_size);
+    let population = population.as_ref();
+
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+    telemetry.set_repeatable(true);
+    let solution = VectorSolution::new(vec![], 0., vec![]);
+    telemetry.on_initial(&solution, Timer::start());
+
+    (0..20).for_each(|_| telemetry.on_generation(population, 0., Timer::start(), false));
+
+    match &telemetry.get_statistics().speed {
+        HeuristicSpeed::Moderate { average, median } => {
+            assert_eq!(compare_floats(*average, 1000.), Ordering::Equal);
+            assert_eq!(*median, None);
+        }
+        speed => unreachable!("unexpected speed: {speed:?}"),
+    }
+}
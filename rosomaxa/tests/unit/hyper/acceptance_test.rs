@@ -0,0 +1,65 @@
+use super::*;
+use crate::utils::Environment;
+
+fn create_context<'a>(
+    best: &'a [f64],
+    current: &'a [f64],
+    candidate: &'a [f64],
+    estimate: f64,
+) -> AcceptanceContext<'a> {
+    AcceptanceContext { best, current, candidate, termination_estimate: estimate }
+}
+
+parameterized_test! {can_get_relative_delta, (a, b, expected), {
+    assert_eq!(get_relative_delta(a, b), expected);
+}}
+
+can_get_relative_delta! {
+    case01_equal: (&[1., 10.], &[1., 10.], 0.),
+    case02_worse_first: (&[2., 10.], &[1., 20.], 1.),
+    case03_better_second: (&[1., 5.], &[1., 10.], -0.5),
+    case04_small_values: (&[0., 0.5], &[0., 0.], 0.5),
+}
+
+parameterized_test! {can_accept_with_simulated_annealing, (candidate, estimate, expected), {
+    let random = Environment::default().random;
+    let mut acceptance = SimulatedAnnealing::new(0.1, 1E-6, random);
+
+    let result = acceptance.accept(&create_context(&[90.], &[100.], &[candidate], estimate));
+
+    assert_eq!(result, expected);
+}}
+
+can_accept_with_simulated_annealing! {
+    case01_better: (90., 1., true),
+    case02_equal: (100., 1., true),
+    case03_worse_at_end: (110., 1., false),
+}
+
+parameterized_test! {can_accept_with_record_to_record, (candidate, estimate, expected), {
+    let mut acceptance = RecordToRecord::new(0.1);
+
+    let result = acceptance.accept(&create_context(&[100.], &[105.], &[candidate], estimate));
+
+    assert_eq!(result, expected);
+}}
+
+can_accept_with_record_to_record! {
+    case01_within_threshold: (108., 0., true),
+    case02_outside_threshold: (111., 0., false),
+    case03_within_reduced_threshold: (104., 0.5, true),
+    case04_outside_reduced_threshold: (108., 0.5, false),
+}
+
+#[test]
+fn can_accept_with_late_acceptance() {
+    let mut acceptance = LateAcceptance::new(2);
+    let mut accept =
+        |current: f64, candidate: f64| acceptance.accept(&create_context(&[0.], &[current], &[candidate], 0.));
+
+    assert!(accept(100., 90.));
+    assert!(!accept(90., 95.));
+    assert!(accept(90., 90.));
+    // NOTE history has 90 and 90 at this point
+    assert!(!accept(90., 95.));
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::{create_default_heuristic_context, create_example_objective};
+
+struct ShiftOperator {
+    is_improving: bool,
+}
+
+impl HeuristicSearchOperator for ShiftOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        // NOTE example objective has a global minimum at (1, 1)
+        let data = solution.data.iter().map(|value| if self.is_improving { 1. } else { value + 1. }).collect();
+
+        VectorSolution::new_with_objective(data, create_example_objective().as_ref())
+    }
+}
+
+impl HeuristicDiversifyOperator for ShiftOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        vec![self.search(heuristic_ctx, solution)]
+    }
+}
+
+fn create_heuristic(
+    operators: Vec<bool>,
+    acceptance: Box<dyn AcceptanceCriterion>,
+) -> AdaptiveSelective<VectorContext, VectorObjective, VectorSolution> {
+    let search_operators = operators
+        .into_iter()
+        .enumerate()
+        .map(|(idx, is_improving)| {
+            let operator: Arc<dyn HeuristicSearchOperator<Context = _, Objective = _, Solution = _> + Send + Sync> =
+                Arc::new(ShiftOperator { is_improving });
+            (operator, format!("operator{idx}"), 1.)
+        })
+        .collect();
+
+    AdaptiveSelective::new(search_operators, vec![Arc::new(ShiftOperator { is_improving: true })], acceptance, 10, 0.5)
+}
+
+#[test]
+fn can_increase_weight_of_successful_operator() {
+    let heuristic_ctx = create_default_heuristic_context();
+    let solution = VectorSolution::new_with_objective(vec![-2., 3.], create_example_objective().as_ref());
+    let mut heuristic = create_heuristic(vec![true, false], Box::new(LateAcceptance::new(1)));
+
+    (0..20).for_each(|_| {
+        heuristic.search_many(&heuristic_ctx, vec![&solution; 4]);
+    });
+
+    assert!(heuristic.operators[0].weight > heuristic.operators[1].weight);
+}
+
+#[test]
+fn can_keep_current_solution_when_candidate_is_rejected() {
+    let heuristic_ctx = create_default_heuristic_context();
+    let solution = VectorSolution::new_with_objective(vec![2., 2.], create_example_objective().as_ref());
+    let mut heuristic = create_heuristic(vec![false], Box::new(RecordToRecord::new(0.)));
+
+    let candidates = heuristic.search(&heuristic_ctx, &solution);
+    let candidates = heuristic.search(&heuristic_ctx, &candidates[0]);
+
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].data, vec![3., 3.]);
+    assert_eq!(heuristic.current.as_ref().map(|current| current.data.clone()), Some(vec![2., 2.]));
+}
@@ -0,0 +1,37 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::{create_example_objective, create_heuristic_context_with_solutions};
+
+struct AverageCrossover;
+
+impl HeuristicCrossoverOperator for AverageCrossover {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn crossover(&self, _: &Self::Context, first: &Self::Solution, second: &Self::Solution) -> Self::Solution {
+        let data = first.data.iter().zip(second.data.iter()).map(|(a, b)| (a + b) / 2.).collect();
+
+        VectorSolution::new_with_objective(data, create_example_objective().as_ref())
+    }
+}
+
+#[test]
+fn can_recombine_solution_with_population_individual() {
+    let heuristic_ctx = create_heuristic_context_with_solutions(vec![vec![2., 2.]]);
+    let solution = VectorSolution::new_with_objective(vec![0., 0.], create_example_objective().as_ref());
+
+    let offspring = CrossoverSearch::new(Arc::new(AverageCrossover)).search(&heuristic_ctx, &solution);
+
+    assert_eq!(offspring.data, vec![1., 1.]);
+}
+
+#[test]
+fn can_return_copy_when_no_other_parent() {
+    let heuristic_ctx = create_heuristic_context_with_solutions(vec![]);
+    let solution = VectorSolution::new_with_objective(vec![3., 3.], create_example_objective().as_ref());
+
+    let offspring = CrossoverSearch::new(Arc::new(AverageCrossover)).search(&heuristic_ctx, &solution);
+
+    assert_eq!(offspring.data, vec![3., 3.]);
+}
@@ -1,6 +1,8 @@
 use super::*;
 use crate::example::{VectorContext, VectorObjective, VectorSolution};
-use crate::helpers::example::create_default_heuristic_context;
+use crate::helpers::example::{create_default_heuristic_context, create_example_objective};
+use crate::utils::DefaultRandom;
+use crate::{get_default_population, get_default_selection_size};
 use std::ops::Range;
 use std::time::Duration;
 
@@ -89,6 +91,48 @@ fn can_estimate_reward_multiplier_impl(
     assert_eq!(result, expected);
 }
 
+parameterized_test! {can_ignore_search_duration_when_repeatable, is_repeatable, {
+    can_ignore_search_duration_when_repeatable_impl(is_repeatable);
+}}
+
+can_ignore_search_duration_when_repeatable! {
+    case_01_repeatable: true,
+    case_02_randomized: false,
+}
+
+fn can_ignore_search_duration_when_repeatable_impl(is_repeatable: bool) {
+    struct SlowHeuristicOperator;
+    impl HeuristicSearchOperator for SlowHeuristicOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+            std::thread::sleep(Duration::from_millis(2));
+            solution.deep_copy()
+        }
+    }
+    let random: Arc<dyn Random + Send + Sync> =
+        if is_repeatable { Arc::new(DefaultRandom::new_with_seed(0)) } else { Arc::new(DefaultRandom::default()) };
+    let environment = Arc::new(Environment { random, ..Environment::default() });
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let population = get_default_population(objective.clone(), environment.clone(), selection_size);
+    let heuristic_ctx = VectorContext::new(objective, population, TelemetryMode::None, environment);
+    let solution = VectorSolution::new(vec![0., 0.], 0., vec![0., 0.]);
+    let action = SearchAction { operator: Arc::new(SlowHeuristicOperator), operator_name: "slow".to_string() };
+
+    let feedback = action.take(SearchContext {
+        heuristic_ctx: &heuristic_ctx,
+        from: SearchState::BestKnown,
+        slot_idx: 0,
+        solution: &solution,
+        approx_median: None,
+    });
+
+    assert_eq!(feedback.sample.duration == 0, is_repeatable);
+}
+
 #[test]
 fn can_display_heuristic_info() {
     let is_experimental = true;
@@ -136,3 +180,44 @@ fn can_handle_when_objective_lies() {
 
     assert_eq!(distance, 0.)
 }
+
+#[test]
+fn can_get_and_set_state() {
+    struct NoopOperator;
+    impl HeuristicSearchOperator for NoopOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+            solution.deep_copy()
+        }
+    }
+    let create_heuristic = || {
+        DynamicSelective::<VectorContext, VectorObjective, VectorSolution>::new(
+            vec![(Arc::new(NoopOperator), "first".to_string(), 1.), (Arc::new(NoopOperator), "second".to_string(), 1.)],
+            vec![],
+            &Environment::default(),
+        )
+    };
+    let state = vec![
+        ("best/first".to_string(), vec![2., 3., 4., 5., 6.]),
+        ("diverse/second".to_string(), vec![7., 8., 9., 10., 11.]),
+        ("diverse/unknown".to_string(), vec![1., 1., 1., 1., 1.]),
+    ];
+    let mut heuristic = create_heuristic();
+
+    heuristic.set_state(&state);
+    let actual = heuristic.get_state();
+
+    let default_params = create_heuristic().get_state()[0].1.clone();
+    assert_eq!(
+        actual,
+        vec![
+            ("best/first".to_string(), vec![2., 3., 4., 5., 6.]),
+            ("best/second".to_string(), default_params.clone()),
+            ("diverse/first".to_string(), default_params),
+            ("diverse/second".to_string(), vec![7., 8., 9., 10., 11.]),
+        ]
+    );
+}
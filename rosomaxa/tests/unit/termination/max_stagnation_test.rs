@@ -0,0 +1,68 @@
+use super::*;
+use crate::example::VectorContext;
+use crate::helpers::example::*;
+use crate::Timer;
+
+parameterized_test! {can_detect_termination_with_sample, (generations, tolerance, fitness, expected), {
+    can_detect_termination_with_sample_impl(generations, tolerance, fitness, expected);
+}}
+
+can_detect_termination_with_sample! {
+    case01_no_improvement: (3, vec![], vec![10., 10., 10., 10.], vec![false, false, false, true]),
+    case02_improvement: (3, vec![], vec![10., 9., 8., 7.], vec![false, false, false, false]),
+    case03_within_tolerance: (3, vec![0.1], vec![10., 9.5, 9.2, 9.1], vec![false, false, false, true]),
+    case04_accumulated: (3, vec![0.1], vec![10., 9.5, 8.9, 8.9, 8.9], vec![false, false, false, false, false]),
+    case05_worse: (2, vec![], vec![10., 11., 12.], vec![false, false, true]),
+}
+
+fn can_detect_termination_with_sample_impl(
+    generations: usize,
+    tolerance: Vec<f64>,
+    fitness: Vec<f64>,
+    expected: Vec<bool>,
+) {
+    let mut context = create_default_heuristic_context();
+    let termination = MaxStagnation::<_, _, _, _>::new_with_sample(generations, tolerance, 0);
+
+    let result = fitness
+        .into_iter()
+        .map(|cost| {
+            context.on_generation(vec![], 0.1, Timer::start());
+
+            termination.update_and_check(&mut context, vec![0., cost])
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_compare_objectives_with_tolerance, (tolerance, best, fitness, expected), {
+    let termination = MaxStagnation::<VectorContext, _, _, _>::new_with_sample(1, tolerance, 0);
+
+    assert_eq!(termination.is_improvement(best.as_slice(), fitness.as_slice()), expected);
+}}
+
+can_compare_objectives_with_tolerance! {
+    case01_same: (vec![], vec![1., 10.], vec![1., 10.], false),
+    case02_first_better: (vec![], vec![2., 10.], vec![1., 20.], true),
+    case03_first_worse: (vec![], vec![1., 20.], vec![2., 10.], false),
+    case04_first_within_tolerance: (vec![0.1, 0.], vec![10., 20.], vec![10.5, 10.], true),
+    case05_second_within_tolerance: (vec![0., 0.1], vec![10., 20.], vec![10., 19.], false),
+    case06_last_tolerance_reused: (vec![0.1], vec![10., 20.], vec![10.5, 19.], false),
+}
+
+#[test]
+fn can_detect_termination_with_period() {
+    let mut context = create_default_heuristic_context();
+    let termination = MaxStagnation::<_, _, _, _>::new_with_period(1, vec![], 0);
+
+    let result = [(0, 10.), (1100, 9.), (2000, 9.), (2200, 9.)]
+        .into_iter()
+        .enumerate()
+        .map(|(generation, (time, cost))| {
+            termination.update_and_check_at(&mut context, vec![0., cost], generation, time)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, vec![false, false, false, true]);
+}
@@ -0,0 +1,23 @@
+use super::*;
+use crate::example::VectorSolution;
+use crate::helpers::example::create_heuristic_context_with_solutions;
+
+parameterized_test! {can_use_target_cost, (solutions, target_cost, expected), {
+    can_use_target_cost_impl(solutions, target_cost, expected);
+}}
+
+can_use_target_cost! {
+    case01_above: (vec![vec![0., 0.]], 0.5, false),
+    case02_equal: (vec![vec![0., 0.]], 1., true),
+    case03_below: (vec![vec![1., 1.]], 0.5, true),
+    case04_empty: (vec![], 0.5, false),
+}
+
+fn can_use_target_cost_impl(solutions: Vec<Vec<f64>>, target_cost: f64, expected: bool) {
+    let mut context = create_heuristic_context_with_solutions(solutions);
+    let cost_fn = Box::new(|solution: &VectorSolution| solution.fitness().next());
+
+    let result = TargetCost::<_, _, _>::new(target_cost, cost_fn).is_termination(&mut context);
+
+    assert_eq!(result, expected)
+}
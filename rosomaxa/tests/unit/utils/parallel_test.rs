@@ -1,11 +1,12 @@
 use super::*;
+use crate::utils::{with_isolated_random_stream, with_random_stream, DefaultRandom, Random};
 use std::collections::HashMap;
 
 #[test]
 fn can_use_map_reduce_for_vec() {
     let vec = vec![1, 2, 3];
 
-    let result = map_reduce(&vec, |item| *item, || 0, |a, b| a + b);
+    let result = map_reduce(&vec, |item| *item, || 0, |a, b| a + b, &DefaultRandom::default());
 
     assert_eq!(result, 6);
 }
@@ -16,7 +17,7 @@ fn can_use_map_reduce_for_map() {
     map.insert(1, "1");
     map.insert(2, "2");
 
-    let result = map_reduce(&map, |(key, _)| *key, || 0, |a, b| a + b);
+    let result = map_reduce(&map, |(key, _)| *key, || 0, |a, b| a + b, &DefaultRandom::default());
 
     assert_eq!(result, 3);
 }
@@ -25,7 +26,59 @@ fn can_use_map_reduce_for_map() {
 fn can_use_map_reduce_for_slice() {
     let vec = vec![1, 2, 3];
 
-    let result = map_reduce(vec.as_slice(), |item| *item, || 0, |a, b| a + b);
+    let result = map_reduce(vec.as_slice(), |item| *item, || 0, |a, b| a + b, &DefaultRandom::default());
 
     assert_eq!(result, 6);
 }
+
+#[test]
+fn can_derive_the_same_random_streams_for_parallel_tasks() {
+    let sample = |seed: u64| {
+        let random = DefaultRandom::new_with_seed(seed);
+        let pool = ThreadPool::new(4);
+
+        with_isolated_random_stream(|| {
+            pool.execute(|| {
+                parallel_collect(&(0..32).collect::<Vec<_>>(), |_| {
+                    (0..4).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>()
+                })
+            })
+        })
+    };
+
+    let first = sample(42);
+
+    assert_eq!(first, sample(42));
+    assert_ne!(first, sample(43));
+    assert!(first.windows(2).any(|pair| pair[0] != pair[1]));
+}
+
+parameterized_test! {can_use_map_reduce_with_random, random, {
+    can_use_map_reduce_with_random_impl(random);
+}}
+
+can_use_map_reduce_with_random! {
+    case01_randomized: DefaultRandom::default(),
+    case02_repeatable: DefaultRandom::new_with_seed(42),
+}
+
+fn can_use_map_reduce_with_random_impl(random: DefaultRandom) {
+    let vec = (0..32).collect::<Vec<_>>();
+
+    let result = map_reduce(&vec, |item| vec![*item], Vec::new, |a, b| a.into_iter().chain(b).collect(), &random);
+
+    assert_eq!(result, vec);
+}
+
+#[test]
+fn can_restore_random_stream_after_panic() {
+    let random = DefaultRandom::new_with_seed(42);
+    let sample = || with_isolated_random_stream(|| (0..4).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>());
+    let expected = sample();
+
+    let result =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| with_random_stream(1, || panic!("task failed"))));
+
+    assert!(result.is_err());
+    assert_eq!(sample(), expected);
+}
@@ -20,3 +20,35 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_use_seeded_random_generation() {
+    let sample = |random: &DefaultRandom| (0..10).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+
+    let first = sample(&DefaultRandom::new_with_seed(42));
+    let second = sample(&DefaultRandom::new_with_seed(43));
+
+    assert_ne!(first, second);
+    // NOTE each instance starts its own generator state, so the same seed gives the same sequence
+    assert_eq!(first, sample(&DefaultRandom::new_with_seed(42)));
+    std::thread::spawn(move || assert_eq!(first, sample(&DefaultRandom::new_with_seed(42)))).join().unwrap();
+}
+
+#[test]
+fn can_get_seed() {
+    assert_eq!(DefaultRandom::new_with_seed(42).get_seed(), Some(42));
+    assert_eq!(DefaultRandom::default().get_seed(), None);
+}
+
+#[test]
+fn can_use_different_seeded_streams_for_derived_keys() {
+    let random = DefaultRandom::new_with_seed(42);
+    let sample = || (0..10).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+
+    let first = with_random_stream(get_task_stream_key(1, 0), sample);
+    let second = with_random_stream(get_task_stream_key(1, 1), sample);
+    let third = with_random_stream(get_task_stream_key(1, 0), sample);
+
+    assert_ne!(first, second);
+    assert_eq!(first, third);
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/bench_test.rs"]
+mod bench_test;
+
+use super::*;
+
+use clap::ArgAction;
+use std::collections::HashMap;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+use vrp_cli::extensions::bench::{self, read_best_known_solutions, write_bench_csv, write_bench_json, BenchSettings};
+use vrp_core::prelude::*;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const INSTANCES_ARG_NAME: &str = "INSTANCES";
+const BKS_ARG_NAME: &str = "bks";
+const SEEDS_ARG_NAME: &str = "seeds";
+const GENERATIONS_ARG_NAME: &str = "max-generations";
+const TIME_ARG_NAME: &str = "max-time";
+const TARGET_GAP_ARG_NAME: &str = "target-gap";
+const ROUNDED_ARG_NAME: &str = "round";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+const LOG_ARG_NAME: &str = "log";
+
+pub fn get_bench_app() -> Command {
+    Command::new("bench")
+        .about("Benchmarks solver on a set of scientific problem instances against their best known solutions")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .value_parser(["solomon", "lilim", "tsplib"])
+                .index(1),
+        )
+        .arg(Arg::new(INSTANCES_ARG_NAME).help("Sets the folder with problem instances").required(true).index(2))
+        .arg(
+            Arg::new(BKS_ARG_NAME)
+                .help("Specifies path to csv file with best known solutions: instance, vehicles and cost columns")
+                .short('b')
+                .long(BKS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(SEEDS_ARG_NAME)
+                .help("Specifies amount of runs per instance, each run uses its own seed starting from zero")
+                .short('s')
+                .long(SEEDS_ARG_NAME)
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new(GENERATIONS_ARG_NAME)
+                .help("Specifies maximum number of generations of one run")
+                .short('n')
+                .long(GENERATIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TIME_ARG_NAME)
+                .help("Specifies max time of one run in seconds")
+                .short('t')
+                .long(TIME_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TARGET_GAP_ARG_NAME)
+                .help("Specifies a gap to best known solution in percents used to measure time to target")
+                .long(TARGET_GAP_ARG_NAME)
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new(ROUNDED_ARG_NAME)
+                .help("Specifies whether costs are rounded")
+                .short('r')
+                .long(ROUNDED_ARG_NAME)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(OUT_FORMAT_ARG_NAME)
+                .help("Specifies output format: csv with statistics per instance or json with all runs")
+                .long(OUT_FORMAT_ARG_NAME)
+                .value_parser(["csv", "json"])
+                .default_value("csv"),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(LOG_ARG_NAME)
+                .help("Specifies whether default logging is enabled")
+                .long(LOG_ARG_NAME)
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub fn run_bench(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+    let is_rounded = matches.get_one::<bool>(ROUNDED_ARG_NAME).copied().unwrap_or(false);
+
+    // NOTE best known solutions are identified by file name without extension
+    let instances = read_problems(format, matches.get_one::<String>(INSTANCES_ARG_NAME).unwrap(), is_rounded)?
+        .into_iter()
+        .map(|(name, problem)| {
+            let name = Path::new(&name).file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or(name);
+            (name, Arc::new(problem))
+        })
+        .collect::<Vec<_>>();
+
+    if instances.is_empty() {
+        return Err("no problem instances found".into());
+    }
+
+    let bks = matches
+        .get_one::<String>(BKS_ARG_NAME)
+        .map(|path| read_best_known_solutions(BufReader::new(open_file(path, "best known solutions"))))
+        .transpose()?
+        .unwrap_or_else(HashMap::default);
+
+    let seeds = parse_int_value::<u64>(matches, SEEDS_ARG_NAME, "seeds")?.unwrap();
+    let settings = BenchSettings {
+        seeds: (0..seeds).collect(),
+        max_generations: parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?,
+        max_time: parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?,
+        target_gap: parse_float_value::<f64>(matches, TARGET_GAP_ARG_NAME, "target gap")?.unwrap(),
+    };
+
+    let logger: InfoLogger = if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
+        Arc::new(|msg: &str| println!("{msg}"))
+    } else {
+        Arc::new(|_: &str| {})
+    };
+
+    let result = bench::run_bench(instances.as_slice(), &bks, &settings, logger)?;
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let mut out_buffer = out_writer_func(out_result);
+    match matches.get_one::<String>(OUT_FORMAT_ARG_NAME).map(String::as_str) {
+        Some("json") => write_bench_json(&result, &mut out_buffer)?,
+        _ => write_bench_csv(&result, &mut out_buffer)?,
+    }
+    out_buffer.flush()?;
+
+    Ok(())
+}
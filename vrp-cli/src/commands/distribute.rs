@@ -0,0 +1,169 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/distribute_test.rs"]
+mod distribute_test;
+
+use super::*;
+
+use clap::ArgAction;
+use std::io::{BufWriter, Read, Write};
+use std::sync::Arc;
+use std::time::Duration;
+use vrp_cli::extensions::distribute::{self, CoordinatorConfig, Listener};
+use vrp_core::prelude::*;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "PROBLEM";
+const MATRIX_ARG_NAME: &str = "matrix";
+const CONFIG_ARG_NAME: &str = "config";
+const ADDRESS_ARG_NAME: &str = "address";
+const WORKERS_ARG_NAME: &str = "workers";
+const ROUNDS_ARG_NAME: &str = "rounds";
+const GENERATIONS_ARG_NAME: &str = "generations";
+const ELITE_SIZE_ARG_NAME: &str = "elite-size";
+const TIMEOUT_ARG_NAME: &str = "worker-timeout";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+const LOG_ARG_NAME: &str = "log";
+
+pub fn get_coordinator_app() -> Command {
+    Command::new("coordinator")
+        .about("Starts a coordinator which distributes solving of VRP across multiple worker processes")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::new(ADDRESS_ARG_NAME)
+                .help("Specifies address to listen on: 'host:port' for TCP or 'unix:/path/to/socket' for unix socket")
+                .short('a')
+                .long(ADDRESS_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(WORKERS_ARG_NAME)
+                .help("Specifies amount of workers to wait for")
+                .short('w')
+                .long(WORKERS_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(ROUNDS_ARG_NAME)
+                .help("Specifies amount of rounds with elite solutions exchange")
+                .long(ROUNDS_ARG_NAME)
+                .default_value("10"),
+        )
+        .arg(
+            Arg::new(GENERATIONS_ARG_NAME)
+                .help("Specifies amount of generations run by each worker within one round")
+                .short('n')
+                .long(GENERATIONS_ARG_NAME)
+                .default_value("200"),
+        )
+        .arg(
+            Arg::new(ELITE_SIZE_ARG_NAME)
+                .help("Specifies max amount of elite solutions shared with workers on each round")
+                .long(ELITE_SIZE_ARG_NAME)
+                .default_value("2"),
+        )
+        .arg(
+            Arg::new(TIMEOUT_ARG_NAME)
+                .help("Specifies max time in seconds to wait for a worker response, otherwise the worker is dropped")
+                .long(TIMEOUT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .num_args(1..)
+                .required(false),
+        )
+        .arg(
+            Arg::new(CONFIG_ARG_NAME)
+                .help("Specifies path to algorithm configuration file used by workers")
+                .short('c')
+                .long(CONFIG_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+        .arg(get_log_arg())
+}
+
+pub fn get_worker_app() -> Command {
+    Command::new("worker")
+        .about("Starts a worker which connects to coordinator and runs search rounds requested by it")
+        .arg(
+            Arg::new(ADDRESS_ARG_NAME)
+                .help("Specifies coordinator address: 'host:port' for TCP or 'unix:/path/to/socket' for unix socket")
+                .short('a')
+                .long(ADDRESS_ARG_NAME)
+                .required(true),
+        )
+        .arg(get_log_arg())
+}
+
+pub fn run_coordinator(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let address = matches.get_one::<String>(ADDRESS_ARG_NAME).unwrap();
+    let problem = read_file(matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap(), "problem")?;
+    let matrices = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .map(|paths| paths.map(|path| read_file(path, "matrix")).collect::<Result<Vec<_>, _>>())
+        .transpose()?
+        .unwrap_or_default();
+    let config = matches.get_one::<String>(CONFIG_ARG_NAME).map(|path| read_file(path, "config")).transpose()?;
+
+    let settings = CoordinatorConfig {
+        workers: parse_int_value::<usize>(matches, WORKERS_ARG_NAME, "workers")?.unwrap(),
+        rounds: parse_int_value::<usize>(matches, ROUNDS_ARG_NAME, "rounds")?.unwrap(),
+        generations: parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "generations")?.unwrap(),
+        elite_size: parse_int_value::<usize>(matches, ELITE_SIZE_ARG_NAME, "elite size")?.unwrap(),
+        timeout: parse_int_value::<u64>(matches, TIMEOUT_ARG_NAME, "worker timeout")?.map(Duration::from_secs),
+    };
+
+    let listener = Listener::bind(address)?;
+    let solution = distribute::run_coordinator(&listener, problem, matrices, config, &settings, get_logger(matches))?;
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
+    let mut out_buffer = out_writer_func(out_result);
+    out_buffer.write_all(solution.as_bytes())?;
+    out_buffer.flush()?;
+
+    Ok(())
+}
+
+pub fn run_worker(matches: &ArgMatches) -> Result<(), GenericError> {
+    let address = matches.get_one::<String>(ADDRESS_ARG_NAME).unwrap();
+
+    distribute::run_worker(address, get_logger(matches))
+}
+
+fn get_log_arg() -> Arg {
+    Arg::new(LOG_ARG_NAME)
+        .help("Specifies whether default logging is enabled")
+        .long(LOG_ARG_NAME)
+        .action(ArgAction::SetTrue)
+}
+
+fn get_logger(matches: &ArgMatches) -> InfoLogger {
+    if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
+        Arc::new(|msg: &str| println!("{msg}"))
+    } else {
+        Arc::new(|_: &str| {})
+    }
+}
+
+fn read_file(path: &str, description: &str) -> Result<String, GenericError> {
+    let mut content = String::new();
+    open_file(path, description)
+        .read_to_string(&mut content)
+        .map_err(|err| format!("cannot read {description} file '{path}': '{err}'"))?;
+
+    Ok(content)
+}
@@ -1,17 +1,21 @@
 use clap::{Arg, ArgMatches, Command};
 
 pub mod analyze;
+pub mod bench;
 pub mod check;
+pub mod distribute;
 pub mod generate;
 pub mod import;
 pub mod solve;
+pub mod tune;
 
 use std::fs::File;
 use std::io::{stdout, BufReader, BufWriter, Write};
+use std::path::Path;
 use std::process;
 use std::str::FromStr;
 use vrp_cli::extensions::check::check_pragmatic_solution;
-use vrp_core::prelude::GenericError;
+use vrp_core::prelude::{GenericError, Problem};
 
 pub(crate) fn create_write_buffer(out_file: Option<File>) -> BufWriter<Box<dyn Write>> {
     if let Some(out_file) = out_file {
@@ -92,3 +96,57 @@ fn check_solution(
     }
     .map_err(|errs| format!("checker found {} errors:\n{}", errs.len(), GenericError::join_many(&errs, "\n")).into())
 }
+
+/// Reads problems from all files with format specific extension in given folder ordered by file name.
+fn read_problems(format: &str, path: &str, is_rounded: bool) -> Result<Vec<(String, Problem)>, GenericError> {
+    let extension = match format {
+        "pragmatic" => "json",
+        "tsplib" => "vrp",
+        _ => "txt",
+    };
+
+    let mut paths = std::fs::read_dir(path)
+        .map_err(|err| format!("cannot read instances folder '{path}': '{err}'"))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == extension))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let problem = read_problem(format, path, is_rounded)
+                .map_err(|err| format!("cannot read {format} problem from '{}': '{err}'", path.display()))?;
+
+            Ok((name, problem))
+        })
+        .collect()
+}
+
+fn read_problem(format: &str, path: &Path, is_rounded: bool) -> Result<Problem, GenericError> {
+    let reader = BufReader::new(open_file(&path.to_string_lossy(), "problem"));
+
+    match format {
+        "pragmatic" => {
+            use vrp_pragmatic::format::problem::PragmaticProblem;
+            reader.read_pragmatic().map_err(From::from)
+        }
+        #[cfg(feature = "scientific-format")]
+        "solomon" => {
+            use vrp_scientific::solomon::SolomonProblem;
+            reader.read_solomon(is_rounded)
+        }
+        #[cfg(feature = "scientific-format")]
+        "lilim" => {
+            use vrp_scientific::lilim::LilimProblem;
+            reader.read_lilim(is_rounded)
+        }
+        #[cfg(feature = "scientific-format")]
+        "tsplib" => {
+            use vrp_cli::scientific::tsplib::TsplibProblem;
+            reader.read_tsplib(is_rounded)
+        }
+        _ => Err(format!("unknown format: '{format}'").into()),
+    }
+}
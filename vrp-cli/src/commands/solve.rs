@@ -8,16 +8,23 @@ use clap::ArgAction;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use vrp_cli::core::solver::TargetHeuristic;
+use vrp_cli::extensions::solve::checkpoint::{
+    create_file_checkpoint, create_file_priors_export, read_checkpoint, read_heuristic_priors,
+};
 use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::solve::progress::create_progress_listener;
 use vrp_cli::get_locations_serialized;
 use vrp_cli::scientific::tsplib::{TsplibProblem, TsplibSolution};
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::GoalContext;
 use vrp_core::prelude::*;
 use vrp_core::rosomaxa::evolution::*;
+use vrp_core::rosomaxa::hyper::HyperHeuristicState;
+use vrp_core::rosomaxa::termination::TargetCost;
 use vrp_core::rosomaxa::{get_default_population, get_default_selection_size};
 use vrp_core::solver::*;
 use vrp_core::utils::*;
@@ -29,6 +36,8 @@ const MATRIX_ARG_NAME: &str = "matrix";
 const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const MIN_CV_ARG_NAME: &str = "min-cv";
+const MAX_STAGNATION_ARG_NAME: &str = "max-stagnation";
+const TARGET_COST_ARG_NAME: &str = "target-cost";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
@@ -40,9 +49,21 @@ const LOG_ARG_NAME: &str = "log";
 const CHECK_ARG_NAME: &str = "check";
 const SEARCH_MODE_ARG_NAME: &str = "search-mode";
 const PARALLELISM_ARG_NAME: &str = "parallelism";
+const SEED_ARG_NAME: &str = "seed";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const CHECKPOINT_ARG_NAME: &str = "checkpoint";
+const CHECKPOINT_INTERVAL_ARG_NAME: &str = "checkpoint-interval";
+const RESUME_ARG_NAME: &str = "resume";
+const PROGRESS_ARG_NAME: &str = "progress";
+const PROGRESS_SOLUTION_ARG_NAME: &str = "progress-solution";
+const VEHICLE_STATES_ARG_NAME: &str = "vehicle-states";
+const KEEP_PLAN_ARG_NAME: &str = "keep-plan";
+const LOWER_BOUND_ARG_NAME: &str = "lower-bound";
+const TARGET_GAP_ARG_NAME: &str = "target-gap";
+const PRIORS_ARG_NAME: &str = "priors";
+const OUT_PRIORS_ARG_NAME: &str = "out-priors";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, GenericError>>);
@@ -199,6 +220,21 @@ pub fn get_solve_app() -> Command {
                 .long(MIN_CV_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(MAX_STAGNATION_ARG_NAME)
+                .help(
+                    "Specifies termination criteria when best known solution is not improved in form \"type,value,tolerance\". \
+                    Tolerance is optional and can be specified per objective as a comma separated list",
+                )
+                .long(MAX_STAGNATION_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(TARGET_COST_ARG_NAME)
+                .help("Specifies termination criteria when best known solution without unassigned jobs reaches the target cost")
+                .long(TARGET_COST_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(INIT_SOLUTION_ARG_NAME)
                 .help("Specifies path to file with initial solution")
@@ -278,6 +314,12 @@ pub fn get_solve_app() -> Command {
                 .short('p')
                 .required(false)
         )
+        .arg(
+            Arg::new(SEED_ARG_NAME)
+                .help("Specifies a seed of random generator to make runs reproducible for the same parallelism settings")
+                .long(SEED_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(HEURISTIC_ARG_NAME)
                 .help("Specifies hyper heuristic algorithm")
@@ -301,6 +343,78 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(CHECKPOINT_ARG_NAME)
+                .help("Specifies path to checkpoint file where search state is saved periodically. Applicable only for pragmatic format.")
+                .long(CHECKPOINT_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(CHECKPOINT_INTERVAL_ARG_NAME)
+                .help("Specifies amount of generations between checkpoints")
+                .long(CHECKPOINT_INTERVAL_ARG_NAME)
+                .required(false)
+                .default_value("100")
+        )
+        .arg(
+            Arg::new(RESUME_ARG_NAME)
+                .help("Specifies path to checkpoint file to resume the search from. Applicable only for pragmatic format.")
+                .long(RESUME_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(PROGRESS_ARG_NAME)
+                .help("Specifies path to file where progress events are streamed as json lines, one event per improvement of the best known solution. Use '-' to write them to stdout.")
+                .long(PROGRESS_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(PROGRESS_SOLUTION_ARG_NAME)
+                .help("Specifies whether progress events include full solution. Applicable only for pragmatic format.")
+                .long(PROGRESS_SOLUTION_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new(VEHICLE_STATES_ARG_NAME)
+                .help("Specifies path to file with current vehicle states to re-optimize initial solution: executed part of tours is frozen. Applicable only for pragmatic format.")
+                .long(VEHICLE_STATES_ARG_NAME)
+                .required(false)
+                .requires(INIT_SOLUTION_ARG_NAME)
+        )
+        .arg(
+            Arg::new(KEEP_PLAN_ARG_NAME)
+                .help("Specifies relation type used to keep unexecuted jobs of en route vehicles assigned to them when vehicle states are used.")
+                .long(KEEP_PLAN_ARG_NAME)
+                .required(false)
+                .value_parser(["any", "sequence", "strict"])
+                .requires(VEHICLE_STATES_ARG_NAME)
+        )
+        .arg(
+            Arg::new(LOWER_BOUND_ARG_NAME)
+                .help("Specifies whether lower bound of the solution cost is estimated and reported together with optimality gap in solution extras. Applicable only for pragmatic format.")
+                .long(LOWER_BOUND_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new(TARGET_GAP_ARG_NAME)
+                .help("Specifies relative optimality gap termination criteria: search stops when the gap between best known solution cost and its lower bound is not greater than the threshold, e.g. 0.05")
+                .long(TARGET_GAP_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(PRIORS_ARG_NAME)
+                .help("Specifies path to file with heuristic priors saved by previous run on similar problem, see out-priors option")
+                .long(PRIORS_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(OUT_PRIORS_ARG_NAME)
+                .help("Specifies path to file where heuristic state learned by the end of the search is saved to be used as priors by next runs")
+                .long(OUT_PRIORS_ARG_NAME)
+                .required(false)
+        )
 }
 
 /// Runs solver commands.
@@ -308,7 +422,12 @@ pub fn run_solve(
     matches: &ArgMatches,
     out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
 ) -> Result<(), GenericError> {
-    let environment = get_environment(matches)?;
+    let resume = matches
+        .get_one::<String>(RESUME_ARG_NAME)
+        .map(|path| read_checkpoint(BufReader::new(open_file(path, "resume checkpoint"))))
+        .transpose()?;
+    let seed = parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed")?;
+    let environment = get_environment(matches, seed.or(resume.as_ref().and_then(|checkpoint| checkpoint.seed)))?;
     let formats = get_formats(matches, environment.random.clone());
 
     let problem_path = matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
@@ -323,6 +442,9 @@ pub fn run_solve(
 
     let is_get_locations_set = matches.get_one::<bool>(GET_LOCATIONS_ARG_NAME).copied().unwrap_or(false);
     let is_check_requested = matches.get_one::<bool>(CHECK_ARG_NAME).copied().unwrap_or(false);
+    let target_gap = parse_float_value::<f64>(matches, TARGET_GAP_ARG_NAME, "target gap")?;
+    let is_lower_bound_set =
+        matches.get_one::<bool>(LOWER_BOUND_ARG_NAME).copied().unwrap_or(false) || target_gap.is_some();
 
     match formats.get(problem_format.as_str()) {
         Some((
@@ -337,34 +459,82 @@ pub fn run_solve(
             if is_get_locations_set {
                 locations_writer(problem_file, out_buffer).map_err(|err| format!("cannot get locations '{err}'").into())
             } else {
-                match problem_reader(problem_file, matrix_files) {
-                    Ok(problem) => {
-                        let problem = Arc::new(problem);
-                        let init_solutions = init_solution
-                            .map(|file| read_init_solution(problem.clone(), environment.clone(), file, init_reader))
-                            .unwrap_or_else(|| Ok(Vec::default()))?;
-
-                        let solver = if let Some(config) = config {
-                            from_config_parameters(problem.clone(), init_solutions, config)?
-                        } else {
-                            from_cli_parameters(problem.clone(), environment, init_solutions, matches)?
-                        };
+                let vehicle_states =
+                    matches.get_one::<String>(VEHICLE_STATES_ARG_NAME).map(|path| open_file(path, "vehicle states"));
 
-                        let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
+                let (problem, init_solutions) = if let Some(vehicle_states) = vehicle_states {
+                    if problem_format != "pragmatic" {
+                        return Err(format!("vehicle states are not supported for '{problem_format}' format").into());
+                    }
+                    if is_check_requested {
+                        return Err("feasibility check is not supported with vehicle states".into());
+                    }
 
-                        solution_writer(&problem, solution, out_buffer, geo_buffer).unwrap();
+                    read_reoptimization(
+                        problem_file,
+                        matrix_files,
+                        init_solution,
+                        vehicle_states,
+                        matches.get_one::<String>(KEEP_PLAN_ARG_NAME).map(String::as_str),
+                        environment.clone(),
+                        is_lower_bound_set,
+                    )?
+                } else {
+                    let problem = problem_reader(problem_file, matrix_files).map_err(|error| {
+                        format!("cannot read {problem_format} problem from '{problem_path}': '{error}'")
+                    })?;
+                    let problem = Arc::new(with_lower_bound(problem, is_lower_bound_set));
+                    let init_solutions = init_solution
+                        .map(|file| read_init_solution(problem.clone(), environment.clone(), file, init_reader))
+                        .unwrap_or_else(|| Ok(Vec::default()))?;
+
+                    (problem, init_solutions)
+                };
 
-                        if is_check_requested {
-                            check_pragmatic_solution_with_args(matches)?;
-                            println!("solution feasibility check is completed successfully");
-                        }
+                let checkpoint = get_checkpoint(matches, problem_format, problem.clone())?;
+                let progress = get_progress(matches, problem_format, problem.clone())?;
+                let resume = resume
+                    .map(|resume| {
+                        check_checkpoint_format(problem_format)?;
+                        resume.into_evolution(problem.clone(), environment.clone())
+                    })
+                    .transpose()?;
 
-                        Ok(())
-                    }
-                    Err(error) => {
-                        Err(format!("cannot read {problem_format} problem from '{problem_path}': '{error}'").into())
-                    }
+                let builder = if let Some(config) = config {
+                    from_config_parameters(problem.clone(), init_solutions, config)?
+                } else {
+                    from_cli_parameters(problem.clone(), environment, init_solutions, matches)?
+                };
+                let builder = if let Some(target_gap) = target_gap {
+                    let lower_bound = problem.extras.get_lower_bound().ok_or("cannot get lower bound")?;
+                    builder
+                        .with_termination(Box::new(TargetGapTermination::new(lower_bound.as_ref().clone(), target_gap)))
+                } else {
+                    builder
+                };
+                let config = builder
+                    .with_checkpoint(checkpoint)
+                    .with_resume(resume)
+                    .with_progress(progress)
+                    .with_heuristic_priors(get_heuristic_priors(matches)?)
+                    .with_heuristic_export(
+                        matches
+                            .get_one::<String>(OUT_PRIORS_ARG_NAME)
+                            .map(|path| create_file_priors_export(path.into())),
+                    )
+                    .build()?;
+                let solver = Solver::new(problem.clone(), config);
+
+                let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
+
+                solution_writer(&problem, solution, out_buffer, geo_buffer).unwrap();
+
+                if is_check_requested {
+                    check_pragmatic_solution_with_args(matches)?;
+                    println!("solution feasibility check is completed successfully");
                 }
+
+                Ok(())
             }
         }
         None => Err(format!("unknown format: '{problem_format}'").into()),
@@ -382,14 +552,69 @@ fn read_init_solution(
         .map(|solution| vec![InsertionContext::new_from_solution(problem.clone(), (solution, None), environment)])
 }
 
+fn read_reoptimization(
+    problem: File,
+    matrices: Option<Vec<File>>,
+    init_solution: Option<File>,
+    vehicle_states: File,
+    keep_plan: Option<&str>,
+    environment: Arc<Environment>,
+    is_lower_bound_set: bool,
+) -> GenericResult<(Arc<Problem>, Vec<InsertionContext>)> {
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem, RelationType};
+    use vrp_pragmatic::format::reoptimization::{create_reoptimization, deserialize_vehicle_states};
+    use vrp_pragmatic::format::solution::{deserialize_solution, read_init_solution as read_init_pragmatic};
+
+    let init_solution = init_solution.ok_or("vehicle states require initial solution")?;
+
+    let problem = deserialize_problem(BufReader::new(problem))
+        .map_err(|err| format!("cannot read pragmatic problem: '{err}'"))?;
+    let matrices = matrices
+        .map(|matrices| matrices.into_iter().map(|matrix| deserialize_matrix(BufReader::new(matrix))).collect())
+        .transpose()
+        .map_err(|err| format!("cannot read routing matrix: '{err}'"))?;
+    let solution = deserialize_solution(BufReader::new(init_solution))
+        .map_err(|err| format!("cannot read initial solution '{err}'"))?;
+    let vehicle_states = deserialize_vehicle_states(BufReader::new(vehicle_states))?;
+    let keep_plan = match keep_plan {
+        Some("any") => Some(RelationType::Any),
+        Some("sequence") => Some(RelationType::Sequence),
+        Some("strict") => Some(RelationType::Strict),
+        Some(value) => return Err(format!("unknown keep plan relation type: '{value}'").into()),
+        None => None,
+    };
+
+    let (problem, solution) = create_reoptimization(&problem, &solution, vehicle_states.as_slice(), keep_plan)
+        .map_err(|err| format!("cannot create re-optimization problem: '{err}'"))?;
+
+    let problem =
+        (problem, matrices).read_pragmatic().map_err(|err| format!("cannot read re-optimization problem: '{err}'"))?;
+    let problem = Arc::new(with_lower_bound(problem, is_lower_bound_set));
+
+    let solution = serde_json::to_vec(&solution).map_err(|err| format!("cannot write initial solution: '{err}'"))?;
+    let solution =
+        read_init_pragmatic(BufReader::new(solution.as_slice()), problem.clone(), environment.random.clone())
+            .map_err(|err| format!("cannot read initial solution '{err}'"))?;
+
+    Ok((problem.clone(), vec![InsertionContext::new_from_solution(problem, (solution, None), environment)]))
+}
+
+fn with_lower_bound(mut problem: Problem, is_lower_bound_set: bool) -> Problem {
+    if is_lower_bound_set {
+        let mut extras = problem.extras.as_ref().clone();
+        extras.set_lower_bound(Arc::new(estimate_lower_bound(&problem)));
+        problem.extras = Arc::new(extras);
+    }
+
+    problem
+}
+
 fn from_config_parameters(
     problem: Arc<Problem>,
     init_solutions: Vec<InsertionContext>,
     config: File,
-) -> GenericResult<Solver> {
-    create_builder_from_config_file(problem.clone(), init_solutions, BufReader::new(config))
-        .and_then(|builder| builder.build())
-        .map(|config| Solver::new(problem.clone(), config))
+) -> GenericResult<ProblemConfigBuilder> {
+    create_builder_from_config_file(problem, init_solutions, BufReader::new(config))
         .map_err(|err| format!("cannot read config: '{err}'").into())
 }
 
@@ -398,7 +623,7 @@ fn from_cli_parameters(
     environment: Arc<Environment>,
     init_solutions: Vec<InsertionContext>,
     matches: &ArgMatches,
-) -> GenericResult<Solver> {
+) -> GenericResult<ProblemConfigBuilder> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
 
     let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?;
@@ -408,10 +633,12 @@ fn from_cli_parameters(
         TelemetryMode::None
     };
     let min_cv = get_min_cv(matches)?;
+    let max_stagnation = get_max_stagnation(matches)?;
+    let target_cost = parse_float_value::<f64>(matches, TARGET_COST_ARG_NAME, "target cost")?;
     let init_size = get_init_size(matches)?;
     let mode = matches.get_one::<String>(SEARCH_MODE_ARG_NAME);
 
-    let config = VrpConfigBuilder::new(problem.clone())
+    let builder = VrpConfigBuilder::new(problem.clone())
         .set_environment(environment.clone())
         .set_telemetry_mode(telemetry_mode.clone())
         .prebuild()?
@@ -419,16 +646,81 @@ fn from_cli_parameters(
         .with_max_generations(max_generations)
         .with_max_time(max_time)
         .with_min_cv(min_cv, "min_cv".to_string())
+        .with_max_stagnation(max_stagnation, "max_stagnation".to_string())
         .with_context(RefinementContext::new(
             problem.clone(),
             get_population(mode, problem.goal.clone(), environment.clone()),
             telemetry_mode,
             environment.clone(),
         ))
-        .with_heuristic(get_heuristic(matches, problem.clone(), environment)?)
-        .build()?;
+        .with_heuristic(get_heuristic(matches, problem.clone(), environment)?);
 
-    Ok(Solver::new(problem.clone(), config))
+    let builder = if let Some(target_cost) = target_cost {
+        builder.with_termination(Box::new(TargetCost::new(target_cost, Box::new(get_complete_solution_cost))))
+    } else {
+        builder
+    };
+
+    Ok(builder)
+}
+
+fn get_checkpoint(
+    matches: &ArgMatches,
+    problem_format: &str,
+    problem: Arc<Problem>,
+) -> GenericResult<Option<CheckpointConfig<InsertionContext>>> {
+    matches
+        .get_one::<String>(CHECKPOINT_ARG_NAME)
+        .map(|path| {
+            check_checkpoint_format(problem_format)?;
+            let interval =
+                parse_int_value::<usize>(matches, CHECKPOINT_INTERVAL_ARG_NAME, "checkpoint interval")?.unwrap_or(100);
+
+            create_file_checkpoint(problem, PathBuf::from(path), interval)
+        })
+        .transpose()
+}
+
+fn get_heuristic_priors(matches: &ArgMatches) -> GenericResult<Option<HyperHeuristicState>> {
+    matches
+        .get_one::<String>(PRIORS_ARG_NAME)
+        .map(|path| read_heuristic_priors(BufReader::new(open_file(path, "heuristic priors"))))
+        .transpose()
+}
+
+fn check_checkpoint_format(problem_format: &str) -> GenericResult<()> {
+    if problem_format == "pragmatic" {
+        Ok(())
+    } else {
+        Err(format!("checkpoint is not supported for '{problem_format}' format").into())
+    }
+}
+
+fn get_progress(
+    matches: &ArgMatches,
+    problem_format: &str,
+    problem: Arc<Problem>,
+) -> GenericResult<Option<ProgressListener<InsertionContext>>> {
+    let include_solution = matches.get_one::<bool>(PROGRESS_SOLUTION_ARG_NAME).copied().unwrap_or(false);
+    if include_solution && problem_format != "pragmatic" {
+        return Err(format!("progress solution is not supported for '{problem_format}' format").into());
+    }
+
+    Ok(matches.get_one::<String>(PROGRESS_ARG_NAME).map(|path| {
+        let writer: Box<dyn Write + Send> =
+            if path == "-" { Box::new(std::io::stdout()) } else { Box::new(create_file(path, "progress")) };
+        let writer = Mutex::new(BufWriter::new(writer));
+
+        create_progress_listener(
+            problem,
+            include_solution,
+            Box::new(move |line| {
+                let mut writer = writer.lock().expect("cannot lock progress writer");
+                // NOTE flush each event, so it can be consumed immediately
+                let _ = writeln!(writer, "{line}").and_then(|_| writer.flush());
+            }),
+        )
+    }))
 }
 
 fn get_min_cv(matches: &ArgMatches) -> GenericResult<Option<(String, usize, f64, bool)>> {
@@ -451,6 +743,27 @@ fn get_min_cv(matches: &ArgMatches) -> GenericResult<Option<(String, usize, f64,
         .unwrap_or(Ok(None))
 }
 
+fn get_max_stagnation(matches: &ArgMatches) -> GenericResult<Option<(String, usize, Vec<f64>)>> {
+    let err_result = Err("cannot parse max_stagnation parameter".into());
+    matches
+        .get_one::<String>(MAX_STAGNATION_ARG_NAME)
+        .map(|arg| match arg.split(',').collect::<Vec<_>>().as_slice() {
+            [interval_type, value, tolerance @ ..] => {
+                let tolerance = tolerance.iter().map(|value| value.parse::<f64>()).collect::<Result<Vec<_>, _>>();
+                match (*interval_type, value.parse::<usize>(), tolerance) {
+                    (interval_type, Ok(value), Ok(tolerance))
+                        if (interval_type == "sample" || interval_type == "period") && value > 0 =>
+                    {
+                        Ok(Some((interval_type.to_string(), value, tolerance)))
+                    }
+                    _ => err_result,
+                }
+            }
+            _ => err_result,
+        })
+        .unwrap_or(Ok(None))
+}
+
 fn get_init_size(matches: &ArgMatches) -> GenericResult<Option<usize>> {
     matches
         .get_one::<String>(INIT_SIZE_ARG_NAME)
@@ -465,11 +778,11 @@ fn get_init_size(matches: &ArgMatches) -> GenericResult<Option<usize>> {
         .unwrap_or(Ok(None))
 }
 
-fn get_environment(matches: &ArgMatches) -> GenericResult<Arc<Environment>> {
+fn get_environment(matches: &ArgMatches, seed: Option<u64>) -> GenericResult<Arc<Environment>> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
     let quota = Some(create_interruption_quota(max_time));
     let is_experimental = matches.get_one::<bool>(EXPERIMENTAL_ARG_NAME).copied().unwrap_or(false);
-    let random = Arc::new(DefaultRandom::new_with_seed(0));
+    let random: Arc<dyn Random + Send + Sync> = Arc::new(seed.map(DefaultRandom::new_with_seed).unwrap_or_default());
 
     matches
         .get_one::<String>(PARALLELISM_ARG_NAME)
@@ -495,7 +808,7 @@ fn get_environment(matches: &ArgMatches) -> GenericResult<Arc<Environment>> {
                 Err("cannot parse parallelism parameter".into())
             }
         })
-        .unwrap_or_else(|| Ok(Arc::new(Environment { quota, is_experimental, ..Environment::default() })))
+        .unwrap_or_else(|| Ok(Arc::new(Environment { random, quota, is_experimental, ..Environment::default() })))
 }
 
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
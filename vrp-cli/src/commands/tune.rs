@@ -0,0 +1,149 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/tune_test.rs"]
+mod tune_test;
+
+use super::*;
+
+use clap::ArgAction;
+use std::io::{BufWriter, Write};
+use std::sync::Arc;
+use vrp_cli::extensions::tune::{self, read_parameter_space, TuneInstance, TuneSettings};
+use vrp_core::prelude::*;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const INSTANCES_ARG_NAME: &str = "INSTANCES";
+const SPACE_ARG_NAME: &str = "space";
+const ITERATIONS_ARG_NAME: &str = "iterations";
+const CANDIDATES_ARG_NAME: &str = "candidates";
+const ELITE_SIZE_ARG_NAME: &str = "elite-size";
+const MIN_INSTANCES_ARG_NAME: &str = "min-instances";
+const GENERATIONS_ARG_NAME: &str = "generations";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+const OUT_REPORT_ARG_NAME: &str = "out-report";
+const LOG_ARG_NAME: &str = "log";
+
+pub fn get_tune_app() -> Command {
+    Command::new("tune")
+        .about("Tunes algorithm configuration on a set of training problem instances")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .value_parser(["pragmatic", "solomon", "lilim", "tsplib"])
+                .index(1),
+        )
+        .arg(
+            Arg::new(INSTANCES_ARG_NAME)
+                .help("Sets the folder with problem instances used for training")
+                .required(true)
+                .index(2),
+        )
+        .arg(
+            Arg::new(SPACE_ARG_NAME)
+                .help("Specifies path to file with parameter space definition")
+                .short('s')
+                .long(SPACE_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(ITERATIONS_ARG_NAME)
+                .help("Specifies amount of racing iterations")
+                .short('i')
+                .long(ITERATIONS_ARG_NAME)
+                .default_value("5"),
+        )
+        .arg(
+            Arg::new(CANDIDATES_ARG_NAME)
+                .help("Specifies amount of candidate configurations raced on each iteration")
+                .long(CANDIDATES_ARG_NAME)
+                .default_value("8"),
+        )
+        .arg(
+            Arg::new(ELITE_SIZE_ARG_NAME)
+                .help("Specifies amount of elite configurations which survive each race")
+                .long(ELITE_SIZE_ARG_NAME)
+                .default_value("2"),
+        )
+        .arg(
+            Arg::new(MIN_INSTANCES_ARG_NAME)
+                .help("Specifies amount of instances seen before the worst candidates are eliminated")
+                .long(MIN_INSTANCES_ARG_NAME)
+                .default_value("2"),
+        )
+        .arg(
+            Arg::new(GENERATIONS_ARG_NAME)
+                .help("Specifies amount of generations used to solve one instance")
+                .short('n')
+                .long(GENERATIONS_ARG_NAME)
+                .default_value("200"),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for tuned config output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_REPORT_ARG_NAME)
+                .help("Specifies path to file for report which compares tuned config against default one")
+                .long(OUT_REPORT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(LOG_ARG_NAME)
+                .help("Specifies whether default logging is enabled")
+                .long(LOG_ARG_NAME)
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub fn run_tune(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+    let instances = read_instances(format, matches.get_one::<String>(INSTANCES_ARG_NAME).unwrap())?;
+    let space = read_parameter_space(BufReader::new(open_file(
+        matches.get_one::<String>(SPACE_ARG_NAME).unwrap(),
+        "parameter space",
+    )))?;
+
+    let settings = TuneSettings {
+        iterations: parse_int_value::<usize>(matches, ITERATIONS_ARG_NAME, "iterations")?.unwrap(),
+        candidates: parse_int_value::<usize>(matches, CANDIDATES_ARG_NAME, "candidates")?.unwrap(),
+        elite_size: parse_int_value::<usize>(matches, ELITE_SIZE_ARG_NAME, "elite size")?.unwrap(),
+        min_instances: parse_int_value::<usize>(matches, MIN_INSTANCES_ARG_NAME, "min instances")?.unwrap(),
+        generations: parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "generations")?.unwrap(),
+    };
+
+    let logger: InfoLogger = if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
+        Arc::new(|msg: &str| println!("{msg}"))
+    } else {
+        Arc::new(|_: &str| {})
+    };
+    let environment = Arc::new(Environment { logger, ..Environment::default() });
+
+    let result = tune::run_tune(&space, instances.as_slice(), &settings, environment)?;
+
+    if let Some(path) = matches.get_one::<String>(OUT_REPORT_ARG_NAME) {
+        let mut writer = BufWriter::new(create_file(path, "out report"));
+        serde_json::to_writer_pretty(&mut writer, &result.report)
+            .map_err(|err| format!("cannot write report: '{err}'"))?;
+        writer.flush()?;
+    }
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out config"));
+    let mut out_buffer = out_writer_func(out_result);
+    serde_json::to_writer_pretty(&mut out_buffer, &result.config)
+        .map_err(|err| format!("cannot write tuned config: '{err}'"))?;
+    out_buffer.flush()?;
+
+    Ok(())
+}
+
+fn read_instances(format: &str, path: &str) -> Result<Vec<TuneInstance>, GenericError> {
+    read_problems(format, path, false).map(|problems| {
+        problems.into_iter().map(|(name, problem)| TuneInstance { name, problem: Arc::new(problem) }).collect()
+    })
+}
@@ -0,0 +1,284 @@
+//! Provides the way to benchmark solver on a set of problem instances against their best known solutions (BKS).
+//!
+//! Each instance is solved multiple times with different seeds, solution cost of each run is compared with BKS
+//! cost and the following statistics is collected per instance: mean, best and worst gaps, mean run time and
+//! time to target, which is time needed to find a solution within the target gap. Runs with unassigned jobs are
+//! not comparable with BKS, so their gap is not calculated.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/bench/bench_test.rs"]
+mod bench_test;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::sync::{Arc, Mutex};
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::{ProgressEvent, ProgressListener};
+use vrp_core::rosomaxa::utils::Timer;
+use vrp_core::solver::{get_complete_solution_cost, is_complete_assignment, Solver, VrpConfigBuilder};
+
+/// A best known solution of a problem instance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BestKnownSolution {
+    /// Amount of used vehicles, if known.
+    pub vehicles: Option<usize>,
+    /// A solution cost.
+    pub cost: f64,
+}
+
+/// Specifies benchmark settings.
+#[derive(Clone, Debug)]
+pub struct BenchSettings {
+    /// Seeds used to solve each instance, one run per seed.
+    pub seeds: Vec<u64>,
+    /// Max amount of generations of one run.
+    pub max_generations: Option<usize>,
+    /// Max time of one run in seconds.
+    pub max_time: Option<usize>,
+    /// A target gap to BKS in percents used to measure time to target.
+    pub target_gap: f64,
+}
+
+/// A result of one solver run.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchRun {
+    /// An instance name.
+    pub instance: String,
+    /// A seed used to solve the instance.
+    pub seed: u64,
+    /// A solution cost.
+    pub cost: f64,
+    /// Amount of used vehicles.
+    pub vehicles: usize,
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+    /// A gap to BKS cost in percents.
+    pub gap: Option<f64>,
+    /// Run duration in seconds.
+    pub duration: f64,
+    /// Time in seconds needed to find a solution within the target gap.
+    pub time_to_target: Option<f64>,
+}
+
+/// A statistic of runs of one instance.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchSummary {
+    /// An instance name.
+    pub instance: String,
+    /// A BKS cost.
+    pub bks_cost: Option<f64>,
+    /// Amount of vehicles in BKS.
+    pub bks_vehicles: Option<usize>,
+    /// Amount of runs.
+    pub runs: usize,
+    /// A best cost found.
+    pub best_cost: f64,
+    /// A mean gap in percents.
+    pub mean_gap: Option<f64>,
+    /// A best gap in percents.
+    pub best_gap: Option<f64>,
+    /// A worst gap in percents.
+    pub worst_gap: Option<f64>,
+    /// A mean run duration in seconds.
+    pub mean_duration: f64,
+    /// Amount of runs which reached target gap.
+    pub hits: usize,
+    /// A mean time to target of runs which reached target gap.
+    pub mean_time_to_target: Option<f64>,
+}
+
+/// A benchmark result.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchResult {
+    /// Statistics per instance.
+    pub summaries: Vec<BenchSummary>,
+    /// All runs.
+    pub runs: Vec<BenchRun>,
+}
+
+/// Reads best known solutions from csv with `instance,vehicles,cost` columns. Vehicles column can be empty.
+/// Empty lines, lines started with `#` and header are skipped.
+pub fn read_best_known_solutions<R: Read>(reader: BufReader<R>) -> GenericResult<HashMap<String, BestKnownSolution>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| line.map(|line| (idx, line)).map_err(GenericError::from))
+        .filter(|result| match result {
+            Ok((_, line)) => !line.trim().is_empty() && !line.trim().starts_with('#'),
+            Err(_) => true,
+        })
+        .filter_map(|result| {
+            result
+                .and_then(|(idx, line)| {
+                    match line.split(',').map(|value| value.trim()).collect::<Vec<_>>().as_slice() {
+                        [instance, vehicles, cost] => match (vehicles.parse::<usize>(), cost.parse::<f64>()) {
+                            (Ok(vehicles), Ok(cost)) => Ok(Some((instance.to_string(), Some(vehicles), cost))),
+                            (_, Ok(cost)) if vehicles.is_empty() => Ok(Some((instance.to_string(), None, cost))),
+                            // NOTE skip header
+                            _ if idx == 0 => Ok(None),
+                            _ => Err(format!("cannot parse best known solution at line {}: '{line}'", idx + 1).into()),
+                        },
+                        _ => Err(format!("expected three columns at line {}: '{line}'", idx + 1).into()),
+                    }
+                })
+                .transpose()
+        })
+        .map(|result| result.map(|(instance, vehicles, cost)| (instance, BestKnownSolution { vehicles, cost })))
+        .collect()
+}
+
+/// Runs benchmark on given instances: each instance is solved once per seed.
+pub fn run_bench(
+    instances: &[(String, Arc<Problem>)],
+    bks: &HashMap<String, BestKnownSolution>,
+    settings: &BenchSettings,
+    logger: InfoLogger,
+) -> GenericResult<BenchResult> {
+    if settings.seeds.is_empty() {
+        return Err("at least one seed is required".into());
+    }
+
+    let runs = instances
+        .iter()
+        .flat_map(|(name, problem)| settings.seeds.iter().map(move |seed| (name, problem, *seed)))
+        .map(|(name, problem, seed)| {
+            let run = run_instance(name, problem.clone(), bks.get(name), settings, seed)?;
+            (logger)(&format!(
+                "instance '{name}' with seed {seed} is solved: cost {:.2}, vehicles {}, duration {:.2}s",
+                run.cost, run.vehicles, run.duration
+            ));
+
+            Ok(run)
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let summaries =
+        instances.iter().map(|(name, _)| create_summary(name, bks.get(name), runs.as_slice())).collect::<Vec<_>>();
+
+    Ok(BenchResult { summaries, runs })
+}
+
+/// Writes summaries of benchmark result in csv format.
+pub fn write_bench_csv<W: Write>(result: &BenchResult, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    let format_f64 = |value: Option<f64>| value.map(|value| format!("{value:.4}")).unwrap_or_default();
+    let format_usize = |value: Option<usize>| value.map(|value| value.to_string()).unwrap_or_default();
+
+    writeln!(
+        writer,
+        "instance,bks_vehicles,bks_cost,runs,best_cost,mean_gap,best_gap,worst_gap,mean_duration,hits,mean_time_to_target"
+    )?;
+
+    result.summaries.iter().try_for_each(|summary| {
+        writeln!(
+            writer,
+            "{},{},{},{},{:.4},{},{},{},{:.4},{},{}",
+            summary.instance,
+            format_usize(summary.bks_vehicles),
+            format_f64(summary.bks_cost),
+            summary.runs,
+            summary.best_cost,
+            format_f64(summary.mean_gap),
+            format_f64(summary.best_gap),
+            format_f64(summary.worst_gap),
+            summary.mean_duration,
+            summary.hits,
+            format_f64(summary.mean_time_to_target),
+        )
+    })?;
+
+    Ok(())
+}
+
+/// Writes benchmark result in json format.
+pub fn write_bench_json<W: Write>(result: &BenchResult, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    serde_json::to_writer_pretty(writer, result).map_err(|err| format!("cannot write bench result: '{err}'").into())
+}
+
+fn run_instance(
+    name: &str,
+    problem: Arc<Problem>,
+    bks: Option<&BestKnownSolution>,
+    settings: &BenchSettings,
+    seed: u64,
+) -> GenericResult<BenchRun> {
+    let environment = Arc::new(Environment {
+        random: Arc::new(DefaultRandom::new_with_seed(seed)),
+        logger: Arc::new(|_: &str| {}),
+        ..Environment::default()
+    });
+
+    let target = bks.map(|bks| bks.cost * (1. + settings.target_gap / 100.));
+    let time_to_target = Arc::new(Mutex::new(None::<f64>));
+
+    let config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(environment)
+        .prebuild()?
+        .with_max_generations(settings.max_generations)
+        .with_max_time(settings.max_time)
+        .with_progress(target.map(|target| {
+            let time_to_target = time_to_target.clone();
+            Box::new(move |event: ProgressEvent<'_, InsertionContext>| {
+                let is_hit = get_complete_solution_cost(event.solution).is_some_and(|cost| cost <= target);
+
+                let mut time_to_target = time_to_target.lock().expect("cannot lock time to target");
+                if is_hit && time_to_target.is_none() {
+                    *time_to_target = Some(event.elapsed);
+                }
+            }) as ProgressListener<InsertionContext>
+        }))
+        .build()?;
+
+    let timer = Timer::start();
+    let solution = Solver::new(problem, config).solve().map_err(|err| format!("cannot solve '{name}': {err}"))?;
+    let duration = timer.elapsed_secs_as_f64();
+
+    // NOTE solutions with unassigned jobs are not comparable with BKS
+    let is_complete = is_complete_assignment(solution.unassigned.iter().map(|(job, _)| job));
+    let gap = bks.filter(|_| is_complete).map(|bks| (solution.cost - bks.cost) / bks.cost * 100.);
+    let time_to_target = *time_to_target.lock().expect("cannot lock time to target");
+
+    Ok(BenchRun {
+        instance: name.to_string(),
+        seed,
+        cost: solution.cost,
+        vehicles: solution.routes.len(),
+        unassigned: solution.unassigned.len(),
+        gap,
+        duration,
+        time_to_target,
+    })
+}
+
+fn create_summary(name: &str, bks: Option<&BestKnownSolution>, runs: &[BenchRun]) -> BenchSummary {
+    let runs = runs.iter().filter(|run| run.instance == name).collect::<Vec<_>>();
+    let size = runs.len() as f64;
+
+    let gaps = runs.iter().filter_map(|run| run.gap).collect::<Vec<_>>();
+    let times_to_target = runs.iter().filter_map(|run| run.time_to_target).collect::<Vec<_>>();
+    let get_mean = |values: &[f64]| {
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().sum::<f64>() / values.len() as f64)
+        }
+    };
+
+    BenchSummary {
+        instance: name.to_string(),
+        bks_cost: bks.map(|bks| bks.cost),
+        bks_vehicles: bks.and_then(|bks| bks.vehicles),
+        runs: runs.len(),
+        best_cost: runs.iter().map(|run| run.cost).fold(f64::MAX, f64::min),
+        mean_gap: get_mean(gaps.as_slice()),
+        best_gap: gaps.iter().copied().reduce(f64::min),
+        worst_gap: gaps.iter().copied().reduce(f64::max),
+        mean_duration: runs.iter().map(|run| run.duration).sum::<f64>() / size,
+        hits: times_to_target.len(),
+        mean_time_to_target: get_mean(times_to_target.as_slice()),
+    }
+}
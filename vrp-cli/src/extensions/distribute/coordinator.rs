@@ -0,0 +1,118 @@
+use super::*;
+use std::cmp::Ordering;
+use vrp_core::rosomaxa::prelude::{HeuristicObjective, HeuristicSolution};
+
+/// Specifies coordinator settings.
+pub struct CoordinatorConfig {
+    /// Amount of workers to wait for.
+    pub workers: usize,
+    /// Amount of search rounds.
+    pub rounds: usize,
+    /// Amount of generations run by each worker within one round.
+    pub generations: usize,
+    /// Max amount of elite solutions sent to workers on each round.
+    pub elite_size: usize,
+    /// Max time to wait for a response from a worker. If it is exceeded, the worker is dropped.
+    /// No limit if not set.
+    pub timeout: Option<Duration>,
+}
+
+/// Runs a coordinator which waits for workers, ships them the problem and exchanges elite solutions
+/// between them. Returns the best found solution in pragmatic format.
+pub fn run_coordinator(
+    listener: &Listener,
+    problem: String,
+    matrices: Vec<String>,
+    config: Option<String>,
+    settings: &CoordinatorConfig,
+    logger: InfoLogger,
+) -> GenericResult<String> {
+    if settings.workers == 0 || settings.rounds == 0 || settings.elite_size == 0 {
+        return Err("amount of workers, rounds and elite size should be greater than zero".into());
+    }
+
+    if settings.timeout.is_some_and(|timeout| timeout.is_zero()) {
+        return Err("worker timeout should be greater than zero".into());
+    }
+
+    let core_problem = Arc::new(read_problem(problem.clone(), matrices.clone())?);
+    let environment = Arc::new(Environment::default());
+
+    (logger)(&format!("waiting for {} worker(-s) on '{}'", settings.workers, listener.address()?));
+    let mut connections = (0..settings.workers)
+        .map(|idx| {
+            let mut connection = listener.accept(settings.timeout)?;
+            connection.send(&Message::Problem {
+                problem: problem.clone(),
+                matrices: matrices.clone(),
+                config: config.clone(),
+            })?;
+            (logger)(&format!("worker {} is connected", idx + 1));
+
+            Ok((idx + 1, connection))
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let mut elites: Vec<(InsertionContext, String)> = Vec::default();
+
+    for round in 0..settings.rounds {
+        let solutions = elites.iter().map(|(_, solution)| solution.clone()).collect::<Vec<_>>();
+
+        // NOTE send all requests first, so workers run their search simultaneously
+        connections.retain_mut(|(idx, connection)| {
+            let result =
+                connection.send(&Message::Solve { generations: settings.generations, solutions: solutions.clone() });
+
+            keep_worker(*idx, result, &logger)
+        });
+
+        connections.retain_mut(|(idx, connection)| {
+            let result = connection.receive().and_then(|message| match message {
+                Message::Solution { solution } => {
+                    let insertion_ctx = read_solution(core_problem.clone(), environment.clone(), solution.as_str())?;
+                    elites.push((insertion_ctx, solution));
+                    Ok(())
+                }
+                _ => Err("unexpected message from worker".into()),
+            });
+
+            keep_worker(*idx, result, &logger)
+        });
+
+        if connections.is_empty() {
+            return Err("all workers are dropped".into());
+        }
+
+        let goal = core_problem.goal.as_ref();
+        elites.sort_by(|(a, _), (b, _)| goal.total_order(a, b));
+        elites.dedup_by(|(a, _), (b, _)| goal.total_order(a, b) == Ordering::Equal);
+        elites.truncate(settings.elite_size);
+
+        if let Some((best, _)) = elites.first() {
+            (logger)(&format!(
+                "round {} is completed, best fitness: {:?}",
+                round + 1,
+                best.fitness().collect::<Vec<_>>()
+            ));
+        }
+    }
+
+    connections.iter_mut().for_each(|(idx, connection)| {
+        if let Err(err) = connection.send(&Message::Stop) {
+            (logger)(&format!("cannot stop worker {idx}: {err}"));
+        }
+    });
+
+    elites.into_iter().next().map(|(_, solution)| solution).ok_or_else(|| "no solution found".into())
+}
+
+/// Returns true if the worker can be used further, otherwise logs the reason why it is dropped.
+fn keep_worker(idx: usize, result: GenericResult<()>, logger: &InfoLogger) -> bool {
+    match result {
+        Ok(_) => true,
+        Err(err) => {
+            (logger)(&format!("worker {idx} is dropped: {err}"));
+            false
+        }
+    }
+}
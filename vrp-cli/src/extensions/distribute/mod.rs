@@ -0,0 +1,226 @@
+//! Provides the way to run one solve across multiple worker processes, possibly on different machines.
+//!
+//! A coordinator ships the problem in `pragmatic` format to connected workers and runs a few rounds of
+//! search: on each round, every worker evolves its own population (island) starting from elite solutions
+//! known by coordinator and sends its best solution back. Worker's population and heuristic state are kept
+//! between rounds. Workers which are disconnected or do not respond in time are dropped, so the search continues
+//! with the remaining ones. Messages are serialized as json, one message per line. Both TCP (`host:port`) and, on
+//! unix platforms, unix socket (`unix:/path/to/socket`) addresses are supported.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/distribute/distribute_test.rs"]
+mod distribute_test;
+
+mod coordinator;
+pub use self::coordinator::*;
+
+mod worker;
+pub use self::worker::*;
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::time::Duration;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::solution::{read_init_solution, write_pragmatic};
+
+#[cfg(unix)]
+use std::os::unix::fs::FileTypeExt;
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+
+const UNIX_ADDRESS_PREFIX: &str = "unix:";
+
+/// A message exchanged between coordinator and workers.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Message {
+    /// Sent by coordinator once worker is connected: contains problem definition in pragmatic format,
+    /// routing matrices and, optionally, algorithm configuration.
+    Problem {
+        /// A problem definition.
+        problem: String,
+        /// Routing matrices.
+        matrices: Vec<String>,
+        /// An algorithm configuration.
+        config: Option<String>,
+    },
+
+    /// Sent by coordinator to start a new round of search from given elite solutions.
+    Solve {
+        /// Amount of generations to run.
+        generations: usize,
+        /// Elite solutions in pragmatic format.
+        solutions: Vec<String>,
+    },
+
+    /// Sent by worker with the best solution found within the round.
+    Solution {
+        /// A solution in pragmatic format.
+        solution: String,
+    },
+
+    /// Sent by coordinator to stop the worker.
+    Stop,
+}
+
+/// A bidirectional connection between coordinator and worker.
+pub struct Connection {
+    reader: BufReader<Box<dyn Read + Send>>,
+    writer: BufWriter<Box<dyn Write + Send>>,
+}
+
+impl Connection {
+    /// Connects to the coordinator listening on given address.
+    pub fn connect(address: &str) -> GenericResult<Self> {
+        match address.strip_prefix(UNIX_ADDRESS_PREFIX) {
+            #[cfg(unix)]
+            Some(path) => {
+                let stream =
+                    UnixStream::connect(path).map_err(|err| format!("cannot connect to '{address}': {err}"))?;
+                Ok(Self::new(Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+            #[cfg(not(unix))]
+            Some(_) => Err(format!("unix sockets are not supported on this platform: '{address}'").into()),
+            None => {
+                let stream =
+                    TcpStream::connect(address).map_err(|err| format!("cannot connect to '{address}': {err}"))?;
+                Ok(Self::new(Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+        }
+    }
+
+    /// Sends a message.
+    pub fn send(&mut self, message: &Message) -> GenericResult<()> {
+        serde_json::to_writer(&mut self.writer, message).map_err(|err| format!("cannot send message: {err}"))?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Receives a message, blocks till it is available.
+    pub fn receive(&mut self) -> GenericResult<Message> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err("connection is closed".into());
+        }
+
+        serde_json::from_str(line.as_str()).map_err(|err| format!("cannot receive message: {err}").into())
+    }
+
+    fn new(reader: Box<dyn Read + Send>, writer: Box<dyn Write + Send>) -> Self {
+        Self { reader: BufReader::new(reader), writer: BufWriter::new(writer) }
+    }
+}
+
+/// Listens for incoming worker connections. A unix socket file is removed when listener is dropped.
+pub enum Listener {
+    /// A TCP listener.
+    Tcp(TcpListener),
+    /// A unix socket listener.
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+impl Listener {
+    /// Binds a new listener to given address.
+    pub fn bind(address: &str) -> GenericResult<Self> {
+        let map_err = |err: std::io::Error| GenericError::from(format!("cannot listen on '{address}': {err}"));
+
+        match address.strip_prefix(UNIX_ADDRESS_PREFIX) {
+            #[cfg(unix)]
+            Some(path) => {
+                remove_stale_socket(path);
+                UnixListener::bind(path).map(Listener::Unix).map_err(map_err)
+            }
+            #[cfg(not(unix))]
+            Some(_) => Err(format!("unix sockets are not supported on this platform: '{address}'").into()),
+            None => TcpListener::bind(address).map(Listener::Tcp).map_err(map_err),
+        }
+    }
+
+    /// Returns an address which can be used by workers to connect.
+    pub fn address(&self) -> GenericResult<String> {
+        match self {
+            Listener::Tcp(listener) => Ok(listener.local_addr()?.to_string()),
+            #[cfg(unix)]
+            Listener::Unix(listener) => listener
+                .local_addr()?
+                .as_pathname()
+                .map(|path| format!("{UNIX_ADDRESS_PREFIX}{}", path.display()))
+                .ok_or_else(|| "unix socket has no path".into()),
+        }
+    }
+
+    /// Accepts a new connection, blocks till it is available. Receiving a message from the connection
+    /// fails if no data is read within `timeout`.
+    pub fn accept(&self, timeout: Option<Duration>) -> GenericResult<Connection> {
+        match self {
+            Listener::Tcp(listener) => {
+                let (stream, _) = listener.accept()?;
+                stream.set_read_timeout(timeout)?;
+                Ok(Connection::new(Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+            #[cfg(unix)]
+            Listener::Unix(listener) => {
+                let (stream, _) = listener.accept()?;
+                stream.set_read_timeout(timeout)?;
+                Ok(Connection::new(Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+        }
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Listener::Unix(listener) = self {
+            // NOTE socket file is kept by OS after the listener is closed, so it would prevent binding again
+            if let Some(path) =
+                listener.local_addr().ok().and_then(|addr| addr.as_pathname().map(|path| path.to_owned()))
+            {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+}
+
+/// Removes a socket file left by a listener which was not closed properly, e.g. killed coordinator.
+/// The file is kept if somebody listens on it.
+#[cfg(unix)]
+fn remove_stale_socket(path: &str) {
+    let is_socket = std::fs::metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket());
+
+    if is_socket && UnixStream::connect(path).is_err() {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn read_problem(problem: String, matrices: Vec<String>) -> GenericResult<Problem> {
+    if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+        .map_err(|err| format!("cannot read problem: {err}").into())
+}
+
+fn read_solution(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    solution: &str,
+) -> GenericResult<InsertionContext> {
+    let solution =
+        read_init_solution(BufReader::new(solution.as_bytes()), problem.clone(), environment.random.clone())?;
+
+    Ok(InsertionContext::new_from_solution(problem, (solution, None), environment))
+}
+
+fn write_solution(problem: &Problem, solution: &Solution) -> GenericResult<String> {
+    let mut writer = BufWriter::new(Vec::new());
+    write_pragmatic(problem, solution, Default::default(), &mut writer)?;
+
+    let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
+
+    String::from_utf8(bytes).map_err(|err| format!("{err}").into())
+}
@@ -0,0 +1,112 @@
+use super::*;
+use crate::extensions::solve::config::{create_builder_from_config, read_config, Config, TerminationConfig};
+use std::sync::Mutex;
+use vrp_core::rosomaxa::evolution::{CheckpointConfig, EvolutionCheckpoint};
+
+/// A search state of the worker's island which is kept between rounds: population and heuristic state.
+pub(crate) type IslandState = Option<EvolutionCheckpoint<InsertionContext>>;
+
+/// Runs a worker which connects to the coordinator on given address, receives the problem and runs
+/// search rounds till coordinator stops it.
+pub fn run_worker(address: &str, logger: InfoLogger) -> GenericResult<()> {
+    let mut connection = Connection::connect(address)?;
+
+    let (problem, config) = match connection.receive()? {
+        Message::Problem { problem, matrices, config } => {
+            let problem = Arc::new(read_problem(problem, matrices)?);
+            let config = config
+                .map(|config| read_config(BufReader::new(config.as_bytes())))
+                .transpose()
+                .map_err(|err| format!("cannot read config: {err}"))?
+                .unwrap_or_default();
+
+            (problem, config)
+        }
+        _ => return Err("expected problem message from coordinator".into()),
+    };
+
+    (logger)(&format!("connected to coordinator on '{address}'"));
+
+    let environment = Arc::new(Environment::default());
+    let mut state: IslandState = None;
+    let mut best: Option<String> = None;
+
+    loop {
+        match connection.receive()? {
+            Message::Solve { generations, solutions } => {
+                // NOTE best solution is already a part of the restored population
+                let previous = best.take().filter(|_| state.is_none());
+                let solutions = previous.iter().chain(solutions.iter()).map(|solution| solution.as_str());
+                let (solution, next_state) =
+                    solve_round(problem.clone(), environment.clone(), &config, generations, solutions, state.take())?;
+
+                connection.send(&Message::Solution { solution: solution.clone() })?;
+                state = next_state;
+                best = Some(solution);
+            }
+            Message::Stop => {
+                (logger)("stopped by coordinator");
+                return Ok(());
+            }
+            _ => return Err("unexpected message from coordinator".into()),
+        }
+    }
+}
+
+/// Runs one search round. The island state from the previous round, if any, is used to resume the search,
+/// so population and learned heuristic state are not lost between rounds. Elite solutions received from
+/// coordinator are added to the restored population. The state is not kept when a custom evolution strategy
+/// (e.g. island model) is configured as it does not support resume.
+pub(crate) fn solve_round<'a>(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    config: &Config,
+    generations: usize,
+    solutions: impl Iterator<Item = &'a str>,
+    state: IslandState,
+) -> GenericResult<(String, IslandState)> {
+    let init_solutions = solutions
+        .map(|solution| read_solution(problem.clone(), environment.clone(), solution))
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    // NOTE generation counter is restored on resume, so the limit includes generations of previous rounds.
+    // The limit is inclusive and checkpoint interval matches the round length, so the state is saved on the
+    // last generation of the round.
+    let generations = generations.max(1);
+    let first_generation = state.as_ref().map_or(0, |state| state.generation + 1);
+    let next_state = Arc::new(Mutex::new(None));
+    let is_resumable = config.evolution.as_ref().and_then(|evolution| evolution.islands.as_ref()).is_none();
+    let checkpoint = if is_resumable {
+        let next_state = next_state.clone();
+        Some(CheckpointConfig::new(
+            generations,
+            Box::new(move |checkpoint| {
+                *next_state.lock().unwrap() = Some(checkpoint);
+                Ok(())
+            }),
+        )?)
+    } else {
+        None
+    };
+
+    let config = Config {
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_generations: Some(first_generation + generations - 1),
+            variation: None,
+            stagnation: None,
+            target_cost: None,
+        }),
+        ..config.clone()
+    };
+
+    let solution = create_builder_from_config(problem.clone(), init_solutions, &config)
+        .and_then(|builder| builder.with_checkpoint(checkpoint).with_resume(state).build())
+        .map(|config| Solver::new(problem.clone(), config))
+        .and_then(|solver| solver.solve())
+        .map_err(|err| format!("cannot find any solution: {err}"))?;
+
+    let next_state = next_state.lock().unwrap().take();
+
+    Ok((write_solution(problem.as_ref(), &solution)?, next_state))
+}
@@ -67,6 +67,8 @@ pub(crate) fn generate_plan(
                 services: generate_tasks(&job_proto.services, true),
                 skills: job_proto.skills.clone(),
                 value: job_proto.value,
+                service_weight: job_proto.service_weight,
+                unassigned_cost: job_proto.unassigned_cost,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
             }
@@ -99,6 +99,8 @@ mod actual {
                 services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
                 skills: None,
                 value: None,
+                service_weight: None,
+                unassigned_cost: None,
                 group: None,
                 compatibility: None,
             })
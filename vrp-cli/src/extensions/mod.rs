@@ -3,9 +3,15 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub mod analyze;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod bench;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod distribute;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tune;
 
 pub mod import;
 pub mod solve;
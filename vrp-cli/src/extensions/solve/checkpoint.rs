@@ -0,0 +1,150 @@
+//! Provides the way to save the search state into checkpoint file and resume the search from it.
+//! Additionally, learned heuristic state can be saved and used as priors for a search of similar problem.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/checkpoint_test.rs"]
+mod checkpoint_test;
+
+use super::create_api_solution;
+use serde::{Deserialize, Serialize};
+use std::fs::{rename, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::{CheckpointConfig, EvolutionCheckpoint};
+use vrp_core::rosomaxa::hyper::{HyperHeuristicState, HyperHeuristicStateHandler};
+use vrp_pragmatic::format::solution::{read_init_solution, serialize_solution, Solution as ApiSolution};
+
+/// A search state saved in checkpoint file.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    /// A last completed generation.
+    pub generation: usize,
+    /// A seed of random generator used by the search, if it is seeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// An internal state of hyper heuristic.
+    pub heuristic: Vec<HeuristicParameter>,
+    /// Solutions in pragmatic format which represent population state.
+    pub solutions: Vec<ApiSolution>,
+}
+
+/// A named hyper heuristic parameter.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct HeuristicParameter {
+    /// A parameter name.
+    pub name: String,
+    /// Parameter values.
+    pub values: Vec<f64>,
+}
+
+impl Checkpoint {
+    /// Creates a checkpoint from the search state.
+    pub fn new(problem: &Problem, checkpoint: EvolutionCheckpoint<InsertionContext>) -> GenericResult<Self> {
+        let solutions = checkpoint
+            .solutions
+            .into_iter()
+            .map(|insertion_ctx| create_api_solution(problem, insertion_ctx))
+            .collect::<GenericResult<Vec<_>>>()?;
+
+        Ok(Self {
+            generation: checkpoint.generation,
+            seed: checkpoint.seed,
+            heuristic: checkpoint
+                .heuristic
+                .into_iter()
+                .map(|(name, values)| HeuristicParameter { name, values })
+                .collect(),
+            solutions,
+        })
+    }
+
+    /// Converts checkpoint into the search state.
+    pub fn into_evolution(
+        self,
+        problem: Arc<Problem>,
+        environment: Arc<Environment>,
+    ) -> GenericResult<EvolutionCheckpoint<InsertionContext>> {
+        let solutions = self
+            .solutions
+            .iter()
+            .map(|solution| {
+                let mut writer = BufWriter::new(Vec::new());
+                serialize_solution(solution, &mut writer).map_err(|err| err.to_string())?;
+                let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
+
+                let solution =
+                    read_init_solution(BufReader::new(bytes.as_slice()), problem.clone(), environment.random.clone())?;
+
+                Ok(InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone()))
+            })
+            .collect::<GenericResult<Vec<_>>>()?;
+
+        Ok(EvolutionCheckpoint {
+            generation: self.generation,
+            solutions,
+            heuristic: self.heuristic.into_iter().map(|parameter| (parameter.name, parameter.values)).collect(),
+            seed: self.seed,
+        })
+    }
+}
+
+/// Reads checkpoint from json.
+pub fn read_checkpoint<R: Read>(reader: BufReader<R>) -> GenericResult<Checkpoint> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot read checkpoint: {err}").into())
+}
+
+/// Writes checkpoint as json.
+pub fn write_checkpoint<W: Write>(checkpoint: &Checkpoint, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    serde_json::to_writer(writer, checkpoint).map_err(|err| format!("cannot write checkpoint: {err}").into())
+}
+
+/// Creates a checkpoint configuration which saves the search state into a file at given path each
+/// `interval` generations. The file is replaced only when a new checkpoint is completely written.
+pub fn create_file_checkpoint(
+    problem: Arc<Problem>,
+    path: PathBuf,
+    interval: usize,
+) -> GenericResult<CheckpointConfig<InsertionContext>> {
+    CheckpointConfig::new(
+        interval,
+        Box::new(move |checkpoint| {
+            let checkpoint = Checkpoint::new(problem.as_ref(), checkpoint)?;
+
+            let tmp_path = path.with_extension("tmp");
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            write_checkpoint(&checkpoint, &mut writer)?;
+            writer.flush()?;
+            drop(writer);
+
+            rename(&tmp_path, &path).map_err(|err| format!("cannot write checkpoint file: {err}").into())
+        }),
+    )
+}
+
+/// Reads heuristic priors from json.
+pub fn read_heuristic_priors<R: Read>(reader: BufReader<R>) -> GenericResult<HyperHeuristicState> {
+    serde_json::from_reader::<_, Vec<HeuristicParameter>>(reader)
+        .map(|parameters| parameters.into_iter().map(|parameter| (parameter.name, parameter.values)).collect())
+        .map_err(|err| format!("cannot read heuristic priors: {err}").into())
+}
+
+/// Writes heuristic state as json, so it can be used as priors later.
+pub fn write_heuristic_priors<W: Write>(state: HyperHeuristicState, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    let parameters = state.into_iter().map(|(name, values)| HeuristicParameter { name, values }).collect::<Vec<_>>();
+
+    serde_json::to_writer(writer, &parameters).map_err(|err| format!("cannot write heuristic priors: {err}").into())
+}
+
+/// Creates a handler which saves heuristic state learned by the end of the search into a file at given path.
+pub fn create_file_priors_export(path: PathBuf) -> HyperHeuristicStateHandler {
+    Box::new(move |state| {
+        let mut writer = BufWriter::new(File::create(&path)?);
+        write_heuristic_priors(state, &mut writer)?;
+
+        writer.flush().map_err(|err| format!("cannot write heuristic priors file: {err}").into())
+    })
+}
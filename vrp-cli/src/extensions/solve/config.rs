@@ -14,10 +14,15 @@ use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::GoalContext;
 use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::strategies::{IslandHeuristic, IslandModel};
 use vrp_core::rosomaxa::evolution::{InitialOperator, TelemetryMode};
-use vrp_core::rosomaxa::get_default_selection_size;
+use vrp_core::rosomaxa::hyper::{
+    AcceptanceCriterion, CrossoverSearch, LateAcceptance, RecordToRecord, SimulatedAnnealing,
+};
 use vrp_core::rosomaxa::prelude::*;
+use vrp_core::rosomaxa::termination::TargetCost;
 use vrp_core::rosomaxa::utils::*;
+use vrp_core::rosomaxa::{get_default_population, get_default_selection_size};
 use vrp_core::solver::search::*;
 use vrp_core::solver::RecreateInitialOperator;
 use vrp_core::solver::*;
@@ -45,6 +50,23 @@ pub struct Config {
 pub struct EvolutionConfig {
     pub initial: Option<InitialConfig>,
     pub population: Option<PopulationType>,
+    /// Specifies island model configuration. Default is a single population.
+    pub islands: Option<IslandsConfig>,
+}
+
+/// An island model configuration: multiple populations are evolved in parallel and periodically
+/// exchange their best individuals.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IslandsConfig {
+    /// Amount of islands including the main one.
+    pub count: usize,
+    /// Amount of generations between migrations. Default is 100.
+    pub migration_interval: Option<usize>,
+    /// Amount of best individuals sent to the next island. Default is 2.
+    pub migration_size: Option<usize>,
+    /// Hyper heuristics assigned to islands in round-robin order. Default is the main hyper heuristic.
+    pub hypers: Option<Vec<HyperType>>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -134,6 +156,48 @@ pub enum HyperType {
     /// learning technics.
     #[serde(rename(deserialize = "dynamic-selective"))]
     DynamicSelective,
+
+    /// An adaptive large neighbourhood search hyper heuristic which selects operator from the predefined
+    /// list using roulette wheel with weights adjusted by operator scores.
+    #[serde(rename(deserialize = "adaptive-selective"))]
+    #[serde(rename_all = "camelCase")]
+    AdaptiveSelective {
+        /// An acceptance criterion of new solutions. Default is simulated annealing.
+        acceptance: Option<AcceptanceType>,
+        /// Amount of iterations after which operator weights are updated. Default is 100.
+        segment_size: Option<usize>,
+        /// Specifies how quickly operator weights react to their scores. Default is 0.1.
+        reaction_factor: Option<f64>,
+    },
+}
+
+/// An acceptance criterion configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum AcceptanceType {
+    /// Accepts worse solutions with probability which decreases with temperature.
+    #[serde(rename(deserialize = "simulated-annealing"))]
+    #[serde(rename_all = "camelCase")]
+    SimulatedAnnealing {
+        /// Initial temperature specified as relative fitness difference.
+        initial_temperature: f64,
+        /// Final temperature specified as relative fitness difference.
+        final_temperature: f64,
+    },
+
+    /// Accepts solutions which are not worse than best known by more than a relative threshold.
+    #[serde(rename(deserialize = "record-to-record"))]
+    RecordToRecord {
+        /// Initial relative threshold.
+        threshold: f64,
+    },
+
+    /// Accepts solutions which are not worse than current solution some iterations ago.
+    #[serde(rename(deserialize = "late-acceptance"))]
+    LateAcceptance {
+        /// A length of history.
+        length: usize,
+    },
 }
 
 /// A operator configuration.
@@ -152,6 +216,20 @@ pub enum SearchOperatorType {
         probability: OperatorProbabilityType,
     },
 
+    /// A large scale decomposition which optimizes subproblems built from seed route and its nearest
+    /// routes (POPMUSIC).
+    #[serde(rename(deserialize = "popmusic"))]
+    Popmusic {
+        /// Amount of routes in one subproblem.
+        routes: usize,
+        /// Amount of attempts to repeat refinement of each subproblem.
+        repeat: usize,
+        /// Max amount of rounds within one operator call.
+        rounds: usize,
+        /// Probability of operator.
+        probability: OperatorProbabilityType,
+    },
+
     /// A local search heuristic.
     #[serde(rename(deserialize = "local-search"))]
     LocalSearch {
@@ -163,6 +241,36 @@ pub enum SearchOperatorType {
         operators: Vec<LocalOperatorType>,
     },
 
+    /// A crossover which recombines solution with another individual from population using
+    /// selective route exchange.
+    #[serde(rename(deserialize = "crossover"))]
+    #[serde(rename_all = "camelCase")]
+    Crossover {
+        /// Probability of operator.
+        probability: OperatorProbabilityType,
+        /// Max amount of routes taken from another individual.
+        max_routes: usize,
+        /// Recreate methods used to insert jobs left unassigned after exchange.
+        recreates: Vec<RecreateMethod>,
+    },
+
+    /// A route-based matheuristic which collects routes of accepted solutions in the pool and
+    /// recombines them by solving set partitioning problem.
+    #[serde(rename(deserialize = "set-partitioning"))]
+    #[serde(rename_all = "camelCase")]
+    SetPartitioning {
+        /// Probability of operator.
+        probability: OperatorProbabilityType,
+        /// Max amount of routes kept in the pool.
+        pool_size: usize,
+        /// Amount of best known solutions used to collect routes.
+        max_solutions: usize,
+        /// Max amount of nodes explored by branch and bound search.
+        max_nodes: usize,
+        /// Recreate methods used to insert jobs left unassigned after recombination.
+        recreates: Vec<RecreateMethod>,
+    },
+
     /// A ruin and recreate metaheuristic settings.
     #[serde(rename(deserialize = "ruin-recreate"))]
     RuinRecreate {
@@ -302,6 +410,9 @@ pub enum RecreateMethod {
     /// Insertion with regret method.
     #[serde(rename(deserialize = "regret"))]
     Regret { weight: usize, start: usize, end: usize },
+    /// Cheapest insertion followed by ejection chains of given depth for jobs left unassigned.
+    #[serde(rename(deserialize = "ejection-chain"))]
+    EjectionChain { weight: usize, depth: usize, ejections: usize },
 }
 
 /// A local search configuration.
@@ -322,6 +433,18 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt"))]
+    TwoOpt { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt-star"))]
+    TwoOptStar { weight: usize },
+
+    #[serde(rename(deserialize = "or-opt"))]
+    OrOpt { weight: usize },
+
+    #[serde(rename(deserialize = "cross-exchange"))]
+    CrossExchange { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -337,6 +460,8 @@ pub struct TerminationConfig {
     pub max_time: Option<usize>,
     pub max_generations: Option<usize>,
     pub variation: Option<VariationConfig>,
+    pub stagnation: Option<StagnationConfig>,
+    pub target_cost: Option<f64>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -348,6 +473,14 @@ pub struct VariationConfig {
     is_global: bool,
 }
 
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StagnationConfig {
+    interval_type: String,
+    value: usize,
+    tolerance: Option<Vec<f64>>,
+}
+
 /// A telemetry config.
 #[derive(Clone, Deserialize, Debug)]
 pub struct TelemetryConfig {
@@ -389,6 +522,10 @@ pub struct EnvironmentConfig {
 
     /// Specifies experimental behavior flag.
     pub is_experimental: Option<bool>,
+
+    /// Specifies a seed of random generator. When set, solving is deterministic for the same
+    /// parallelism configuration.
+    pub seed: Option<u64>,
 }
 
 /// Data parallelism configuration.
@@ -469,55 +606,7 @@ fn configure_from_evolution(
         }
 
         if let Some(variation) = &config.population {
-            let default_selection_size = get_default_selection_size(environment.as_ref());
-            let population = match &variation {
-                PopulationType::Greedy { selection_size } => Box::new(GreedyPopulation::new(
-                    problem.goal.clone(),
-                    selection_size.unwrap_or(default_selection_size),
-                    None,
-                )),
-                PopulationType::Elitism { max_size, selection_size } => Box::new(ElitismPopulation::new(
-                    problem.goal.clone(),
-                    environment.random.clone(),
-                    max_size.unwrap_or(4),
-                    selection_size.unwrap_or(default_selection_size),
-                )) as TargetPopulation,
-                PopulationType::Rosomaxa {
-                    max_elite_size,
-                    max_node_size,
-                    spread_factor,
-                    distribution_factor,
-                    selection_size,
-                    rebalance_memory,
-                    exploration_ratio,
-                } => {
-                    let mut config = RosomaxaConfig::new_with_defaults(default_selection_size);
-                    if let Some(selection_size) = selection_size {
-                        config.selection_size = *selection_size;
-                    }
-                    if let Some(max_elite_size) = max_elite_size {
-                        config.elite_size = *max_elite_size;
-                    }
-                    if let Some(max_node_size) = max_node_size {
-                        config.node_size = *max_node_size;
-                    }
-                    if let Some(spread_factor) = spread_factor {
-                        config.spread_factor = *spread_factor;
-                    }
-                    if let Some(distribution_factor) = distribution_factor {
-                        config.distribution_factor = *distribution_factor;
-                    }
-                    if let Some(rebalance_memory) = rebalance_memory {
-                        config.rebalance_memory = *rebalance_memory;
-                    }
-                    if let Some(exploration_ratio) = exploration_ratio {
-                        config.exploration_ratio = *exploration_ratio;
-                    }
-
-                    Box::new(RosomaxaPopulation::new(problem.goal.clone(), environment.clone(), config)?)
-                }
-            };
-
+            let population = create_population(problem.clone(), environment.clone(), variation)?;
             builder = builder.with_context(RefinementContext::new(problem, population, telemetry_mode, environment));
         }
     }
@@ -525,6 +614,61 @@ fn configure_from_evolution(
     Ok(builder)
 }
 
+fn create_population(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    variation: &PopulationType,
+) -> GenericResult<TargetPopulation> {
+    let default_selection_size = get_default_selection_size(environment.as_ref());
+    Ok(match variation {
+        PopulationType::Greedy { selection_size } => Box::new(GreedyPopulation::new(
+            problem.goal.clone(),
+            selection_size.unwrap_or(default_selection_size),
+            None,
+        )),
+        PopulationType::Elitism { max_size, selection_size } => Box::new(ElitismPopulation::new(
+            problem.goal.clone(),
+            environment.random.clone(),
+            max_size.unwrap_or(4),
+            selection_size.unwrap_or(default_selection_size),
+        )) as TargetPopulation,
+        PopulationType::Rosomaxa {
+            max_elite_size,
+            max_node_size,
+            spread_factor,
+            distribution_factor,
+            selection_size,
+            rebalance_memory,
+            exploration_ratio,
+        } => {
+            let mut config = RosomaxaConfig::new_with_defaults(default_selection_size);
+            if let Some(selection_size) = selection_size {
+                config.selection_size = *selection_size;
+            }
+            if let Some(max_elite_size) = max_elite_size {
+                config.elite_size = *max_elite_size;
+            }
+            if let Some(max_node_size) = max_node_size {
+                config.node_size = *max_node_size;
+            }
+            if let Some(spread_factor) = spread_factor {
+                config.spread_factor = *spread_factor;
+            }
+            if let Some(distribution_factor) = distribution_factor {
+                config.distribution_factor = *distribution_factor;
+            }
+            if let Some(rebalance_memory) = rebalance_memory {
+                config.rebalance_memory = *rebalance_memory;
+            }
+            if let Some(exploration_ratio) = exploration_ratio {
+                config.exploration_ratio = *exploration_ratio;
+            }
+
+            Box::new(RosomaxaPopulation::new(problem.goal.clone(), environment.clone(), config)?)
+        }
+    })
+}
+
 fn configure_from_hyper(
     mut builder: ProblemConfigBuilder,
     problem: Arc<Problem>,
@@ -532,30 +676,109 @@ fn configure_from_hyper(
     hyper_config: &Option<HyperType>,
 ) -> Result<ProblemConfigBuilder, GenericError> {
     if let Some(config) = hyper_config {
-        match config {
-            HyperType::StaticSelective { operators } => {
-                let static_selective = if let Some(operators) = operators {
-                    let heuristic_group = operators
-                        .iter()
-                        .map(|operator| create_operator(problem.clone(), environment.clone(), operator))
-                        .collect::<Result<Vec<_>, _>>()?;
-                    get_static_heuristic_from_heuristic_group(problem.clone(), environment.clone(), heuristic_group)
-                } else {
-                    get_static_heuristic(problem, environment)
-                };
+        builder = builder.with_heuristic(create_heuristic(problem, environment, config)?);
+    }
 
-                builder = builder.with_heuristic(Box::new(static_selective));
-            }
-            HyperType::DynamicSelective => {
-                let dynamic_selective = get_dynamic_heuristic(problem, environment);
-                builder = builder.with_heuristic(Box::new(dynamic_selective));
-            }
+    Ok(builder)
+}
+
+fn configure_from_islands(
+    mut builder: ProblemConfigBuilder,
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    config: &Config,
+) -> Result<ProblemConfigBuilder, GenericError> {
+    let evolution_config = config.evolution.as_ref();
+    if let Some(islands) = evolution_config.and_then(|evolution| evolution.islands.as_ref()) {
+        if islands.count == 0 {
+            return Err("amount of islands should be greater than zero".into());
         }
+
+        let hypers = islands.hypers.as_ref().filter(|hypers| !hypers.is_empty());
+        let heuristics =
+            (0..islands.count)
+                .map(|idx| match hypers.map(|hypers| &hypers[idx % hypers.len()]).or(config.hyper.as_ref()) {
+                    Some(hyper) => create_heuristic(problem.clone(), environment.clone(), hyper),
+                    None => Ok(Box::new(get_dynamic_heuristic(problem.clone(), environment.clone()))
+                        as IslandHeuristic<_, _, _>),
+                })
+                .collect::<GenericResult<Vec<_>>>()?;
+
+        // NOTE only the main island reports telemetry
+        let contexts = (1..islands.count)
+            .map(|_| {
+                let population = match evolution_config.and_then(|evolution| evolution.population.as_ref()) {
+                    Some(variation) => create_population(problem.clone(), environment.clone(), variation)?,
+                    None => get_default_population(
+                        problem.goal.clone(),
+                        environment.clone(),
+                        get_default_selection_size(environment.as_ref()),
+                    ),
+                };
+
+                Ok(RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone()))
+            })
+            .collect::<GenericResult<Vec<_>>>()?;
+
+        builder = builder.with_strategy(Box::new(IslandModel::new(
+            heuristics,
+            contexts,
+            islands.migration_interval.unwrap_or(100),
+            islands.migration_size.unwrap_or(2),
+            1,
+        )?));
     }
 
     Ok(builder)
 }
 
+fn create_heuristic(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    config: &HyperType,
+) -> GenericResult<IslandHeuristic<RefinementContext, GoalContext, InsertionContext>> {
+    Ok(match config {
+        HyperType::StaticSelective { operators } => {
+            let static_selective = if let Some(operators) = operators {
+                let heuristic_group = operators
+                    .iter()
+                    .map(|operator| create_operator(problem.clone(), environment.clone(), operator))
+                    .collect::<Result<Vec<_>, _>>()?;
+                get_static_heuristic_from_heuristic_group(problem.clone(), environment.clone(), heuristic_group)
+            } else {
+                get_static_heuristic(problem, environment)
+            };
+
+            Box::new(static_selective)
+        }
+        HyperType::DynamicSelective => Box::new(get_dynamic_heuristic(problem, environment)),
+        HyperType::AdaptiveSelective { acceptance, segment_size, reaction_factor } => {
+            let acceptance = create_acceptance(acceptance, environment.clone());
+            Box::new(get_adaptive_heuristic(
+                problem,
+                environment,
+                acceptance,
+                segment_size.unwrap_or(100),
+                reaction_factor.unwrap_or(0.1),
+            ))
+        }
+    })
+}
+
+fn create_acceptance(
+    acceptance: &Option<AcceptanceType>,
+    environment: Arc<Environment>,
+) -> Box<dyn AcceptanceCriterion> {
+    match acceptance {
+        Some(AcceptanceType::SimulatedAnnealing { initial_temperature, final_temperature }) => {
+            Box::new(SimulatedAnnealing::new(*initial_temperature, *final_temperature, environment.random.clone()))
+        }
+        Some(AcceptanceType::RecordToRecord { threshold }) => Box::new(RecordToRecord::new(*threshold)),
+        Some(AcceptanceType::LateAcceptance { length }) => Box::new(LateAcceptance::new(*length)),
+        None => Box::new(SimulatedAnnealing::new(0.01, 0.0001, environment.random.clone())),
+    }
+}
+
 fn configure_from_termination(
     mut builder: ProblemConfigBuilder,
     termination_config: &Option<TerminationConfig>,
@@ -565,6 +788,18 @@ fn configure_from_termination(
             config.variation.as_ref().map(|v| (v.interval_type.clone(), v.value, v.cv, v.is_global)),
             "min_cv".to_string(),
         );
+        builder = builder.with_max_stagnation(
+            config
+                .stagnation
+                .as_ref()
+                .map(|s| (s.interval_type.clone(), s.value, s.tolerance.clone().unwrap_or_default())),
+            "max_stagnation".to_string(),
+        );
+
+        if let Some(target_cost) = config.target_cost {
+            builder =
+                builder.with_termination(Box::new(TargetCost::new(target_cost, Box::new(get_complete_solution_cost))));
+        }
     }
 
     builder
@@ -589,6 +824,10 @@ fn create_recreate_method(
         RecreateMethod::Regret { weight, start, end } => {
             (Arc::new(RecreateWithRegret::new(*start, *end, random)), *weight)
         }
+        RecreateMethod::EjectionChain { weight, depth, ejections } => {
+            let inner = Arc::new(RecreateWithCheapest::new(random));
+            (Arc::new(RecreateWithEjectionChain::new(inner, *depth, *ejections)), *weight)
+        }
         RecreateMethod::Perturbation { weight, probability, min, max } => {
             let noise = Noise::new_with_addition(*probability, (*min, *max), random.clone());
             (Arc::new(RecreateWithPerturbation::new(noise, random.clone())), *weight)
@@ -618,6 +857,49 @@ fn create_operator(
             let operator = create_local_search(times, inners, environment.random.clone());
             (Arc::new(LocalSearch::new(operator)), create_operator_probability(probability, environment.random.clone()))
         }
+        SearchOperatorType::Crossover { probability, max_routes, recreates } => {
+            if *max_routes < 1 {
+                return Err(format!("max routes must be greater than 0. Specified: {max_routes}").into());
+            }
+
+            let recreate = Arc::new(WeightedRecreate::new(
+                recreates.iter().map(|r| create_recreate_method(r, environment.clone())).collect(),
+            ));
+            (
+                Arc::new(CrossoverSearch::new(Arc::new(SelectiveRouteExchange::new(recreate, *max_routes)))),
+                create_operator_probability(probability, environment.random.clone()),
+            )
+        }
+        SearchOperatorType::Popmusic { routes, repeat, rounds, probability } => {
+            if *routes < 2 {
+                return Err(format!("routes must be greater than 1. Specified: {routes}").into());
+            }
+            if *repeat < 1 || *rounds < 1 {
+                return Err(format!("repeat and rounds must be greater than 0. Specified: {repeat}, {rounds}").into());
+            }
+
+            let operator = create_default_heuristic_operator(problem, environment.clone());
+            (
+                Arc::new(PopmusicSearch::new(operator, *routes, *repeat, *rounds, 200)),
+                create_operator_probability(probability, environment.random.clone()),
+            )
+        }
+        SearchOperatorType::SetPartitioning { probability, pool_size, max_solutions, max_nodes, recreates } => {
+            if *pool_size < 1 || *max_nodes < 1 {
+                return Err(format!(
+                    "pool size and max nodes must be greater than 0. Specified: {pool_size}, {max_nodes}"
+                )
+                .into());
+            }
+
+            let recreate = Arc::new(WeightedRecreate::new(
+                recreates.iter().map(|r| create_recreate_method(r, environment.clone())).collect(),
+            ));
+            (
+                Arc::new(SetPartitioningSearch::new(recreate, *pool_size, *max_solutions, *max_nodes)),
+                create_operator_probability(probability, environment.random.clone()),
+            )
+        }
         SearchOperatorType::Decomposition { routes, repeat, probability } => {
             if *repeat < 1 {
                 return Err(format!("repeat must be greater than 1. Specified: {repeat}").into());
@@ -716,6 +998,10 @@ fn create_local_search(
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::TwoOpt { weight } => (Arc::new(TwoOpt::default()), *weight),
+            LocalOperatorType::TwoOptStar { weight } => (Arc::new(TwoOptStar::default()), *weight),
+            LocalOperatorType::OrOpt { weight } => (Arc::new(OrOpt::default()), *weight),
+            LocalOperatorType::CrossExchange { weight } => (Arc::new(CrossExchange::default()), *weight),
         })
         .collect::<Vec<_>>();
 
@@ -787,6 +1073,10 @@ fn configure_from_environment(
         environment.is_experimental = is_experimental;
     }
 
+    if let Some(seed) = environment_config.as_ref().and_then(|c| c.seed) {
+        environment.random = Arc::new(DefaultRandom::new_with_seed(seed));
+    }
+
     Arc::new(environment)
 }
 
@@ -824,7 +1114,8 @@ pub fn create_builder_from_config(
 
     builder =
         configure_from_evolution(builder, problem.clone(), environment.clone(), telemetry_mode, &config.evolution)?;
-    builder = configure_from_hyper(builder, problem, environment, &config.hyper)?;
+    builder = configure_from_hyper(builder, problem.clone(), environment.clone(), &config.hyper)?;
+    builder = configure_from_islands(builder, problem, environment, config)?;
     builder = configure_from_termination(builder, &config.termination);
 
     Ok(builder)
@@ -1,3 +1,19 @@
 //! Solve command helpers
 
+pub mod checkpoint;
 pub mod config;
+pub mod progress;
+
+use std::io::{BufReader, BufWriter};
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+use vrp_pragmatic::format::solution::{deserialize_solution, write_pragmatic, Solution as ApiSolution};
+
+/// Converts a solution represented by insertion context into `pragmatic` format.
+pub(crate) fn create_api_solution(problem: &Problem, insertion_ctx: InsertionContext) -> GenericResult<ApiSolution> {
+    let mut writer = BufWriter::new(Vec::new());
+    write_pragmatic(problem, &Solution::from(insertion_ctx), Default::default(), &mut writer)?;
+    let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
+
+    deserialize_solution(BufReader::new(bytes.as_slice())).map_err(|err| err.to_string().into())
+}
@@ -0,0 +1,71 @@
+//! Provides the way to stream search progress as json lines: one event per improvement of the best known solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/progress_test.rs"]
+mod progress_test;
+
+use super::create_api_solution;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::ProgressListener;
+use vrp_core::rosomaxa::prelude::HeuristicSolution;
+use vrp_pragmatic::format::solution::Solution as ApiSolution;
+
+/// A progress event emitted when the best known solution is improved.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressRecord {
+    /// A generation number.
+    pub generation: usize,
+    /// Elapsed seconds since the search start.
+    pub elapsed: f64,
+    /// A fitness vector of the new best known solution.
+    pub fitness: Vec<f64>,
+    /// A new best known solution in pragmatic format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<ApiSolution>,
+}
+
+/// A function which receives progress events serialized as a single json line.
+pub type ProgressHandler = Box<dyn Fn(String) + Send + Sync>;
+
+/// Reads progress record from a json line.
+pub fn read_progress_record(line: &str) -> GenericResult<ProgressRecord> {
+    serde_json::from_str(line).map_err(|err| format!("cannot read progress record: {err}").into())
+}
+
+/// Creates a progress listener which serializes events as json lines and passes them to the handler.
+/// When `include_solution` is set, the full pragmatic solution is added to each event.
+pub fn create_progress_listener(
+    problem: Arc<Problem>,
+    include_solution: bool,
+    handler: ProgressHandler,
+) -> ProgressListener<InsertionContext> {
+    Box::new(move |event| {
+        let solution = if include_solution {
+            match create_api_solution(problem.as_ref(), event.solution.deep_copy()) {
+                Ok(solution) => Some(solution),
+                Err(err) => {
+                    (event.solution.environment.logger)(&format!("cannot write progress solution: {err}"));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let record = ProgressRecord {
+            generation: event.generation,
+            elapsed: event.elapsed,
+            fitness: event.solution.fitness().collect(),
+            solution,
+        };
+
+        match serde_json::to_string(&record) {
+            Ok(line) => (handler)(line),
+            Err(err) => (event.solution.environment.logger)(&format!("cannot write progress event: {err}")),
+        }
+    })
+}
@@ -0,0 +1,413 @@
+//! Provides the way to tune algorithm configuration for a family of problem instances.
+//!
+//! Tuning is done with a simplified iterated racing: on each iteration, candidate configurations are sampled
+//! from the user defined parameter space around elite candidates found so far. Candidates are raced on training
+//! instances one by one and the worst ranked half is eliminated after each instance once enough instances are
+//! seen. Survivors become elites of the next iteration.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/tune/tune_test.rs"]
+mod tune_test;
+
+use crate::extensions::solve::config::{create_builder_from_config, Config, TerminationConfig};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::EvolutionSimulator;
+use vrp_core::rosomaxa::prelude::{HeuristicObjective, HeuristicSolution};
+
+/// Specifies a parameter space to search in.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParameterSpace {
+    /// A base config which sampled parameter values are applied to. Default config is used when omitted.
+    pub base: Option<Value>,
+    /// Parameters to tune.
+    pub parameters: Vec<Parameter>,
+}
+
+/// Specifies a single tunable parameter.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Parameter {
+    /// A json pointer to the config property, e.g. `/evolution/population/selectionSize`.
+    pub path: String,
+    /// A domain of parameter values.
+    #[serde(flatten)]
+    pub domain: ParameterDomain,
+}
+
+/// Specifies a domain of parameter values.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ParameterDomain {
+    /// An integer value within inclusive range.
+    Int {
+        /// A min value.
+        min: i32,
+        /// A max value.
+        max: i32,
+    },
+
+    /// A real value within range.
+    Float {
+        /// A min value.
+        min: f64,
+        /// A max value.
+        max: f64,
+    },
+
+    /// One of the listed values.
+    Categorical {
+        /// Possible values.
+        values: Vec<Value>,
+    },
+}
+
+/// Specifies tuning settings.
+#[derive(Clone, Debug)]
+pub struct TuneSettings {
+    /// Amount of racing iterations.
+    pub iterations: usize,
+    /// Amount of candidates raced on each iteration.
+    pub candidates: usize,
+    /// Amount of elite candidates which survive the race.
+    pub elite_size: usize,
+    /// Amount of instances seen before candidates are eliminated.
+    pub min_instances: usize,
+    /// Amount of generations used to solve one instance.
+    pub generations: usize,
+}
+
+/// A training problem instance.
+pub struct TuneInstance {
+    /// An instance name.
+    pub name: String,
+    /// A problem definition.
+    pub problem: Arc<Problem>,
+}
+
+/// A tuning result.
+pub struct TuneResult {
+    /// A tuned config.
+    pub config: Value,
+    /// A report which compares tuned config against default one.
+    pub report: TuneReport,
+}
+
+/// A report which compares tuned config against default one.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TuneReport {
+    /// Amount of solver runs made while racing.
+    pub evaluations: usize,
+    /// Amount of instances where tuned config found a better solution.
+    pub wins: usize,
+    /// Amount of instances where tuned config found a worse solution.
+    pub losses: usize,
+    /// Amount of instances where both configs found equally good solutions.
+    pub ties: usize,
+    /// Per instance comparison.
+    pub instances: Vec<InstanceReport>,
+}
+
+/// A comparison of solutions found for one instance.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceReport {
+    /// An instance name.
+    pub name: String,
+    /// A fitness of solution found with default config.
+    pub default: Vec<f64>,
+    /// A fitness of solution found with tuned config.
+    pub tuned: Vec<f64>,
+}
+
+/// A candidate configuration: parameter values in the order of parameter space definition.
+/// No value means that value from the base config is kept.
+type Candidate = Vec<Option<Value>>;
+
+/// Reads parameter space from reader.
+pub fn read_parameter_space<R: Read>(reader: BufReader<R>) -> GenericResult<ParameterSpace> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize parameter space: '{err}'").into())
+}
+
+/// Runs tuning of algorithm configuration on given training instances.
+pub fn run_tune(
+    space: &ParameterSpace,
+    instances: &[TuneInstance],
+    settings: &TuneSettings,
+    environment: Arc<Environment>,
+) -> GenericResult<TuneResult> {
+    validate(space, instances, settings)?;
+
+    let random = environment.random.as_ref();
+    let base = space.base.clone().unwrap_or_else(|| Value::Object(Map::default()));
+    let default: Candidate = space.parameters.iter().map(|parameter| base.pointer(&parameter.path).cloned()).collect();
+
+    let mut elites = vec![default.clone()];
+    let mut evaluations = 0;
+
+    for iteration in 0..settings.iterations {
+        let spread = 1. / (iteration + 1) as f64;
+        let mut candidates = elites.clone();
+        while candidates.len() < settings.candidates {
+            let parent = &elites[random.uniform_int(0, elites.len() as i32 - 1) as usize];
+            candidates.push(sample_candidate(space, parent, spread, random));
+        }
+
+        // NOTE start each race from a different instance as the first ones have the biggest impact on elimination
+        let mut order = (0..instances.len()).collect::<Vec<_>>();
+        order.rotate_left(iteration % instances.len());
+
+        let instances = order.iter().map(|&idx| &instances[idx]);
+        let (survivors, race_evaluations) = race(&base, space, candidates, instances, settings, &environment.logger);
+
+        evaluations += race_evaluations;
+        if !survivors.is_empty() {
+            elites = survivors;
+        }
+
+        (environment.logger)(&format!(
+            "tune iteration {} is completed, total evaluations: {evaluations}",
+            iteration + 1
+        ));
+    }
+
+    let tuned = elites.into_iter().next().unwrap_or(default.clone());
+    let report = create_report(&base, space, &default, &tuned, instances, settings, evaluations)?;
+
+    Ok(TuneResult { config: create_config(&base, space, &tuned)?, report })
+}
+
+fn validate(space: &ParameterSpace, instances: &[TuneInstance], settings: &TuneSettings) -> GenericResult<()> {
+    if instances.is_empty() {
+        return Err("no training instances".into());
+    }
+
+    if space.parameters.is_empty() {
+        return Err("parameter space has no parameters".into());
+    }
+
+    if settings.elite_size == 0 || settings.candidates < settings.elite_size {
+        return Err("amount of candidates should not be less than elite size which should be positive".into());
+    }
+
+    space.parameters.iter().try_for_each(|parameter| {
+        let is_valid = match &parameter.domain {
+            ParameterDomain::Int { min, max } => min <= max,
+            ParameterDomain::Float { min, max } => min <= max,
+            ParameterDomain::Categorical { values } => !values.is_empty(),
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(format!("invalid domain of parameter '{}'", parameter.path).into())
+        }
+    })
+}
+
+/// Races candidates on instances and returns elites together with amount of evaluations.
+/// Candidates which cannot be evaluated are eliminated.
+fn race<'a>(
+    base: &Value,
+    space: &ParameterSpace,
+    candidates: Vec<Candidate>,
+    instances: impl Iterator<Item = &'a TuneInstance>,
+    settings: &TuneSettings,
+    logger: &InfoLogger,
+) -> (Vec<Candidate>, usize) {
+    let mut ranks = vec![0_usize; candidates.len()];
+    let mut alive = (0..candidates.len()).collect::<Vec<_>>();
+    let mut evaluations = 0;
+
+    for (seen, instance) in instances.enumerate() {
+        evaluations += alive.len();
+        let mut results = alive
+            .iter()
+            .filter_map(|&idx| match evaluate(base, space, &candidates[idx], instance, settings) {
+                Ok(solution) => Some((idx, solution)),
+                Err(err) => {
+                    // NOTE some parameter combinations are not valid, such candidates are eliminated
+                    (logger)(&format!("candidate is eliminated on '{}': {err}", instance.name));
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let goal = instance.problem.goal.as_ref();
+        results.sort_by(|(_, a), (_, b)| goal.total_order(a, b));
+        let mut rank = 0;
+        results.iter().enumerate().for_each(|(position, (idx, solution))| {
+            if position > 0 && goal.total_order(&results[position - 1].1, solution) != Ordering::Equal {
+                rank = position;
+            }
+            ranks[*idx] += rank;
+        });
+
+        alive = results.iter().map(|(idx, _)| *idx).collect();
+        alive.sort_by_key(|&idx| ranks[idx]);
+        if seen + 1 >= settings.min_instances {
+            alive.truncate(settings.elite_size.max(alive.len().div_ceil(2)));
+        }
+    }
+
+    alive.truncate(settings.elite_size);
+
+    (alive.into_iter().map(|idx| candidates[idx].clone()).collect(), evaluations)
+}
+
+fn create_report(
+    base: &Value,
+    space: &ParameterSpace,
+    default: &Candidate,
+    tuned: &Candidate,
+    instances: &[TuneInstance],
+    settings: &TuneSettings,
+    evaluations: usize,
+) -> GenericResult<TuneReport> {
+    let mut report = TuneReport { evaluations, wins: 0, losses: 0, ties: 0, instances: Vec::default() };
+
+    instances.iter().try_for_each(|instance| {
+        let default = evaluate(base, space, default, instance, settings)?;
+        let tuned = evaluate(base, space, tuned, instance, settings)?;
+
+        match instance.problem.goal.total_order(&tuned, &default) {
+            Ordering::Less => report.wins += 1,
+            Ordering::Greater => report.losses += 1,
+            Ordering::Equal => report.ties += 1,
+        }
+
+        report.instances.push(InstanceReport {
+            name: instance.name.clone(),
+            default: default.fitness().collect(),
+            tuned: tuned.fitness().collect(),
+        });
+
+        Ok::<_, GenericError>(())
+    })?;
+
+    Ok(report)
+}
+
+fn evaluate(
+    base: &Value,
+    space: &ParameterSpace,
+    candidate: &Candidate,
+    instance: &TuneInstance,
+    settings: &TuneSettings,
+) -> GenericResult<InsertionContext> {
+    let config = create_config(base, space, candidate)?;
+    let config = serde_json::from_value::<Config>(config).map_err(|err| format!("cannot create config: '{err}'"))?;
+    let config = Config {
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_generations: Some(settings.generations),
+            variation: None,
+            stagnation: None,
+            target_cost: None,
+        }),
+        ..config
+    };
+
+    let config = create_builder_from_config(instance.problem.clone(), Vec::default(), &config)?.build()?;
+    let (solutions, _) = EvolutionSimulator::new(config)?.run()?;
+
+    solutions.into_iter().next().ok_or_else(|| format!("cannot find any solution for '{}'", instance.name).into())
+}
+
+fn sample_candidate(space: &ParameterSpace, parent: &Candidate, spread: f64, random: &dyn Random) -> Candidate {
+    space
+        .parameters
+        .iter()
+        .zip(parent.iter())
+        .map(|(parameter, value)| Some(sample_value(&parameter.domain, value.as_ref(), spread, random)))
+        .collect()
+}
+
+fn sample_value(domain: &ParameterDomain, parent: Option<&Value>, spread: f64, random: &dyn Random) -> Value {
+    match domain {
+        ParameterDomain::Int { min, max } => match parent.and_then(Value::as_f64) {
+            Some(value) => {
+                // NOTE parent value is rounded, so the neighbourhood always contains at least one integer
+                let (lower, upper) = get_neighbourhood(value.round(), *min as f64, *max as f64, spread);
+                Value::from(random.uniform_int(lower.ceil() as i32, upper.floor() as i32))
+            }
+            None => Value::from(random.uniform_int(*min, *max)),
+        },
+        ParameterDomain::Float { min, max } => match parent.and_then(Value::as_f64) {
+            Some(value) => {
+                let (lower, upper) = get_neighbourhood(value, *min, *max, spread);
+                Value::from(random.uniform_real(lower, upper))
+            }
+            None => Value::from(random.uniform_real(*min, *max)),
+        },
+        ParameterDomain::Categorical { values } => match parent {
+            Some(value) if !random.is_hit(spread) => value.clone(),
+            _ => values[random.uniform_int(0, values.len() as i32 - 1) as usize].clone(),
+        },
+    }
+}
+
+/// Returns a range around parent value which shrinks with spread.
+fn get_neighbourhood(value: f64, min: f64, max: f64, spread: f64) -> (f64, f64) {
+    let value = value.clamp(min, max);
+    let radius = spread * (max - min) / 2.;
+
+    ((value - radius).max(min), (value + radius).min(max))
+}
+
+/// Creates a config by applying candidate values to the base config.
+fn create_config(base: &Value, space: &ParameterSpace, candidate: &Candidate) -> GenericResult<Value> {
+    let mut config = base.clone();
+
+    space.parameters.iter().zip(candidate.iter()).try_for_each(|(parameter, value)| match value {
+        Some(value) => set_value(&mut config, parameter.path.as_str(), value.clone()),
+        None => Ok(()),
+    })?;
+
+    Ok(config)
+}
+
+/// Sets value at given json pointer creating missing intermediate objects.
+fn set_value(root: &mut Value, path: &str, value: Value) -> GenericResult<()> {
+    let tokens = path
+        .strip_prefix('/')
+        .ok_or_else(|| format!("parameter path should start with '/': '{path}'"))?
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>();
+
+    let (last, parents) = tokens.split_last().ok_or_else(|| format!("invalid parameter path: '{path}'"))?;
+
+    let node = parents.iter().try_fold(root, |node, token| match node {
+        Value::Object(map) => Ok(map.entry(token.clone()).or_insert_with(|| Value::Object(Map::default()))),
+        Value::Array(items) => token
+            .parse::<usize>()
+            .ok()
+            .and_then(|idx| items.get_mut(idx))
+            .ok_or_else(|| GenericError::from(format!("cannot find item '{token}' in '{path}'"))),
+        _ => Err(format!("cannot set property '{token}' of non object value in '{path}'").into()),
+    })?;
+
+    match node {
+        Value::Object(map) => {
+            map.insert(last.clone(), value);
+            Ok(())
+        }
+        Value::Array(items) => {
+            let item = last
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| items.get_mut(idx))
+                .ok_or_else(|| format!("cannot find item '{last}' in '{path}'"))?;
+            *item = value;
+            Ok(())
+        }
+        _ => Err(format!("cannot set property '{last}' of non object value in '{path}'").into()),
+    }
+}
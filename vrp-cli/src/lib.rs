@@ -36,10 +36,13 @@ pub mod extensions;
 
 use crate::extensions::import::import_problem;
 use crate::extensions::solve::config::{create_builder_from_config, Config};
+use crate::extensions::solve::progress::{create_progress_listener, ProgressHandler};
 use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::Problem as CoreProblem;
-use vrp_core::prelude::{GenericError, Solver};
+use vrp_core::prelude::{GenericError, Solution, Solver, SolverHandle};
+use vrp_core::rosomaxa::evolution::ProgressListener;
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::{write_pragmatic, PragmaticOutputType};
 use vrp_pragmatic::format::FormatError;
@@ -54,7 +57,7 @@ mod c_interop {
     use std::ffi::{CStr, CString};
     use std::os::raw::c_char;
     use std::panic;
-    use std::panic::UnwindSafe;
+    use std::panic::{AssertUnwindSafe, UnwindSafe};
     use std::slice;
     use vrp_core::prelude::GenericError;
     use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
@@ -80,18 +83,20 @@ mod c_interop {
         };
     }
 
-    fn catch_panic<F: FnOnce() + UnwindSafe>(failure: Callback, action: F) {
-        if let Err(err) = panic::catch_unwind(action) {
-            let message = err
-                .downcast_ref::<&str>()
-                .cloned()
-                .or_else(|| err.downcast_ref::<String>().map(|str| str.as_str()))
-                .map(|msg| format!("panic: '{msg}'"))
-                .unwrap_or_else(|| "panic with unknown type".to_string());
-
-            let error = CString::new(message.as_bytes()).unwrap();
-            failure(error.as_ptr());
-        }
+    fn catch_panic<R, F: FnOnce() -> R + UnwindSafe>(failure: Callback, action: F) -> Option<R> {
+        panic::catch_unwind(action)
+            .map_err(|err| {
+                let message = err
+                    .downcast_ref::<&str>()
+                    .cloned()
+                    .or_else(|| err.downcast_ref::<String>().map(|str| str.as_str()))
+                    .map(|msg| format!("panic: '{msg}'"))
+                    .unwrap_or_else(|| "panic with unknown type".to_string());
+
+                let error = CString::new(message.as_bytes()).unwrap();
+                failure(error.as_ptr());
+            })
+            .ok()
     }
 
     /// Returns a list of unique locations which can be used to request a routing matrix.
@@ -208,10 +213,137 @@ mod c_interop {
         });
     }
 
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Additionally, calls `progress` with
+    /// an event serialized as json each time the best known solution is improved. When `include_solution`
+    /// is set, each event contains the full solution in `pragmatic` format.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_progress(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        config: *const c_char,
+        include_solution: bool,
+        progress: Callback,
+        success: Callback,
+        failure: Callback,
+    ) {
+        catch_panic(failure, || {
+            let problem = to_string(problem);
+            let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len).to_vec() };
+            let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+
+            let handler: ProgressHandler = Box::new(move |event| {
+                let event = CString::new(event.as_bytes()).unwrap();
+                progress(event.as_ptr());
+            });
+
+            let result =
+                if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+                    .map_err(From::from)
+                    .and_then(|problem| {
+                        read_config(BufReader::new(to_string(config).as_bytes()))
+                            .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
+                            .map(|config| (problem, config))
+                    })
+                    .and_then(|(problem, config)| {
+                        get_solution_serialized_with_progress(Arc::new(problem), config, include_solution, handler)
+                    });
+
+            call_back(result, success, failure);
+        });
+    }
+
+    /// Starts solving Vehicle Routing Problem passed in `pragmatic` format in background and returns
+    /// a handle which can be used to get the best known solution or to stop the search earlier.
+    /// Returns null and calls `failure` if the problem or config cannot be read. The handle has to be
+    /// released by calling `join_pragmatic`.
+    #[no_mangle]
+    extern "C" fn start_pragmatic(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        config: *const c_char,
+        failure: Callback,
+    ) -> *mut SerializedSolverHandle {
+        catch_panic(failure, || {
+            let problem = to_string(problem);
+            let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len).to_vec() };
+            let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+
+            let result =
+                if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+                    .map_err(From::from)
+                    .and_then(|problem| {
+                        read_config(BufReader::new(to_string(config).as_bytes()))
+                            .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
+                            .map(|config| SerializedSolverHandle::start(Arc::new(problem), config))
+                    });
+
+            match result {
+                Ok(handle) => Box::into_raw(Box::new(handle)),
+                Err(err) => {
+                    let error = CString::new(err.to_string().as_bytes()).unwrap();
+                    failure(error.as_ptr());
+                    std::ptr::null_mut()
+                }
+            }
+        })
+        .unwrap_or(std::ptr::null_mut())
+    }
+
+    /// Calls `success` with the best known solution in `pragmatic` format or `failure` if no solution
+    /// is built yet. The `handle` should be created by `start_pragmatic`.
+    #[no_mangle]
+    extern "C" fn get_best_pragmatic(handle: *const SerializedSolverHandle, success: Callback, failure: Callback) {
+        // NOTE the handle is not used after panic
+        catch_panic(
+            failure,
+            AssertUnwindSafe(|| {
+                let handle = unsafe { handle.as_ref() }.expect("null solver handle");
+                let result = handle.best_so_far().and_then(|solution| solution.ok_or_else(|| "no solution yet".into()));
+
+                call_back(result, success, failure);
+            }),
+        );
+    }
+
+    /// Requests the search to be stopped. The `handle` should be created by `start_pragmatic`.
+    #[no_mangle]
+    extern "C" fn stop_pragmatic(handle: *const SerializedSolverHandle) {
+        if let Some(handle) = unsafe { handle.as_ref() } {
+            handle.stop();
+        }
+    }
+
+    /// Returns true if the search is completed. The `handle` should be created by `start_pragmatic`.
+    #[no_mangle]
+    extern "C" fn is_finished_pragmatic(handle: *const SerializedSolverHandle) -> bool {
+        match unsafe { handle.as_ref() } {
+            Some(handle) => handle.is_finished(),
+            None => true,
+        }
+    }
+
+    /// Waits for the search to be completed and calls `success` with the final solution in `pragmatic` format.
+    /// Releases the `handle` created by `start_pragmatic`, so it cannot be used after this call.
+    #[no_mangle]
+    extern "C" fn join_pragmatic(handle: *mut SerializedSolverHandle, success: Callback, failure: Callback) {
+        catch_panic(
+            failure,
+            AssertUnwindSafe(|| {
+                assert!(!handle.is_null(), "null solver handle");
+                let handle = unsafe { Box::from_raw(handle) };
+
+                call_back(handle.join(), success, failure);
+            }),
+        );
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
         use crate::helpers::generate::SIMPLE_PROBLEM;
+        use std::sync::atomic::{AtomicBool, Ordering};
 
         #[test]
         fn can_use_to_string() {
@@ -334,6 +466,94 @@ mod c_interop {
                 failure,
             );
         }
+
+        #[test]
+        fn can_solve_problem_with_progress() {
+            extern "C" fn progress(event: *const c_char) {
+                let event = to_string(event);
+                assert!(event.starts_with('{'));
+                assert!(event.contains("\"fitness\""));
+                assert!(event.contains("\"solution\""));
+            }
+            extern "C" fn success(solution: *const c_char) {
+                assert!(to_string(solution).starts_with('{'));
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{\"termination\": {\"max-generations\": 1}}").unwrap();
+
+            solve_pragmatic_with_progress(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                0,
+                config.as_ptr() as *const c_char,
+                true,
+                progress,
+                success,
+                failure,
+            );
+        }
+
+        #[test]
+        fn can_start_stop_and_join_problem() {
+            static HAS_BEST: AtomicBool = AtomicBool::new(false);
+            extern "C" fn best(solution: *const c_char) {
+                assert!(to_string(solution).starts_with('{'));
+                HAS_BEST.store(true, Ordering::Relaxed);
+            }
+            extern "C" fn no_best(_: *const c_char) {}
+            extern "C" fn success(solution: *const c_char) {
+                assert!(to_string(solution).starts_with('{'));
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{\"termination\": {\"max-generations\": 100000}}").unwrap();
+
+            let handle = start_pragmatic(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                0,
+                config.as_ptr() as *const c_char,
+                failure,
+            );
+            assert!(!handle.is_null());
+
+            while !HAS_BEST.load(Ordering::Relaxed) {
+                get_best_pragmatic(handle, best, no_best);
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            stop_pragmatic(handle);
+            join_pragmatic(handle, success, failure);
+        }
+
+        #[test]
+        fn cannot_start_problem_with_invalid_config() {
+            extern "C" fn failure(err: *const c_char) {
+                assert!(to_string(err).contains("E0004"));
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{").unwrap();
+
+            let handle = start_pragmatic(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                0,
+                config.as_ptr() as *const c_char,
+                failure,
+            );
+
+            assert!(handle.is_null());
+        }
     }
 }
 
@@ -408,11 +628,98 @@ mod py_interop {
             .map_err(|err| PyOSError::new_err(err.to_string()))
     }
 
+    /// Solves Vehicle Routing Problem and calls `progress` with an event serialized as json each time
+    /// the best known solution is improved.
+    #[pyfunction]
+    #[pyo3(signature = (problem, matrices, config, progress, include_solution = false))]
+    fn solve_pragmatic_with_progress(
+        problem: String,
+        matrices: Vec<String>,
+        config: String,
+        progress: PyObject,
+        include_solution: bool,
+    ) -> PyResult<String> {
+        let handler: ProgressHandler = Box::new(move |event| {
+            Python::with_gil(|py| {
+                if let Err(err) = progress.call1(py, (event,)) {
+                    err.print(py);
+                }
+            })
+        });
+
+        if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+            .map_err(From::from)
+            .and_then(|problem| {
+                read_config(BufReader::new(config.as_bytes()))
+                    .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
+                    .map(|config| (problem, config))
+            })
+            .and_then(|(problem, config)| {
+                get_solution_serialized_with_progress(Arc::new(problem), config, include_solution, handler)
+            })
+            .map_err(|err| PyOSError::new_err(err.to_string()))
+    }
+
+    /// A handle of the solver which runs in background.
+    #[pyclass(name = "SolverHandle")]
+    struct PySolverHandle {
+        inner: Option<SerializedSolverHandle>,
+    }
+
+    impl PySolverHandle {
+        fn get_inner(&self) -> PyResult<&SerializedSolverHandle> {
+            self.inner.as_ref().ok_or_else(|| PyOSError::new_err("solver handle is already joined"))
+        }
+    }
+
+    #[pymethods]
+    impl PySolverHandle {
+        /// Returns the best known solution or `None` if no solution is built yet.
+        fn best_so_far(&self) -> PyResult<Option<String>> {
+            self.get_inner()?.best_so_far().map_err(|err| PyOSError::new_err(err.to_string()))
+        }
+
+        /// Requests the search to be stopped.
+        fn stop(&self) -> PyResult<()> {
+            self.get_inner().map(|handle| handle.stop())
+        }
+
+        /// Returns true if the search is completed.
+        fn is_finished(&self) -> PyResult<bool> {
+            self.get_inner().map(|handle| handle.is_finished())
+        }
+
+        /// Waits for the search to be completed and returns the final solution.
+        fn join(&mut self, py: Python<'_>) -> PyResult<String> {
+            let handle = self.inner.take().ok_or_else(|| PyOSError::new_err("solver handle is already joined"))?;
+
+            py.allow_threads(|| handle.join()).map_err(|err| PyOSError::new_err(err.to_string()))
+        }
+    }
+
+    /// Starts solving Vehicle Routing Problem in background and returns a handle which can be used
+    /// to get the best known solution or to stop the search earlier.
+    #[pyfunction]
+    fn start_pragmatic(problem: String, matrices: Vec<String>, config: String) -> PyResult<PySolverHandle> {
+        if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+            .map_err(From::from)
+            .and_then(|problem| {
+                read_config(BufReader::new(config.as_bytes()))
+                    .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
+                    .map(|config| SerializedSolverHandle::start(Arc::new(problem), config))
+            })
+            .map(|handle| PySolverHandle { inner: Some(handle) })
+            .map_err(|err| PyOSError::new_err(err.to_string()))
+    }
+
     #[pymodule]
     fn vrp_cli(m: &Bound<'_, PyModule>) -> PyResult<()> {
         m.add_function(wrap_pyfunction!(convert_to_pragmatic, m)?)?;
         m.add_function(wrap_pyfunction!(get_routing_locations, m)?)?;
         m.add_function(wrap_pyfunction!(solve_pragmatic, m)?)?;
+        m.add_function(wrap_pyfunction!(solve_pragmatic_with_progress, m)?)?;
+        m.add_function(wrap_pyfunction!(start_pragmatic, m)?)?;
+        m.add_class::<PySolverHandle>()?;
         Ok(())
     }
 }
@@ -499,6 +806,50 @@ mod wasm {
             .map(|problem| JsValue::from_str(problem.as_str()))
             .map_err(|err| JsValue::from_str(&err.to_string()))
     }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Additionally, calls `progress` with
+    /// an event serialized as json each time the best known solution is improved.
+    #[wasm_bindgen]
+    pub fn solve_pragmatic_with_progress(
+        problem: JsValue,
+        matrices: JsValue,
+        config: JsValue,
+        progress: js_sys::Function,
+        include_solution: bool,
+    ) -> Result<JsValue, JsValue> {
+        let problem: Problem =
+            serde_wasm_bindgen::from_value(problem).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
+
+        let matrices: Vec<Matrix> =
+            serde_wasm_bindgen::from_value(matrices).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
+
+        let problem = Arc::new(
+            if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+                .map_err(|errs| JsValue::from_str(errs.to_json().as_str()))?,
+        );
+
+        let config: Config = serde_wasm_bindgen::from_value(config)
+            .map_err(|err| serialize_as_config_error(&err.to_string()))
+            .map_err(|err| JsValue::from_str(err.as_str()))?;
+
+        let progress = JsCallback(progress);
+        let handler: ProgressHandler = Box::new(move |event| {
+            let _ = progress.0.call1(&JsValue::NULL, &JsValue::from_str(event.as_str()));
+        });
+
+        get_solution_serialized_with_progress(problem, config, include_solution, handler)
+            .map(|problem| JsValue::from_str(problem.as_str()))
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// Wraps js function to pass it as progress handler.
+    struct JsCallback(js_sys::Function);
+
+    // NOTE wasm target is single threaded, so js function is never accessed from different threads
+    #[allow(unsafe_code)]
+    unsafe impl Send for JsCallback {}
+    #[allow(unsafe_code)]
+    unsafe impl Sync for JsCallback {}
 }
 
 /// Gets locations serialized in json.
@@ -511,27 +862,96 @@ pub fn get_locations_serialized(problem: &Problem) -> Result<String, GenericErro
 
 /// Gets solution serialized in json.
 pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Result<String, GenericError> {
+    solve_serialized(problem, config, None)
+}
+
+/// Gets solution serialized in json. Additionally, passes progress events serialized as json lines to the
+/// handler each time the best known solution is improved. When `include_solution` is set, each event
+/// contains the full solution in `pragmatic` format.
+pub fn get_solution_serialized_with_progress(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    include_solution: bool,
+    handler: ProgressHandler,
+) -> Result<String, GenericError> {
+    let listener = create_progress_listener(problem.clone(), include_solution, handler);
+
+    solve_serialized(problem, config, Some(listener))
+}
+
+/// A handle of the solver which solves `pragmatic` problem in background and provides solutions
+/// serialized in json.
+pub struct SerializedSolverHandle {
+    problem: Arc<CoreProblem>,
+    include_geojson: bool,
+    handle: SolverHandle,
+}
+
+impl SerializedSolverHandle {
+    /// Starts solving the problem in background using given config.
+    pub fn start(problem: Arc<CoreProblem>, config: Config) -> Self {
+        let include_geojson = is_geojson_included(&config);
+        let handle = SolverHandle::start(problem.clone(), {
+            let problem = problem.clone();
+            move || create_builder_from_config(problem, Default::default(), &config)?.build()
+        });
+
+        Self { problem, include_geojson, handle }
+    }
+
+    /// Returns the best known solution serialized in json or `None` if no solution is built yet.
+    pub fn best_so_far(&self) -> Result<Option<String>, GenericError> {
+        self.handle
+            .best_so_far()
+            .map(|solution| write_solution_serialized(self.problem.as_ref(), &solution, self.include_geojson))
+            .transpose()
+    }
+
+    /// Requests the search to be stopped.
+    pub fn stop(&self) {
+        self.handle.stop()
+    }
+
+    /// Returns true if the search is completed.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Waits for the search to be completed and returns the final solution serialized in json.
+    pub fn join(self) -> Result<String, GenericError> {
+        let solution = self.handle.join().map_err(serialize_as_solution_error)?;
+
+        write_solution_serialized(self.problem.as_ref(), &solution, self.include_geojson)
+    }
+}
+
+fn solve_serialized(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    progress: Option<ProgressListener<InsertionContext>>,
+) -> Result<String, GenericError> {
     let solution = create_builder_from_config(problem.clone(), Default::default(), &config)
-        .and_then(|builder| builder.build())
+        .and_then(|builder| builder.with_progress(progress).build())
         .map(|config| Solver::new(problem.clone(), config))
         .and_then(|solver| solver.solve())
-        .map_err(|err| {
-            FormatError::new(
-                "E0003".to_string(),
-                "cannot find any solution".to_string(),
-                format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
-            )
-            .to_json()
-        })?;
-
-    let output_type = if config.output.and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
-        PragmaticOutputType::Combined
-    } else {
-        Default::default()
-    };
+        .map_err(serialize_as_solution_error)?;
+
+    write_solution_serialized(problem.as_ref(), &solution, is_geojson_included(&config))
+}
+
+fn is_geojson_included(config: &Config) -> bool {
+    config.output.as_ref().and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false)
+}
+
+fn write_solution_serialized(
+    problem: &CoreProblem,
+    solution: &Solution,
+    include_geojson: bool,
+) -> Result<String, GenericError> {
+    let output_type = if include_geojson { PragmaticOutputType::Combined } else { Default::default() };
 
     let mut writer = BufWriter::new(Vec::new());
-    write_pragmatic(problem.as_ref(), &solution, output_type, &mut writer)?;
+    write_pragmatic(problem, solution, output_type, &mut writer)?;
 
     let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
     let result = String::from_utf8(bytes).map_err(|err| format!("{err}"))?;
@@ -539,6 +959,16 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
     Ok(result)
 }
 
+fn serialize_as_solution_error(err: GenericError) -> GenericError {
+    FormatError::new(
+        "E0003".to_string(),
+        "cannot find any solution".to_string(),
+        format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
+    )
+    .to_json()
+    .into()
+}
+
 fn serialize_as_config_error(err: &str) -> String {
     FormatError::new(
         "E0004".to_string(),
@@ -17,9 +17,12 @@ mod cli {
     use super::commands::import::{get_import_app, run_import};
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::analyze::{get_analyze_app, run_analyze};
+    use crate::commands::bench::{get_bench_app, run_bench};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::create_write_buffer;
+    use crate::commands::distribute::{get_coordinator_app, get_worker_app, run_coordinator, run_worker};
     use crate::commands::generate::{get_generate_app, run_generate};
+    use crate::commands::tune::{get_tune_app, run_tune};
     use clap::{ArgMatches, Command};
     use std::process;
 
@@ -37,6 +40,10 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_coordinator_app())
+            .subcommand(get_worker_app())
+            .subcommand(get_tune_app())
+            .subcommand(get_bench_app())
     }
 
     pub fn run_subcommand(arg_matches: ArgMatches) {
@@ -46,6 +53,10 @@ mod cli {
             Some(("import", import_matches)) => run_import(import_matches),
             Some(("check", check_matches)) => run_check(check_matches),
             Some(("generate", generate_matches)) => run_generate(generate_matches),
+            Some(("coordinator", coordinator_matches)) => run_coordinator(coordinator_matches, create_write_buffer),
+            Some(("worker", worker_matches)) => run_worker(worker_matches),
+            Some(("tune", tune_matches)) => run_tune(tune_matches, create_write_buffer),
+            Some(("bench", bench_matches)) => run_bench(bench_matches, create_write_buffer),
             _ => {
                 eprintln!("no subcommand was used. Use -h to print help information.");
                 process::exit(1);
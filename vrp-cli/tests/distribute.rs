@@ -0,0 +1,70 @@
+//! Runs distributed solving with workers started as separate processes of the built binary.
+
+use std::io::BufReader;
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use vrp_cli::extensions::distribute::{run_coordinator, CoordinatorConfig, Listener};
+use vrp_core::prelude::InfoLogger;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn start_workers(address: &str, amount: usize) -> Vec<Child> {
+    (0..amount)
+        .map(|_| {
+            Command::new(env!("CARGO_BIN_EXE_vrp-cli"))
+                .args(["worker", "--address", address])
+                .stdout(Stdio::null())
+                .spawn()
+                .expect("cannot start worker process")
+        })
+        .collect()
+}
+
+fn solve_with_worker_processes(
+    rounds: usize,
+    logger: impl Fn(&str, &mut [Child]) + Send + Sync + 'static,
+) -> (String, Vec<Child>) {
+    let problem = std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap();
+    let listener = Listener::bind("127.0.0.1:0").unwrap();
+    let workers = Arc::new(Mutex::new(start_workers(listener.address().unwrap().as_str(), 2)));
+    let settings = CoordinatorConfig {
+        workers: 2,
+        rounds,
+        generations: 10,
+        elite_size: 2,
+        timeout: Some(Duration::from_secs(60)),
+    };
+    let logger: InfoLogger = {
+        let workers = workers.clone();
+        Arc::new(move |message: &str| logger(message, workers.lock().unwrap().as_mut_slice()))
+    };
+
+    let solution = run_coordinator(&listener, problem, vec![], None, &settings, logger).unwrap();
+
+    let workers = std::mem::take(&mut *workers.lock().unwrap());
+
+    (solution, workers)
+}
+
+#[test]
+fn can_solve_problem_with_worker_processes() {
+    let (solution, mut workers) = solve_with_worker_processes(2, |_, _| {});
+
+    assert!(workers.iter_mut().all(|worker| worker.wait().unwrap().success()));
+    assert!(!deserialize_solution(BufReader::new(solution.as_bytes())).unwrap().tours.is_empty());
+}
+
+#[test]
+fn can_continue_solving_when_worker_process_is_killed() {
+    let (solution, mut workers) = solve_with_worker_processes(3, |message, workers| {
+        if message.starts_with("round 1 ") {
+            workers[0].kill().unwrap();
+        }
+    });
+
+    assert!(!workers[0].wait().unwrap().success());
+    assert!(workers[1].wait().unwrap().success());
+    assert!(!deserialize_solution(BufReader::new(solution.as_bytes())).unwrap().tours.is_empty());
+}
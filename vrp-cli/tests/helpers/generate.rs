@@ -10,6 +10,8 @@ pub fn create_empty_job() -> Job {
         services: None,
         skills: None,
         value: None,
+        service_weight: None,
+        unassigned_cost: None,
         group: None,
         compatibility: None,
     }
@@ -0,0 +1,49 @@
+use super::*;
+use vrp_cli::extensions::bench::BenchResult;
+
+const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
+
+#[test]
+fn can_run_bench_on_folder_with_instances() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let instances_path = tmpdir.path().join("instances");
+    std::fs::create_dir(&instances_path).unwrap();
+    std::fs::copy(SOLOMON_PROBLEM_PATH, instances_path.join("C101.25.txt")).unwrap();
+    let bks_path = tmpdir.path().join("bks.csv");
+    std::fs::write(&bks_path, "instance,vehicles,cost\nC101.25,3,191.3\n").unwrap();
+    let out_path = tmpdir.path().join("result.json");
+    let args = vec![
+        "bench",
+        "solomon",
+        instances_path.to_str().unwrap(),
+        "--bks",
+        bks_path.to_str().unwrap(),
+        "--seeds",
+        "2",
+        "--max-generations",
+        "5",
+        "--out-format",
+        "json",
+        "--out-result",
+        out_path.to_str().unwrap(),
+    ];
+    let matches = get_bench_app().try_get_matches_from(args).unwrap();
+
+    run_bench(&matches, create_write_buffer).unwrap();
+
+    let result: BenchResult = serde_json::from_reader(BufReader::new(File::open(out_path).unwrap())).unwrap();
+    assert_eq!(result.runs.len(), 2);
+    assert_eq!(result.summaries[0].instance, "C101.25");
+    assert_eq!(result.summaries[0].bks_cost, Some(191.3));
+}
+
+#[test]
+fn cannot_run_bench_without_instances() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let args = vec!["bench", "solomon", tmpdir.path().to_str().unwrap()];
+    let matches = get_bench_app().try_get_matches_from(args).unwrap();
+
+    let result = run_bench(&matches, create_write_buffer);
+
+    assert!(result.is_err());
+}
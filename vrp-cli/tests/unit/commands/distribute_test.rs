@@ -0,0 +1,51 @@
+use super::*;
+use std::thread;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+#[cfg(unix)]
+#[test]
+fn can_run_coordinator_and_worker_from_args() {
+    let directory = tempfile::tempdir().unwrap();
+    let address = format!("unix:{}", directory.path().join("vrp.sock").display());
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+
+    let coordinator_args = vec![
+        "coordinator",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--address",
+        address.as_str(),
+        "--workers",
+        "1",
+        "--rounds",
+        "2",
+        "--generations",
+        "10",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let coordinator_matches = get_coordinator_app().try_get_matches_from(coordinator_args).unwrap();
+    let worker_matches = get_worker_app().try_get_matches_from(vec!["worker", "--address", address.as_str()]).unwrap();
+
+    let coordinator = thread::spawn(move || run_coordinator(&coordinator_matches, create_write_buffer));
+    // NOTE retry till coordinator starts listening
+    let worker = (0..50)
+        .map(|_| {
+            thread::sleep(std::time::Duration::from_millis(100));
+            run_worker(&worker_matches)
+        })
+        .find(|result| result.is_ok());
+
+    assert!(worker.is_some());
+    coordinator.join().unwrap().unwrap();
+    let solution = vrp_pragmatic::format::solution::deserialize_solution(std::io::BufReader::new(tmpfile.as_file()));
+    assert!(!solution.unwrap().tours.is_empty());
+}
+
+#[test]
+fn cannot_run_coordinator_without_workers_arg() {
+    let args = vec!["coordinator", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--address", "127.0.0.1:0"];
+
+    assert!(get_coordinator_app().try_get_matches_from(args).is_err());
+}
@@ -1,4 +1,5 @@
 use super::*;
+use vrp_cli::extensions::solve::progress::read_progress_record;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
 const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
@@ -110,7 +111,7 @@ fn can_specify_parallelism() {
     ] {
         let matches = get_solomon_matches(params.as_slice());
 
-        let thread_pool_size = get_environment(&matches).map(|e| e.parallelism.thread_pool_size());
+        let thread_pool_size = get_environment(&matches, None).map(|e| e.parallelism.thread_pool_size());
 
         assert_eq!(thread_pool_size, result);
     }
@@ -150,3 +151,241 @@ fn can_specify_cv() {
         assert_eq!(min_cv, result);
     }
 }
+
+#[test]
+fn can_specify_max_stagnation() {
+    for (params, result) in [
+        (vec!["--max-stagnation", "sample,200,0.01"], Ok(Some(("sample".to_string(), 200, vec![0.01])))),
+        (vec!["--max-stagnation", "period,60,0,0.001"], Ok(Some(("period".to_string(), 60, vec![0., 0.001])))),
+        (vec!["--max-stagnation", "sampl,200,0.01"], Err("cannot parse max_stagnation parameter".into())),
+        (vec!["--max-stagnation", "sample,0,0.01"], Err("cannot parse max_stagnation parameter".into())),
+        (vec!["--max-stagnation", "sample,200,abc"], Err("cannot parse max_stagnation parameter".into())),
+        (vec!["--max-stagnation", "sample,200"], Ok(Some(("sample".to_string(), 200, vec![])))),
+        (vec!["--max-stagnation", "sample"], Err("cannot parse max_stagnation parameter".into())),
+        (vec![], Ok(None)),
+    ] {
+        let matches = get_solomon_matches(params.as_slice());
+
+        let max_stagnation = get_max_stagnation(&matches);
+
+        assert_eq!(max_stagnation, result);
+    }
+}
+
+#[test]
+fn can_save_checkpoint_and_resume_from_it() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let checkpoint_path = tmpdir.path().join("checkpoint.json");
+    let checkpoint_path = checkpoint_path.to_str().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--checkpoint",
+        checkpoint_path,
+        "--checkpoint-interval",
+        "5",
+    ];
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+
+    let checkpoint = read_checkpoint(BufReader::new(File::open(checkpoint_path).unwrap())).unwrap();
+    assert_eq!(checkpoint.generation, 9);
+    assert!(!checkpoint.solutions.is_empty());
+
+    let args =
+        vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "12", "--resume", checkpoint_path];
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+}
+
+#[test]
+fn can_stream_progress_into_file() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let progress_path = tmpdir.path().join("progress.ndjson");
+    let progress_path = progress_path.to_str().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--progress",
+        progress_path,
+        "--progress-solution",
+    ];
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+
+    let progress = std::fs::read_to_string(progress_path).unwrap();
+    let records = progress.lines().map(|line| read_progress_record(line).unwrap()).collect::<Vec<_>>();
+    assert!(!records.is_empty());
+    assert!(records.iter().all(|record| record.solution.is_some()));
+}
+
+#[test]
+fn cannot_use_progress_solution_with_scientific_format() {
+    let result =
+        run_solve(&get_solomon_matches(&["--max-generations", "1", "--progress", "-", "--progress-solution"]), |_| {
+            BufWriter::new(Box::new(DummyWrite {}))
+        });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn cannot_use_checkpoint_with_scientific_format() {
+    let result =
+        run_solve(&get_solomon_matches(&["--max-generations", "1", "--checkpoint", "checkpoint.json"]), |_| {
+            BufWriter::new(Box::new(DummyWrite {}))
+        });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_reoptimize_solution_with_vehicle_states() {
+    reoptimize_solution_with_vehicle_states(None);
+}
+
+#[test]
+fn can_reoptimize_solution_with_vehicle_states_keeping_plan() {
+    reoptimize_solution_with_vehicle_states(Some("sequence"));
+}
+
+fn reoptimize_solution_with_vehicle_states(keep_plan: Option<&str>) {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let states_path = tmpdir.path().join("states.json");
+    let states_path = states_path.to_str().unwrap();
+    std::fs::write(
+        states_path,
+        r#"[{"vehicleId": "vehicle_1", "location": {"lat": 52.52599, "lng": 13.45413}, "time": "2019-07-04T10:15:00Z"}]"#,
+    )
+    .unwrap();
+    let mut args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--init-solution",
+        "../examples/data/pragmatic/simple.basic.solution.json",
+        "--vehicle-states",
+        states_path,
+    ];
+    args.extend(keep_plan.into_iter().flat_map(|keep_plan| ["--keep-plan", keep_plan]));
+
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+}
+
+#[test]
+fn cannot_use_vehicle_states_without_init_solution() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--vehicle-states", "states.json"];
+
+    assert!(get_solve_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn cannot_use_keep_plan_without_vehicle_states() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--keep-plan", "strict"];
+
+    assert!(get_solve_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_reproduce_solution_with_seed() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let solve = |name: &str| {
+        let out_path = tmpdir.path().join(name);
+        let out_path = out_path.to_str().unwrap();
+        let args = ["--max-generations", "100", "--parallelism", "2,2", "--seed", "42", "-o", out_path];
+        run_solve(&get_solomon_matches(&args), create_write_buffer).unwrap();
+
+        std::fs::read_to_string(out_path).unwrap()
+    };
+
+    assert_eq!(solve("first.txt"), solve("second.txt"));
+}
+
+#[test]
+fn cannot_use_invalid_seed() {
+    let result = run_solve(&get_solomon_matches(&["--max-generations", "1", "--seed", "abc"]), |_| {
+        BufWriter::new(Box::new(DummyWrite {}))
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_report_lower_bound_in_solution_extras() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let out_path = tmpdir.path().join("solution.json");
+    let out_path = out_path.to_str().unwrap();
+    let args =
+        vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "10", "--lower-bound", "-o", out_path];
+    run_solve(&get_solve_app().try_get_matches_from(args).unwrap(), create_write_buffer).unwrap();
+
+    let solution = vrp_pragmatic::format::solution::deserialize_solution(BufReader::new(File::open(out_path).unwrap()))
+        .expect("cannot read solution");
+    let lower_bound = solution.extras.and_then(|extras| extras.lower_bound).expect("no lower bound");
+    assert!(lower_bound.cost > 0.);
+    assert!(lower_bound.cost <= solution.statistic.cost);
+    assert!(lower_bound.gap.is_some_and(|gap| (0. ..1.).contains(&gap)));
+}
+
+#[test]
+fn can_stop_search_with_target_gap() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "100000", "--target-gap", "1"];
+
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+}
+
+#[test]
+fn cannot_use_invalid_target_gap() {
+    let result = run_solve(&get_solomon_matches(&["--max-generations", "1", "--target-gap", "abc"]), |_| {
+        BufWriter::new(Box::new(DummyWrite {}))
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_stop_search_with_max_stagnation_and_target_cost() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "100000",
+        "--max-stagnation",
+        "sample,10,0.01",
+        "--target-cost",
+        "1000000",
+    ];
+
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+}
+
+#[test]
+fn cannot_use_invalid_target_cost() {
+    let result = run_solve(&get_solomon_matches(&["--max-generations", "1", "--target-cost", "abc"]), |_| {
+        BufWriter::new(Box::new(DummyWrite {}))
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_export_and_import_heuristic_priors() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let priors_path = tmpdir.path().join("priors.json");
+    let priors_path = priors_path.to_str().unwrap();
+
+    let args =
+        vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "10", "--out-priors", priors_path];
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+    let priors = read_heuristic_priors(BufReader::new(File::open(priors_path).unwrap())).unwrap();
+    assert!(!priors.is_empty());
+
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "10", "--priors", priors_path];
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+}
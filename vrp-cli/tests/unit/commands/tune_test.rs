@@ -0,0 +1,58 @@
+use super::*;
+use vrp_cli::extensions::solve::config::read_config;
+use vrp_cli::extensions::tune::TuneReport;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const SPACE_PATH: &str = "../examples/data/config/tune.space.json";
+
+#[test]
+fn can_tune_config_on_folder_with_instances() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let instances_path = tmpdir.path().join("instances");
+    std::fs::create_dir(&instances_path).unwrap();
+    std::fs::copy(PRAGMATIC_PROBLEM_PATH, instances_path.join("problem1.json")).unwrap();
+    std::fs::copy(PRAGMATIC_PROBLEM_PATH, instances_path.join("problem2.json")).unwrap();
+    std::fs::write(instances_path.join("notes.txt"), "not a problem").unwrap();
+    let config_path = tmpdir.path().join("config.json");
+    let report_path = tmpdir.path().join("report.json");
+    let args = vec![
+        "tune",
+        "pragmatic",
+        instances_path.to_str().unwrap(),
+        "--space",
+        SPACE_PATH,
+        "--iterations",
+        "1",
+        "--candidates",
+        "2",
+        "--elite-size",
+        "1",
+        "--generations",
+        "5",
+        "--out-result",
+        config_path.to_str().unwrap(),
+        "--out-report",
+        report_path.to_str().unwrap(),
+    ];
+    let matches = get_tune_app().try_get_matches_from(args).unwrap();
+
+    run_tune(&matches, create_write_buffer).unwrap();
+
+    assert!(read_config(BufReader::new(File::open(config_path).unwrap())).is_ok());
+    let report: TuneReport = serde_json::from_reader(BufReader::new(File::open(report_path).unwrap())).unwrap();
+    assert_eq!(
+        report.instances.iter().map(|instance| instance.name.as_str()).collect::<Vec<_>>(),
+        vec!["problem1.json", "problem2.json"]
+    );
+}
+
+#[test]
+fn cannot_tune_without_instances() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let args = vec!["tune", "pragmatic", tmpdir.path().to_str().unwrap(), "--space", SPACE_PATH];
+    let matches = get_tune_app().try_get_matches_from(args).unwrap();
+
+    let result = run_tune(&matches, create_write_buffer);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,145 @@
+use super::*;
+use std::fs::File;
+use vrp_scientific::solomon::SolomonProblem;
+
+fn create_run(instance: &str, seed: u64, cost: f64, gap: Option<f64>, time_to_target: Option<f64>) -> BenchRun {
+    BenchRun {
+        instance: instance.to_string(),
+        seed,
+        cost,
+        vehicles: 3,
+        unassigned: 0,
+        gap,
+        duration: 2.,
+        time_to_target,
+    }
+}
+
+#[test]
+fn can_read_best_known_solutions() {
+    let content = "instance,vehicles,cost\n# comment\n\nC101,10,828.94\nRC201, ,1261.8\n";
+
+    let bks = read_best_known_solutions(BufReader::new(content.as_bytes())).unwrap();
+
+    assert_eq!(bks.len(), 2);
+    assert_eq!(bks["C101"], BestKnownSolution { vehicles: Some(10), cost: 828.94 });
+    assert_eq!(bks["RC201"], BestKnownSolution { vehicles: None, cost: 1261.8 });
+}
+
+parameterized_test! {cannot_read_invalid_best_known_solutions, content, {
+    cannot_read_invalid_best_known_solutions_impl(content);
+}}
+
+cannot_read_invalid_best_known_solutions! {
+    case01_invalid_cost: "C101,10,828.94\nC102,10,cost",
+    case02_missing_column: "C101,828.94",
+}
+
+fn cannot_read_invalid_best_known_solutions_impl(content: &str) {
+    let result = read_best_known_solutions(BufReader::new(content.as_bytes()));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_create_summary() {
+    let bks = BestKnownSolution { vehicles: Some(3), cost: 100. };
+    let runs = vec![
+        create_run("instance1", 0, 101., Some(1.), Some(1.)),
+        create_run("instance1", 1, 103., Some(3.), None),
+        create_run("instance1", 2, 102., Some(2.), Some(3.)),
+        create_run("instance2", 0, 10., None, None),
+    ];
+
+    let summary = create_summary("instance1", Some(&bks), runs.as_slice());
+
+    assert_eq!(summary.runs, 3);
+    assert_eq!(summary.best_cost, 101.);
+    assert_eq!(summary.mean_gap, Some(2.));
+    assert_eq!(summary.best_gap, Some(1.));
+    assert_eq!(summary.worst_gap, Some(3.));
+    assert_eq!(summary.mean_duration, 2.);
+    assert_eq!(summary.hits, 2);
+    assert_eq!(summary.mean_time_to_target, Some(2.));
+}
+
+#[test]
+fn can_write_summaries_as_csv() {
+    let runs = vec![create_run("instance1", 0, 101., Some(1.), Some(1.)), create_run("instance2", 0, 10., None, None)];
+    let bks = BestKnownSolution { vehicles: Some(3), cost: 100. };
+    let result = BenchResult {
+        summaries: vec![
+            create_summary("instance1", Some(&bks), runs.as_slice()),
+            create_summary("instance2", None, runs.as_slice()),
+        ],
+        runs,
+    };
+    let mut writer = BufWriter::new(Vec::new());
+
+    write_bench_csv(&result, &mut writer).unwrap();
+
+    let content = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    let lines = content.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[1], "instance1,3,100.0000,1,101.0000,1.0000,1.0000,1.0000,2.0000,1,1.0000");
+    assert_eq!(lines[2], "instance2,,,1,10.0000,,,,2.0000,0,");
+}
+
+#[test]
+fn can_run_bench() {
+    let problem = BufReader::new(File::open("../examples/data/scientific/solomon/C101.25.txt").unwrap());
+    let instances = vec![("C101.25".to_string(), Arc::new(problem.read_solomon(false).unwrap()))];
+    let bks = vec![("C101.25".to_string(), BestKnownSolution { vehicles: Some(3), cost: 191.3 })]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    let settings = BenchSettings { seeds: vec![0, 1], max_generations: Some(10), max_time: None, target_gap: 1000. };
+
+    let result = run_bench(instances.as_slice(), &bks, &settings, Arc::new(|_: &str| {})).unwrap();
+
+    assert_eq!(result.runs.len(), 2);
+    assert_eq!(result.runs.iter().map(|run| run.seed).collect::<Vec<_>>(), vec![0, 1]);
+    assert!(result.runs.iter().all(|run| run.gap.is_some_and(|gap| gap > -1.)));
+    assert_eq!(result.summaries.len(), 1);
+    assert_eq!(result.summaries[0].runs, 2);
+    assert_eq!(result.summaries[0].hits, 2);
+}
+
+#[test]
+fn can_skip_gap_of_incomplete_run() {
+    let problem = r"incomplete
+
+VEHICLE
+NUMBER     CAPACITY
+  1          10
+
+CUSTOMER
+CUST NO.  XCOORD.   YCOORD.    DEMAND   READY TIME  DUE DATE   SERVICE   TIME
+
+    0      0          0           0          0       1000          0
+    1      1          0           5          0       1000          1
+    2      2          0          20          0       1000          1
+";
+    let problem = BufReader::new(problem.as_bytes()).read_solomon(false).unwrap();
+    let instances = vec![("incomplete".to_string(), Arc::new(problem))];
+    let bks = vec![("incomplete".to_string(), BestKnownSolution { vehicles: Some(1), cost: 100. })]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    let settings = BenchSettings { seeds: vec![0], max_generations: Some(10), max_time: None, target_gap: 1000. };
+
+    let result = run_bench(instances.as_slice(), &bks, &settings, Arc::new(|_: &str| {})).unwrap();
+
+    assert_eq!(result.runs.len(), 1);
+    assert_eq!(result.runs[0].unassigned, 1);
+    assert_eq!(result.runs[0].gap, None);
+    assert_eq!(result.runs[0].time_to_target, None);
+    assert_eq!(result.summaries[0].mean_gap, None);
+}
+
+#[test]
+fn cannot_run_bench_without_seeds() {
+    let settings = BenchSettings { seeds: vec![], max_generations: Some(10), max_time: None, target_gap: 1. };
+
+    let result = run_bench(&[], &HashMap::default(), &settings, Arc::new(|_: &str| {}));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,159 @@
+use super::*;
+use crate::extensions::solve::config::Config;
+use crate::helpers::generate::SIMPLE_PROBLEM;
+use std::thread;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+fn solve_with_workers(address: &str, workers: usize) -> String {
+    let listener = Listener::bind(address).unwrap();
+    let address = listener.address().unwrap();
+    let settings = CoordinatorConfig { workers, rounds: 2, generations: 10, elite_size: 2, timeout: None };
+    let logger: InfoLogger = Arc::new(|_: &str| {});
+
+    let handles = (0..workers)
+        .map(|_| {
+            let address = address.clone();
+            let logger = logger.clone();
+            thread::spawn(move || run_worker(address.as_str(), logger))
+        })
+        .collect::<Vec<_>>();
+
+    let solution =
+        run_coordinator(&listener, SIMPLE_PROBLEM.to_string(), vec![], None, &settings, logger.clone()).unwrap();
+
+    handles.into_iter().for_each(|handle| handle.join().unwrap().unwrap());
+
+    solution
+}
+
+#[test]
+fn can_send_and_receive_messages() {
+    let listener = Listener::bind("127.0.0.1:0").unwrap();
+    let address = listener.address().unwrap();
+
+    let handle = thread::spawn(move || {
+        let mut connection = Connection::connect(address.as_str()).unwrap();
+        connection.send(&Message::Solution { solution: "{\n}".to_string() }).unwrap();
+        connection.receive().unwrap()
+    });
+    let mut connection = listener.accept(None).unwrap();
+    let received = connection.receive().unwrap();
+    connection.send(&Message::Stop).unwrap();
+
+    assert!(matches!(received, Message::Solution { solution } if solution == "{\n}"));
+    assert!(matches!(handle.join().unwrap(), Message::Stop));
+}
+
+#[test]
+fn can_solve_problem_with_multiple_workers_over_tcp() {
+    let solution = solve_with_workers("127.0.0.1:0", 2);
+
+    let solution = deserialize_solution(BufReader::new(solution.as_bytes())).unwrap();
+    assert!(!solution.tours.is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn can_solve_problem_with_multiple_workers_over_unix_socket() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("vrp.sock");
+
+    let solution = solve_with_workers(format!("unix:{}", path.display()).as_str(), 2);
+
+    let solution = deserialize_solution(BufReader::new(solution.as_bytes())).unwrap();
+    assert!(!solution.tours.is_empty());
+}
+
+#[test]
+fn cannot_run_coordinator_without_workers() {
+    let listener = Listener::bind("127.0.0.1:0").unwrap();
+    let settings = CoordinatorConfig { workers: 0, rounds: 1, generations: 1, elite_size: 1, timeout: None };
+
+    let result = run_coordinator(&listener, SIMPLE_PROBLEM.to_string(), vec![], None, &settings, Arc::new(|_| {}));
+
+    assert!(result.is_err());
+}
+
+parameterized_test! {can_drop_failed_worker, (is_disconnected, timeout), {
+    can_drop_failed_worker_impl(is_disconnected, timeout);
+}}
+
+can_drop_failed_worker! {
+    case01_disconnected: (true, None),
+    case02_not_responding: (false, Some(Duration::from_secs(5))),
+}
+
+fn can_drop_failed_worker_impl(is_disconnected: bool, timeout: Option<Duration>) {
+    let listener = Listener::bind("127.0.0.1:0").unwrap();
+    let address = listener.address().unwrap();
+    let settings = CoordinatorConfig { workers: 2, rounds: 2, generations: 10, elite_size: 2, timeout };
+    let logger: InfoLogger = Arc::new(|_: &str| {});
+
+    let failed = thread::spawn({
+        let address = address.clone();
+        move || {
+            let mut connection = Connection::connect(address.as_str()).unwrap();
+            assert!(matches!(connection.receive().unwrap(), Message::Problem { .. }));
+            if !is_disconnected {
+                assert!(matches!(connection.receive().unwrap(), Message::Solve { .. }));
+                // NOTE blocks till coordinator drops the connection
+                assert!(connection.receive().is_err());
+            }
+        }
+    });
+    let worker = thread::spawn({
+        let logger = logger.clone();
+        move || run_worker(address.as_str(), logger)
+    });
+
+    let solution = run_coordinator(&listener, SIMPLE_PROBLEM.to_string(), vec![], None, &settings, logger).unwrap();
+
+    failed.join().unwrap();
+    worker.join().unwrap().unwrap();
+    assert!(!deserialize_solution(BufReader::new(solution.as_bytes())).unwrap().tours.is_empty());
+}
+
+#[test]
+fn cannot_run_coordinator_with_zero_timeout() {
+    let listener = Listener::bind("127.0.0.1:0").unwrap();
+    let settings =
+        CoordinatorConfig { workers: 1, rounds: 1, generations: 1, elite_size: 1, timeout: Some(Duration::ZERO) };
+
+    let result = run_coordinator(&listener, SIMPLE_PROBLEM.to_string(), vec![], None, &settings, Arc::new(|_| {}));
+
+    assert!(result.is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn can_remove_socket_file() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("vrp.sock");
+    let address = format!("unix:{}", path.display());
+    // NOTE std listener leaves the socket file as if the process was killed
+    drop(UnixListener::bind(&path).unwrap());
+    assert!(path.exists());
+
+    let listener = Listener::bind(address.as_str()).unwrap();
+    assert!(path.exists());
+    drop(listener);
+
+    assert!(!path.exists());
+}
+
+#[test]
+fn can_keep_island_state_between_rounds() {
+    let problem = Arc::new(read_problem(SIMPLE_PROBLEM.to_string(), vec![]).unwrap());
+    let environment = Arc::new(Environment::default());
+    let config = Config::default();
+
+    let (_, state) = solve_round(problem.clone(), environment.clone(), &config, 5, std::iter::empty(), None).unwrap();
+    let state = state.expect("state should be kept after the first round");
+    assert_eq!(state.generation, 4);
+    assert!(!state.solutions.is_empty());
+
+    let (solution, state) = solve_round(problem, environment, &config, 5, std::iter::empty(), Some(state)).unwrap();
+    let state = state.expect("state should be kept after the second round");
+    assert_eq!(state.generation, 9);
+    assert!(!deserialize_solution(BufReader::new(solution.as_bytes())).unwrap().tours.is_empty());
+}
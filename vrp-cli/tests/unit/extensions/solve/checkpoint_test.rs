@@ -0,0 +1,78 @@
+use super::*;
+use crate::helpers::generate::SIMPLE_PROBLEM;
+use vrp_core::rosomaxa::prelude::{HeuristicObjective, HeuristicSolution};
+use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::solution::write_pragmatic;
+
+parameterized_test! {can_write_and_read_checkpoint, seed, {
+    can_write_and_read_checkpoint_impl(seed);
+}}
+
+can_write_and_read_checkpoint! {
+    case01_seeded: Some(7),
+    case02_not_seeded: None,
+}
+
+fn can_write_and_read_checkpoint_impl(seed: Option<u64>) {
+    let problem = Arc::new(SIMPLE_PROBLEM.to_string().read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let solution = vrp_core::solver::Solver::new(
+        problem.clone(),
+        vrp_core::solver::VrpConfigBuilder::new(problem.clone())
+            .prebuild()
+            .unwrap()
+            .with_max_generations(Some(1))
+            .build()
+            .unwrap(),
+    )
+    .solve()
+    .unwrap();
+    let mut writer = BufWriter::new(Vec::new());
+    write_pragmatic(problem.as_ref(), &solution, Default::default(), &mut writer).unwrap();
+    let bytes = writer.into_inner().unwrap();
+    let insertion_ctx = InsertionContext::new_from_solution(
+        problem.clone(),
+        (
+            read_init_solution(BufReader::new(bytes.as_slice()), problem.clone(), environment.random.clone()).unwrap(),
+            None,
+        ),
+        environment.clone(),
+    );
+    let original = EvolutionCheckpoint {
+        generation: 42,
+        solutions: vec![insertion_ctx.deep_copy()],
+        heuristic: vec![("best/operator".to_string(), vec![1., 2., 3.])],
+        seed,
+    };
+
+    let mut writer = BufWriter::new(Vec::new());
+    write_checkpoint(&Checkpoint::new(problem.as_ref(), original).unwrap(), &mut writer).unwrap();
+    let bytes = writer.into_inner().unwrap();
+    let checkpoint = read_checkpoint(BufReader::new(bytes.as_slice())).unwrap();
+    let restored = checkpoint.into_evolution(problem.clone(), environment).unwrap();
+
+    assert_eq!(restored.generation, 42);
+    assert_eq!(restored.seed, seed);
+    assert_eq!(restored.heuristic, vec![("best/operator".to_string(), vec![1., 2., 3.])]);
+    assert_eq!(restored.solutions.len(), 1);
+    assert_eq!(problem.goal.total_order(&restored.solutions[0], &insertion_ctx), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn can_write_and_read_heuristic_priors() {
+    let original = vec![("best/operator".to_string(), vec![1., 2., 3., 4., 5.])];
+
+    let mut writer = BufWriter::new(Vec::new());
+    write_heuristic_priors(original.clone(), &mut writer).unwrap();
+    let bytes = writer.into_inner().unwrap();
+    let restored = read_heuristic_priors(BufReader::new(bytes.as_slice())).unwrap();
+
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn cannot_read_invalid_heuristic_priors() {
+    let result = read_heuristic_priors(BufReader::new("{}".as_bytes()));
+
+    assert!(result.is_err());
+}
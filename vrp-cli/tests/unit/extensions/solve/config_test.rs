@@ -77,7 +77,7 @@ fn can_read_full_config() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.05);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 8);
                 }
                 _ => unreachable!(),
             }
@@ -86,7 +86,7 @@ fn can_read_full_config() {
                 SearchOperatorType::RuinRecreate { probability, ruins, recreates } => {
                     assert_eq!(as_scalar_probability(probability), 1.);
                     assert_eq!(ruins.len(), 7);
-                    assert_eq!(recreates.len(), 12);
+                    assert_eq!(recreates.len(), 13);
                 }
                 _ => unreachable!(),
             }
@@ -100,15 +100,20 @@ fn can_read_full_config() {
                 _ => unreachable!(),
             }
         }
-        HyperType::DynamicSelective => unreachable!(),
+        HyperType::DynamicSelective | HyperType::AdaptiveSelective { .. } => unreachable!(),
     }
 
     let termination = config.termination.expect("no termination config");
     assert_eq!(termination.max_time, Some(300));
     assert_eq!(termination.max_generations, Some(3000));
+    let stagnation = termination.stagnation.expect("no stagnation config");
+    assert_eq!(stagnation.interval_type, "sample");
+    assert_eq!(stagnation.value, 3000);
+    assert_eq!(stagnation.tolerance, Some(vec![0., 0.001]));
 
     let environment = config.environment.expect("no environment config");
     assert_eq!(environment.is_experimental, Some(false));
+    assert_eq!(environment.seed, Some(42));
 
     let parallelism = environment.parallelism.expect("no parallelism config");
     assert_eq!(parallelism.num_thread_pools, 6);
@@ -137,7 +142,13 @@ fn can_configure_telemetry_metrics() {
     let config = Config {
         evolution: None,
         hyper: None,
-        termination: Some(TerminationConfig { max_time: None, max_generations: Some(100), variation: None }),
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_generations: Some(100),
+            variation: None,
+            stagnation: None,
+            target_cost: None,
+        }),
         environment: None,
         telemetry: Some(TelemetryConfig {
             progress: None,
@@ -157,6 +168,254 @@ fn can_configure_telemetry_metrics() {
     assert_eq!(metrics.evolution.len(), 10 + 1);
 }
 
+#[test]
+fn can_stop_search_with_target_cost_from_config() {
+    let config = Config {
+        evolution: None,
+        hyper: None,
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_generations: Some(1000),
+            variation: None,
+            stagnation: None,
+            target_cost: Some(f64::MAX),
+        }),
+        environment: None,
+        telemetry: Some(TelemetryConfig {
+            progress: None,
+            metrics: Some(MetricsConfig { enabled: true, track_population: Some(10) }),
+        }),
+        output: None,
+    };
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    let metrics = solution.telemetry.expect("no metrics");
+    assert!(metrics.generations < 1000);
+}
+
+#[test]
+fn can_use_crossover_operator_from_config() {
+    let config = r#"
+    {
+      "hyper": {
+        "type": "static-selective",
+        "operators": [
+          {
+            "type": "crossover",
+            "probability": { "scalar": 0.5 },
+            "maxRoutes": 2,
+            "recreates": [{ "type": "cheapest", "weight": 1 }]
+          },
+          {
+            "type": "ruin-recreate",
+            "probability": { "scalar": 1 },
+            "ruins": [{ "weight": 1, "methods": [{ "type": "random-job", "probability": 1, "min": 1, "max": 2 }] }],
+            "recreates": [{ "type": "cheapest", "weight": 1 }]
+          }
+        ]
+      },
+      "termination": { "maxGenerations": 10 }
+    }
+    "#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    match config.hyper.as_ref().and_then(|hyper| match hyper {
+        HyperType::StaticSelective { operators } => operators.as_ref().and_then(|operators| operators.first()),
+        _ => None,
+    }) {
+        Some(SearchOperatorType::Crossover { probability, max_routes, recreates }) => {
+            assert_eq!(as_scalar_probability(probability), 0.5);
+            assert_eq!(*max_routes, 2);
+            assert_eq!(recreates.len(), 1);
+        }
+        _ => unreachable!(),
+    }
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve());
+
+    assert!(solution.is_ok());
+}
+
+#[test]
+fn can_use_set_partitioning_operator_from_config() {
+    let config = r#"
+    {
+      "hyper": {
+        "type": "static-selective",
+        "operators": [
+          {
+            "type": "set-partitioning",
+            "probability": { "scalar": 0.5 },
+            "poolSize": 100,
+            "maxSolutions": 2,
+            "maxNodes": 1000,
+            "recreates": [{ "type": "cheapest", "weight": 1 }]
+          },
+          {
+            "type": "ruin-recreate",
+            "probability": { "scalar": 1 },
+            "ruins": [{ "weight": 1, "methods": [{ "type": "random-job", "probability": 1, "min": 1, "max": 2 }] }],
+            "recreates": [{ "type": "cheapest", "weight": 1 }]
+          }
+        ]
+      },
+      "termination": { "maxGenerations": 10 }
+    }
+    "#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    match config.hyper.as_ref().and_then(|hyper| match hyper {
+        HyperType::StaticSelective { operators } => operators.as_ref().and_then(|operators| operators.first()),
+        _ => None,
+    }) {
+        Some(SearchOperatorType::SetPartitioning { probability, pool_size, max_solutions, max_nodes, recreates }) => {
+            assert_eq!(as_scalar_probability(probability), 0.5);
+            assert_eq!(*pool_size, 100);
+            assert_eq!(*max_solutions, 2);
+            assert_eq!(*max_nodes, 1000);
+            assert_eq!(recreates.len(), 1);
+        }
+        _ => unreachable!(),
+    }
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve());
+
+    assert!(solution.is_ok());
+}
+
+#[test]
+fn can_use_popmusic_operator_from_config() {
+    let config = r#"
+    {
+      "hyper": {
+        "type": "static-selective",
+        "operators": [
+          {
+            "type": "popmusic",
+            "probability": { "scalar": 0.5 },
+            "routes": 3,
+            "repeat": 2,
+            "rounds": 4
+          },
+          {
+            "type": "ruin-recreate",
+            "probability": { "scalar": 1 },
+            "ruins": [{ "weight": 1, "methods": [{ "type": "random-job", "probability": 1, "min": 1, "max": 2 }] }],
+            "recreates": [{ "type": "cheapest", "weight": 1 }]
+          }
+        ]
+      },
+      "termination": { "maxGenerations": 10 }
+    }
+    "#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    match config.hyper.as_ref().and_then(|hyper| match hyper {
+        HyperType::StaticSelective { operators } => operators.as_ref().and_then(|operators| operators.first()),
+        _ => None,
+    }) {
+        Some(SearchOperatorType::Popmusic { routes, repeat, rounds, probability }) => {
+            assert_eq!(as_scalar_probability(probability), 0.5);
+            assert_eq!(*routes, 3);
+            assert_eq!(*repeat, 2);
+            assert_eq!(*rounds, 4);
+        }
+        _ => unreachable!(),
+    }
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve());
+
+    assert!(solution.is_ok());
+}
+
+#[test]
+fn can_use_adaptive_selective_heuristic_from_config() {
+    let config = r#"
+    {
+      "hyper": {
+        "type": "adaptive-selective",
+        "acceptance": { "type": "late-acceptance", "length": 10 },
+        "segmentSize": 20
+      },
+      "termination": { "maxGenerations": 10 }
+    }
+    "#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    match config.hyper.as_ref() {
+        Some(HyperType::AdaptiveSelective { acceptance, segment_size, reaction_factor }) => {
+            assert!(matches!(acceptance, Some(AcceptanceType::LateAcceptance { length: 10 })));
+            assert_eq!(*segment_size, Some(20));
+            assert!(reaction_factor.is_none());
+        }
+        _ => unreachable!(),
+    }
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve());
+
+    assert!(solution.is_ok());
+}
+
+#[test]
+fn can_use_island_model_from_config() {
+    let config = r#"
+    {
+      "evolution": {
+        "islands": {
+          "count": 3,
+          "migrationInterval": 2,
+          "hypers": [
+            { "type": "dynamic-selective" },
+            { "type": "adaptive-selective" }
+          ]
+        }
+      },
+      "termination": { "maxGenerations": 10 }
+    }
+    "#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let islands = config.evolution.as_ref().and_then(|evolution| evolution.islands.as_ref()).unwrap();
+    assert_eq!(islands.count, 3);
+    assert_eq!(islands.migration_interval, Some(2));
+    assert!(islands.migration_size.is_none());
+    assert_eq!(islands.hypers.as_ref().map(|hypers| hypers.len()), Some(2));
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve());
+
+    assert!(solution.is_ok());
+}
+
+#[test]
+fn cannot_use_island_model_without_islands() {
+    let config = r#"{ "evolution": { "islands": { "count": 0 } } }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let result = create_builder_from_config(create_example_problem(), Vec::default(), &config);
+
+    assert!(result.is_err());
+}
+
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
     match probability {
         OperatorProbabilityType::Scalar { scalar } => *scalar,
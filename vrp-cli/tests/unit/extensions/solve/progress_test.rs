@@ -0,0 +1,95 @@
+use super::*;
+use crate::extensions::solve::config::{create_builder_from_config, Config, TerminationConfig};
+use crate::helpers::generate::SIMPLE_PROBLEM;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn solve_with_progress(problem: Arc<Problem>, include_solution: bool) -> Vec<String> {
+    let config = Config {
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_generations: Some(10),
+            variation: None,
+            stagnation: None,
+            target_cost: None,
+        }),
+        ..Config::default()
+    };
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let listener = create_progress_listener(problem.clone(), include_solution, {
+        let lines = lines.clone();
+        Box::new(move |line| lines.lock().unwrap().push(line))
+    });
+
+    let config = create_builder_from_config(problem.clone(), Vec::default(), &config)
+        .unwrap()
+        .with_progress(Some(listener))
+        .build()
+        .unwrap();
+    Solver::new(problem, config).solve().unwrap();
+
+    let mut lines = lines.lock().unwrap();
+    std::mem::take(&mut lines)
+}
+
+#[test]
+fn can_stream_progress_events_without_solution() {
+    let lines = solve_with_progress(Arc::new(SIMPLE_PROBLEM.to_string().read_pragmatic().unwrap()), false);
+
+    assert!(!lines.is_empty());
+    lines.iter().for_each(|line| {
+        assert!(!line.contains('\n'));
+        let record = read_progress_record(line).unwrap();
+        assert!(!record.fitness.is_empty());
+        assert!(record.solution.is_none());
+    });
+}
+
+#[test]
+fn can_stream_progress_events_with_solution() {
+    let lines = solve_with_progress(Arc::new(SIMPLE_PROBLEM.to_string().read_pragmatic().unwrap()), true);
+
+    assert!(!lines.is_empty());
+    let records = lines.iter().map(|line| read_progress_record(line).unwrap()).collect::<Vec<_>>();
+    assert!(records.iter().all(|record| record.solution.as_ref().is_some_and(|solution| !solution.tours.is_empty())));
+    assert!(records.windows(2).all(|pair| pair[0].generation < pair[1].generation));
+}
+
+#[test]
+fn can_stream_progress_events_with_unwrapped_clusters() {
+    let mut problem: serde_json::Value = serde_json::from_str(SIMPLE_PROBLEM).unwrap();
+    let job = problem["plan"]["jobs"][0].clone();
+    problem["plan"]["jobs"] = (1..=4)
+        .map(|idx| {
+            let mut job = job.clone();
+            job["id"] = format!("job{idx}").into();
+            job["deliveries"][0]["places"][0]["location"]["lng"] = (13.4023099 + 0.0001 * idx as f64).into();
+            job
+        })
+        .collect();
+    problem["plan"]["clustering"] = serde_json::json!({
+        "type": "vicinity",
+        "profile": { "matrix": "normal_car" },
+        "threshold": { "duration": 120, "distance": 100 },
+        "visiting": "continue",
+        "serving": { "type": "original", "parking": 0 }
+    });
+    let problem = Arc::new(problem.to_string().read_pragmatic().unwrap());
+
+    let lines = solve_with_progress(problem, true);
+
+    assert!(!lines.is_empty());
+    lines.iter().map(|line| read_progress_record(line).unwrap().solution.unwrap()).for_each(|solution| {
+        let job_ids = solution
+            .tours
+            .iter()
+            .flat_map(|tour| tour.stops.iter())
+            .flat_map(|stop| stop.activities().iter())
+            .filter(|activity| activity.activity_type == "delivery")
+            .map(|activity| activity.job_id.as_str())
+            .collect::<HashSet<_>>();
+
+        assert_eq!(job_ids, HashSet::from(["job1", "job2", "job3", "job4"]));
+    });
+}
@@ -0,0 +1,174 @@
+use super::*;
+use crate::extensions::solve::config::read_config;
+use crate::helpers::generate::SIMPLE_PROBLEM;
+use serde_json::json;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn create_instances(size: usize) -> Vec<TuneInstance> {
+    (0..size)
+        .map(|idx| TuneInstance {
+            name: format!("instance{idx}"),
+            problem: Arc::new(SIMPLE_PROBLEM.to_string().read_pragmatic().unwrap()),
+        })
+        .collect()
+}
+
+fn create_space() -> ParameterSpace {
+    ParameterSpace {
+        base: Some(json!({ "evolution": { "population": { "type": "rosomaxa", "selectionSize": 4 } } })),
+        parameters: vec![
+            Parameter {
+                path: "/evolution/population/selectionSize".to_string(),
+                domain: ParameterDomain::Int { min: 2, max: 8 },
+            },
+            Parameter {
+                path: "/evolution/population/spreadFactor".to_string(),
+                domain: ParameterDomain::Float { min: 0.25, max: 1. },
+            },
+            Parameter {
+                path: "/hyper".to_string(),
+                domain: ParameterDomain::Categorical {
+                    values: vec![json!({ "type": "static-selective" }), json!({ "type": "dynamic-selective" })],
+                },
+            },
+        ],
+    }
+}
+
+fn create_settings() -> TuneSettings {
+    TuneSettings { iterations: 2, candidates: 3, elite_size: 1, min_instances: 1, generations: 5 }
+}
+
+#[test]
+fn can_read_parameter_space() {
+    let space = r#"{
+        "parameters": [
+            { "path": "/a", "type": "int", "min": 1, "max": 2 },
+            { "path": "/b", "type": "float", "min": 0.1, "max": 0.2 },
+            { "path": "/c", "type": "categorical", "values": ["x", 1] }
+        ]
+    }"#;
+
+    let space = read_parameter_space(BufReader::new(space.as_bytes())).unwrap();
+
+    assert!(space.base.is_none());
+    assert!(matches!(space.parameters[0].domain, ParameterDomain::Int { min: 1, max: 2 }));
+    assert!(matches!(space.parameters[1].domain, ParameterDomain::Float { .. }));
+    assert!(matches!(&space.parameters[2].domain, ParameterDomain::Categorical { values } if values.len() == 2));
+}
+
+parameterized_test! {can_set_value, (root, path, expected), {
+    can_set_value_impl(root, path, expected);
+}}
+
+can_set_value! {
+    case01_existing_property: (json!({ "a": { "b": 1 } }), "/a/b", Some(json!({ "a": { "b": 42 } }))),
+    case02_missing_objects: (json!({}), "/a/b", Some(json!({ "a": { "b": 42 } }))),
+    case03_array_item: (json!({ "a": [1, 2] }), "/a/1", Some(json!({ "a": [1, 42] }))),
+    case04_array_item_property: (json!({ "a": [{}] }), "/a/0/b", Some(json!({ "a": [{ "b": 42 }] }))),
+    case05_missing_array_item: (json!({ "a": [] }), "/a/0", None),
+    case06_non_object: (json!({ "a": 1 }), "/a/b", None),
+    case07_no_root: (json!({}), "a", None),
+}
+
+fn can_set_value_impl(mut root: Value, path: &str, expected: Option<Value>) {
+    let result = set_value(&mut root, path, json!(42));
+
+    match expected {
+        Some(expected) => {
+            assert!(result.is_ok());
+            assert_eq!(root, expected);
+        }
+        None => assert!(result.is_err()),
+    }
+}
+
+#[test]
+fn can_sample_values_within_domain() {
+    let random = DefaultRandom::default();
+    let int_domain = ParameterDomain::Int { min: 2, max: 4 };
+    let float_domain = ParameterDomain::Float { min: 0.5, max: 1. };
+    let categorical_domain = ParameterDomain::Categorical { values: vec![json!("a"), json!("b")] };
+
+    (0..100).for_each(|_| {
+        let value = sample_value(&int_domain, Some(&json!(3)), 1., &random).as_i64().unwrap();
+        assert!((2..=4).contains(&value));
+
+        let value = sample_value(&float_domain, None, 1., &random).as_f64().unwrap();
+        assert!((0.5..=1.).contains(&value));
+
+        let value = sample_value(&categorical_domain, Some(&json!("a")), 0., &random);
+        assert_eq!(value, json!("a"));
+    });
+}
+
+parameterized_test! {can_sample_int_value_with_non_integer_parent, (parent, expected), {
+    let random = DefaultRandom::default();
+    let int_domain = ParameterDomain::Int { min: 2, max: 4 };
+
+    (0..10).for_each(|_| {
+        let value = sample_value(&int_domain, Some(&json!(parent)), 0.1, &random).as_i64().unwrap();
+        assert_eq!(value, expected);
+    });
+}}
+
+can_sample_int_value_with_non_integer_parent! {
+    case01_round_down: (3.4, 3),
+    case02_round_up: (2.6, 3),
+    case03_below_min: (1.2, 2),
+    case04_above_max: (4.7, 4),
+}
+
+#[test]
+fn can_run_tune() {
+    let instances = create_instances(2);
+
+    let result = run_tune(&create_space(), instances.as_slice(), &create_settings(), Arc::default()).unwrap();
+
+    let config = serde_json::to_string(&result.config).unwrap();
+    assert!(read_config(BufReader::new(config.as_bytes())).is_ok());
+    assert!(result.config.pointer("/evolution/population/selectionSize").is_some());
+    assert_eq!(result.report.instances.len(), 2);
+    assert_eq!(result.report.wins + result.report.losses + result.report.ties, 2);
+    assert!(result.report.evaluations > 0);
+}
+
+parameterized_test! {can_validate_settings, (instances, parameters, candidates, elite_size), {
+    can_validate_settings_impl(instances, parameters, candidates, elite_size);
+}}
+
+can_validate_settings! {
+    case01_no_instances: (0, true, 3, 1),
+    case02_no_parameters: (1, false, 3, 1),
+    case03_zero_elite_size: (1, true, 3, 0),
+    case04_less_candidates_than_elite: (1, true, 1, 2),
+}
+
+fn can_validate_settings_impl(instances: usize, parameters: bool, candidates: usize, elite_size: usize) {
+    let mut space = create_space();
+    if !parameters {
+        space.parameters.clear();
+    }
+    let settings = TuneSettings { candidates, elite_size, ..create_settings() };
+
+    let result = run_tune(&space, create_instances(instances).as_slice(), &settings, Arc::default());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_eliminate_invalid_candidates() {
+    let space = ParameterSpace {
+        parameters: vec![Parameter {
+            path: "/hyper".to_string(),
+            domain: ParameterDomain::Categorical { values: vec![json!({ "type": "unknown" })] },
+        }],
+        ..create_space()
+    };
+    let instances = create_instances(1);
+
+    let result = run_tune(&space, instances.as_slice(), &create_settings(), Arc::default()).unwrap();
+
+    assert!(result.config.pointer("/hyper").is_none());
+    assert_eq!(result.report.ties, 1);
+}
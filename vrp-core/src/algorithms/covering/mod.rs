@@ -0,0 +1,3 @@
+//! This module contains implementation of set covering and partitioning algorithms.
+
+pub mod set_partitioning;
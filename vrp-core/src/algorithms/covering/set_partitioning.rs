@@ -0,0 +1,157 @@
+//! This module contains a depth-first branch and bound solver for set partitioning problem.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/covering/set_partitioning_test.rs"]
+mod set_partitioning_test;
+
+use std::collections::HashMap;
+
+/// Represents a column of set partitioning problem: a subset of items with its cost.
+#[derive(Clone, Debug)]
+pub struct Column {
+    /// Indices of items covered by the column.
+    pub items: Vec<usize>,
+    /// A group of the column. Amount of selected columns from the same group can be limited.
+    pub group: usize,
+    /// A cost of the column.
+    pub cost: f64,
+}
+
+/// Specifies set partitioning problem: find a subset of columns with minimal total cost which
+/// covers each item exactly once and does not exceed group limits.
+pub struct SetPartitioningProblem<'a> {
+    /// Total amount of items. Items are indexed from zero.
+    pub items: usize,
+    /// Available columns.
+    pub columns: &'a [Column],
+    /// Max amount of selected columns per group. Groups without limit are not restricted.
+    pub group_limits: &'a HashMap<usize, usize>,
+}
+
+/// Solves set partitioning problem using depth-first branch and bound search limited by amount
+/// of explored nodes. A lower bound is estimated by distributing column cost evenly among its items.
+/// Returns indices of selected columns for the best found solution which is cheaper than `upper_bound`.
+pub fn solve_set_partitioning(
+    problem: &SetPartitioningProblem,
+    upper_bound: f64,
+    max_nodes: usize,
+) -> Option<Vec<usize>> {
+    let mut item_columns = problem.columns.iter().enumerate().fold(
+        vec![Vec::<usize>::default(); problem.items],
+        |mut acc, (column_idx, column)| {
+            column.items.iter().for_each(|&item| acc[item].push(column_idx));
+            acc
+        },
+    );
+
+    let get_ratio = |column_idx: usize| {
+        let column = &problem.columns[column_idx];
+        column.cost / column.items.len().max(1) as f64
+    };
+
+    item_columns.iter_mut().for_each(|columns| columns.sort_by(|&a, &b| get_ratio(a).total_cmp(&get_ratio(b))));
+
+    let item_bounds = item_columns
+        .iter()
+        .map(|columns| columns.first().map_or(f64::MAX, |&column_idx| get_ratio(column_idx)))
+        .collect::<Vec<_>>();
+
+    // NOTE branch on items with the smallest amount of columns first
+    let mut item_order = (0..problem.items).collect::<Vec<_>>();
+    item_order.sort_by_key(|&item| item_columns[item].len());
+
+    let mut search = BranchAndBound {
+        problem,
+        item_columns,
+        item_bounds,
+        item_order,
+        covered: vec![false; problem.items],
+        group_usage: HashMap::default(),
+        selected: Vec::default(),
+        best: None,
+        upper_bound,
+        nodes: 0,
+        max_nodes,
+    };
+
+    search.explore(0.);
+
+    search.best
+}
+
+struct BranchAndBound<'a> {
+    problem: &'a SetPartitioningProblem<'a>,
+    item_columns: Vec<Vec<usize>>,
+    item_bounds: Vec<f64>,
+    item_order: Vec<usize>,
+    covered: Vec<bool>,
+    group_usage: HashMap<usize, usize>,
+    selected: Vec<usize>,
+    best: Option<Vec<usize>>,
+    upper_bound: f64,
+    nodes: usize,
+    max_nodes: usize,
+}
+
+impl BranchAndBound<'_> {
+    fn explore(&mut self, cost: f64) {
+        if self.nodes >= self.max_nodes {
+            return;
+        }
+        self.nodes += 1;
+
+        let Some(item) = self.item_order.iter().find(|&&item| !self.covered[item]).copied() else {
+            if cost < self.upper_bound {
+                self.upper_bound = cost;
+                self.best = Some(self.selected.clone());
+            }
+            return;
+        };
+
+        let bound =
+            (0..self.problem.items).filter(|&item| !self.covered[item]).map(|item| self.item_bounds[item]).sum::<f64>();
+        if cost + bound >= self.upper_bound {
+            return;
+        }
+
+        for candidate_idx in 0..self.item_columns[item].len() {
+            let column_idx = self.item_columns[item][candidate_idx];
+            if !self.can_select(column_idx) {
+                continue;
+            }
+
+            self.toggle(column_idx, true);
+            self.explore(cost + self.problem.columns[column_idx].cost);
+            self.toggle(column_idx, false);
+
+            if self.nodes >= self.max_nodes {
+                break;
+            }
+        }
+    }
+
+    fn can_select(&self, column_idx: usize) -> bool {
+        let column = &self.problem.columns[column_idx];
+
+        let usage = self.group_usage.get(&column.group).copied().unwrap_or(0);
+        let is_group_available =
+            self.problem.group_limits.get(&column.group).filter(|&&limit| usage >= limit).is_none();
+
+        is_group_available && column.items.iter().all(|&item| !self.covered[item])
+    }
+
+    fn toggle(&mut self, column_idx: usize, is_selected: bool) {
+        let column = &self.problem.columns[column_idx];
+
+        column.items.iter().for_each(|&item| self.covered[item] = is_selected);
+
+        let usage = self.group_usage.entry(column.group).or_insert(0);
+        if is_selected {
+            *usage += 1;
+            self.selected.push(column_idx);
+        } else {
+            *usage -= 1;
+            self.selected.pop();
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! A collection of reusable algorithms without dependencies on any other module in the project.
 
 pub mod clustering;
+pub mod covering;
 pub mod geometry;
@@ -22,6 +22,9 @@ pub use self::route_intervals::*;
 mod reserved_time;
 pub use self::reserved_time::*;
 
+mod route_segments;
+pub use self::route_segments::*;
+
 mod schedule_update;
 pub use self::schedule_update::*;
 
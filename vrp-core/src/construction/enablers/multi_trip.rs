@@ -150,10 +150,12 @@ impl FeatureState for MultiTripState {
         let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
         if intervals.is_marker_job(job) {
             // move all unassigned marker jobs back to ignored
-            let jobs = self.filter_markers(route_ctx.route(), &solution_ctx.required).collect::<HashSet<_>>();
-            solution_ctx.required.retain(|job| !jobs.contains(job));
-            solution_ctx.unassigned.retain(|job, _| !jobs.contains(job));
-            solution_ctx.ignored.extend(jobs);
+            let jobs = self.filter_markers(route_ctx.route(), &solution_ctx.required).collect::<Vec<_>>();
+            let mut job_set = jobs.iter().cloned().collect::<HashSet<_>>();
+            solution_ctx.required.retain(|job| !job_set.contains(job));
+            solution_ctx.unassigned.retain(|job, _| !job_set.contains(job));
+            // NOTE keep original order of jobs to make it deterministic
+            solution_ctx.ignored.extend(jobs.into_iter().filter(|job| job_set.remove(job)));
             // NOTE reevaluate insertion of unassigned due to multi-trip constraint jobs
             solution_ctx.unassigned.iter_mut().for_each(|pair| match pair.1 {
                 UnassignmentInfo::Simple(code) if *code == self.code => {
@@ -166,11 +168,13 @@ impl FeatureState for MultiTripState {
             let jobs = self
                 .filter_markers(route_ctx.route(), &solution_ctx.ignored)
                 .chain(self.filter_markers(route_ctx.route(), &solution_ctx.required))
-                .collect::<HashSet<_>>();
+                .collect::<Vec<_>>();
+            let mut job_set = jobs.iter().cloned().collect::<HashSet<_>>();
 
-            solution_ctx.ignored.retain(|job| !jobs.contains(job));
-            solution_ctx.locked.extend(jobs.iter().cloned());
-            solution_ctx.required.extend(jobs);
+            solution_ctx.ignored.retain(|job| !job_set.contains(job));
+            solution_ctx.locked.extend(job_set.iter().cloned());
+            // NOTE keep original order of jobs to make it deterministic
+            solution_ctx.required.extend(jobs.into_iter().filter(|job| job_set.remove(job)));
         }
     }
 
@@ -166,7 +166,8 @@ impl RouteIntervals {
                 self.filter_markers(route_ctx.route(), &solution_ctx.ignored)
                     .chain(self.filter_markers(route_ctx.route(), &solution_ctx.required))
             })
-            .collect::<HashSet<_>>();
+            .collect::<Vec<_>>();
+        let mut candidate_set = candidate_jobs.iter().cloned().collect::<HashSet<_>>();
 
         // NOTE: get already assigned jobs to guarantee locking them
         let assigned_job = solution_ctx
@@ -176,9 +177,10 @@ impl RouteIntervals {
             .filter(|job| self.is_marker_job(job))
             .cloned();
 
-        solution_ctx.ignored.retain(|job| !candidate_jobs.contains(job));
-        solution_ctx.locked.extend(candidate_jobs.iter().cloned().chain(assigned_job));
-        solution_ctx.required.extend(candidate_jobs);
+        solution_ctx.ignored.retain(|job| !candidate_set.contains(job));
+        solution_ctx.locked.extend(candidate_set.iter().cloned().chain(assigned_job));
+        // NOTE keep original order of jobs to make it deterministic
+        solution_ctx.required.extend(candidate_jobs.into_iter().filter(|job| candidate_set.remove(job)));
     }
 }
 
@@ -0,0 +1,159 @@
+//! Provides time window segments of the route: summaries of route prefixes and suffixes are built once,
+//! so time window violations of a new route created by concatenation of `k` existing segments are
+//! estimated with `k` merges instead of schedule recalculation. Only time windows, service and travel
+//! durations are considered, so segments are used to prefilter local search moves (2-opt, 2-opt*, Or-opt
+//! and CROSS-exchange) which are checked by insertion evaluation afterwards. Segments are not kept in
+//! route state: they are created on demand by search operators which evaluate many moves on the same routes.
+//!
+//! The idea is described in [`Vidal et al. (2013)`].
+//!
+//! [`Vidal et al. (2013)`]: https://doi.org/10.1016/j.cor.2012.07.018
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/route_segments_test.rs"]
+mod route_segments_test;
+
+use crate::models::common::{Duration, Location, Profile, TimeWindow, Timestamp};
+use crate::models::problem::TransportCost;
+use crate::models::solution::{Activity, Route};
+
+/// Summarizes time related properties of a sequence of activities.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeSegment {
+    /// Location of the first activity.
+    pub first: Location,
+    /// Location of the last activity.
+    pub last: Location,
+    /// Total duration of the segment: service, travel and waiting time.
+    pub duration: Duration,
+    /// Total time warp: amount of time by which time windows are violated.
+    pub time_warp: Duration,
+    /// Earliest time when the first activity can be started.
+    pub earliest: Timestamp,
+    /// Latest time when the first activity can be started without additional time warp.
+    pub latest: Timestamp,
+}
+
+impl TimeSegment {
+    /// Creates a segment which consists of one activity.
+    pub fn new(location: Location, time: &TimeWindow, service: Duration) -> Self {
+        Self {
+            first: location,
+            last: location,
+            duration: service,
+            time_warp: 0.,
+            earliest: time.start,
+            latest: time.end,
+        }
+    }
+
+    /// Creates a segment from given activity.
+    pub fn from_activity(activity: &Activity) -> Self {
+        Self::new(activity.place.location, &activity.place.time, activity.place.duration)
+    }
+
+    /// Concatenates two segments using travel duration between the last activity of the first segment
+    /// and the first activity of the second segment.
+    pub fn merge(&self, other: &Self, travel: Duration) -> Self {
+        let delta = self.duration - self.time_warp + travel;
+        let delta_waiting = (other.earliest - delta - self.latest).max(0.);
+        let delta_time_warp = (self.earliest + delta - other.latest).max(0.);
+
+        Self {
+            first: self.first,
+            last: other.last,
+            duration: self.duration + other.duration + travel + delta_waiting,
+            time_warp: self.time_warp + other.time_warp + delta_time_warp,
+            earliest: (other.earliest - delta).max(self.earliest) - delta_waiting,
+            latest: (other.latest - delta).min(self.latest) + delta_time_warp,
+        }
+    }
+}
+
+/// Keeps summaries of route prefixes and suffixes.
+#[derive(Clone, Debug)]
+pub struct RouteSegments<S> {
+    forward: Vec<S>,
+    backward: Vec<S>,
+}
+
+impl<S: Clone> RouteSegments<S> {
+    /// Creates route segments from segments of individual activities using merge function
+    /// which accepts two segments.
+    pub fn new(segments: Vec<S>, merge_fn: impl Fn(&S, &S) -> S) -> Self {
+        let forward = segments.iter().skip(1).fold(
+            segments.first().cloned().into_iter().collect::<Vec<_>>(),
+            |mut acc, segment| {
+                let merged = merge_fn(acc.last().expect("forward segments are not empty"), segment);
+                acc.push(merged);
+                acc
+            },
+        );
+
+        let mut backward = segments.iter().rev().skip(1).fold(
+            segments.last().cloned().into_iter().collect::<Vec<_>>(),
+            |mut acc, segment| {
+                let merged = merge_fn(segment, acc.last().expect("backward segments are not empty"));
+                acc.push(merged);
+                acc
+            },
+        );
+        backward.reverse();
+
+        Self { forward, backward }
+    }
+
+    /// Returns a summary of activities from the start of the route till given activity index (inclusive).
+    pub fn prefix(&self, activity_idx: usize) -> Option<&S> {
+        self.forward.get(activity_idx)
+    }
+
+    /// Returns a summary of activities from given activity index (inclusive) till the end of the route.
+    pub fn suffix(&self, activity_idx: usize) -> Option<&S> {
+        self.backward.get(activity_idx)
+    }
+
+    /// Returns a summary of the whole route.
+    pub fn total(&self) -> Option<&S> {
+        self.forward.last()
+    }
+}
+
+/// Creates time segments of the route. Departure from the route start is considered as fixed.
+pub fn create_route_time_segments(
+    route: &Route,
+    transport: &(dyn TransportCost + Send + Sync),
+) -> RouteSegments<TimeSegment> {
+    let profile = &route.actor.vehicle.profile;
+
+    let segments = route
+        .tour
+        .all_activities()
+        .enumerate()
+        .map(|(activity_idx, activity)| {
+            if activity_idx == 0 {
+                let departure = activity.schedule.departure;
+                TimeSegment::new(activity.place.location, &TimeWindow::new(departure, departure), 0.)
+            } else {
+                TimeSegment::from_activity(activity)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    RouteSegments::new(segments, |left, right| {
+        left.merge(right, transport.duration_approx(profile, left.last, right.first))
+    })
+}
+
+/// Evaluates a new route created by concatenation of given time segments in the given order.
+/// Returns `None` if no segments are given.
+pub fn evaluate_time_segments<'a>(
+    profile: &Profile,
+    transport: &(dyn TransportCost + Send + Sync),
+    segments: impl IntoIterator<Item = &'a TimeSegment>,
+) -> Option<TimeSegment> {
+    segments.into_iter().fold(None, |acc: Option<TimeSegment>, segment| match acc {
+        Some(acc) => Some(acc.merge(segment, transport.duration_approx(profile, acc.last, segment.first))),
+        None => Some(segment.clone()),
+    })
+}
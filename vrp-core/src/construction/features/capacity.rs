@@ -29,18 +29,27 @@ pub trait JobDemandDimension {
     fn get_job_demand<T: LoadOps>(&self) -> Option<&Demand<T>>;
 }
 
+type IsSoftCapacityFn = Arc<dyn Fn(&Vehicle) -> bool + Send + Sync>;
+
 /// Provides a way to build capacity limit feature.
 pub struct CapacityFeatureBuilder<T: LoadOps> {
     name: String,
     route_intervals: Option<RouteIntervals>,
     violation_code: Option<ViolationCode>,
+    is_soft_fn: Option<IsSoftCapacityFn>,
     phantom_data: PhantomData<T>,
 }
 
 impl<T: LoadOps> CapacityFeatureBuilder<T> {
     /// Creates a new instance of `CapacityFeatureBuilder`
     pub fn new(name: &str) -> Self {
-        Self { name: name.to_string(), route_intervals: None, violation_code: None, phantom_data: Default::default() }
+        Self {
+            name: name.to_string(),
+            route_intervals: None,
+            violation_code: None,
+            is_soft_fn: None,
+            phantom_data: Default::default(),
+        }
     }
 
     /// Sets constraint violation code which is used to report back the reason of job's unassignment.
@@ -55,17 +64,33 @@ impl<T: LoadOps> CapacityFeatureBuilder<T> {
         self
     }
 
+    /// Sets a function which checks whether vehicle's capacity is a soft limit. Capacity of such vehicle can be
+    /// exceeded, so its violation is expected to be penalized by an objective, see [SoftLimitsFeatureBuilder].
+    pub fn set_is_soft_capacity<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Vehicle) -> bool + Send + Sync + 'static,
+    {
+        self.is_soft_fn = Some(Arc::new(func));
+        self
+    }
+
     /// Builds a feature.
     pub fn build(self) -> GenericResult<Feature> {
         let name = self.name.as_str();
         let violation_code = self.violation_code.unwrap_or_default();
+        let is_soft_fn = self.is_soft_fn;
 
         if let Some(route_intervals) = self.route_intervals {
             create_multi_trip_feature(
                 name,
                 violation_code,
                 MarkerInsertionPolicy::Last,
-                Arc::new(CapacitatedMultiTrip::<T> { route_intervals, violation_code, phantom: Default::default() }),
+                Arc::new(CapacitatedMultiTrip::<T> {
+                    route_intervals,
+                    violation_code,
+                    is_soft_fn,
+                    phantom: Default::default(),
+                }),
             )
         } else {
             create_multi_trip_feature(
@@ -75,6 +100,7 @@ impl<T: LoadOps> CapacityFeatureBuilder<T> {
                 Arc::new(CapacitatedMultiTrip::<T> {
                     route_intervals: RouteIntervals::Single,
                     violation_code,
+                    is_soft_fn,
                     phantom: Default::default(),
                 }),
             )
@@ -88,6 +114,11 @@ where
 {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
+            MoveContext::Route { route_ctx, .. } | MoveContext::Activity { route_ctx, .. }
+                if self.is_soft(route_ctx) =>
+            {
+                None
+            }
             MoveContext::Route { route_ctx, job, .. } => self.evaluate_job(route_ctx, job),
             MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
         }
@@ -124,6 +155,7 @@ where
 {
     route_intervals: RouteIntervals,
     violation_code: ViolationCode,
+    is_soft_fn: Option<IsSoftCapacityFn>,
     phantom: PhantomData<T>,
 }
 
@@ -249,6 +281,10 @@ where
         violation.map(|stopped| ConstraintViolation { code: self.violation_code, stopped })
     }
 
+    fn is_soft(&self, route_ctx: &RouteContext) -> bool {
+        self.is_soft_fn.as_ref().is_some_and(|is_soft_fn| is_soft_fn(&route_ctx.route().actor.vehicle))
+    }
+
     fn has_markers(&self, route_ctx: &RouteContext) -> bool {
         self.route_intervals.get_marker_intervals(route_ctx).map_or(false, |intervals| intervals.len() > 1)
     }
@@ -12,6 +12,7 @@ pub struct FastServiceFeatureBuilder {
     violation_code: Option<ViolationCode>,
     demand_type_fn: Option<DemandTypeFn>,
     is_filtered_job_fn: Option<IsFilteredJobFn>,
+    job_weight_fn: Option<JobWeightFn>,
     transport: Option<Arc<dyn TransportCost + Send + Sync>>,
     activity: Option<Arc<dyn ActivityCost + Send + Sync>>,
 }
@@ -24,6 +25,7 @@ impl FastServiceFeatureBuilder {
             violation_code: None,
             demand_type_fn: None,
             is_filtered_job_fn: None,
+            job_weight_fn: None,
             transport: None,
             activity: None,
         }
@@ -53,6 +55,16 @@ impl FastServiceFeatureBuilder {
         self
     }
 
+    /// Sets a function to get job's weight which scales its service time, so the objective
+    /// becomes a weighted completion time. Default weight is 1.
+    pub fn set_job_weight_fn<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Job) -> Cost + Send + Sync + 'static,
+    {
+        self.job_weight_fn = Some(Arc::new(func));
+        self
+    }
+
     /// Sets transport costs to estimate distance.
     pub fn set_transport(mut self, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
         self.transport = Some(transport);
@@ -74,11 +86,18 @@ impl FastServiceFeatureBuilder {
             self.demand_type_fn.take().ok_or_else(|| GenericError::from("demand_type_fn must be set"))?;
 
         let is_filtered_job_fn = self.is_filtered_job_fn.take().unwrap_or_else(|| Arc::new(|_| false));
+        let job_weight_fn = self.job_weight_fn.take().unwrap_or_else(|| Arc::new(|_| 1.));
 
         FeatureBuilder::default()
             .with_name(self.name.as_str())
             .with_state(FastServiceState::default())
-            .with_objective(FastServiceObjective::new(demand_type_fn, is_filtered_job_fn, transport, activity))
+            .with_objective(FastServiceObjective::new(
+                demand_type_fn,
+                is_filtered_job_fn,
+                job_weight_fn,
+                transport,
+                activity,
+            ))
             .build()
     }
 }
@@ -104,12 +123,15 @@ type MultiJobRanges = HashMap<Job, (usize, usize)>;
 type DemandTypeFn = Arc<dyn Fn(&Single) -> Option<DemandType> + Send + Sync>;
 /// Returns true if job should not be considered for estimation.
 type IsFilteredJobFn = Arc<dyn Fn(&Job) -> bool + Send + Sync>;
+/// Returns a weight of the job's service time.
+type JobWeightFn = Arc<dyn Fn(&Job) -> Cost + Send + Sync>;
 
 custom_tour_state!(MultiJobRanges typeof MultiJobRanges);
 
 struct FastServiceObjective {
     demand_type_fn: DemandTypeFn,
     is_filtered_job_fn: IsFilteredJobFn,
+    job_weight_fn: JobWeightFn,
     transport: Arc<dyn TransportCost + Send + Sync>,
     activity: Arc<dyn ActivityCost + Send + Sync>,
 }
@@ -121,9 +143,13 @@ impl FeatureObjective for FastServiceObjective {
             .routes
             .iter()
             .flat_map(|route_ctx| {
-                route_ctx.route().tour.jobs().filter(|job| !(self.is_filtered_job_fn)(job)).map(|job| match job {
-                    Job::Single(_) => self.estimate_single_job(route_ctx, job),
-                    Job::Multi(_) => self.estimate_multi_job(route_ctx, job),
+                route_ctx.route().tour.jobs().filter(|job| !(self.is_filtered_job_fn)(job)).map(|job| {
+                    let cost = match job {
+                        Job::Single(_) => self.estimate_single_job(route_ctx, job),
+                        Job::Multi(_) => self.estimate_multi_job(route_ctx, job),
+                    };
+
+                    (self.job_weight_fn)(job) * cost
                 })
             })
             .sum::<Cost>()
@@ -145,7 +171,7 @@ impl FeatureObjective for FastServiceObjective {
             };
 
         // NOTE: for simplicity, we ignore impact on already inserted jobs on local objective level
-        match self.get_time_interval_type(&job, single.as_ref()) {
+        let cost = match self.get_time_interval_type(&job, single.as_ref()) {
             TimeIntervalType::FromStart => {
                 self.get_departure(route_ctx, activity_ctx) - self.get_start_time(route_ctx, activity_idx)
             }
@@ -159,7 +185,9 @@ impl FeatureObjective for FastServiceObjective {
             TimeIntervalType::FromStartToEnd => {
                 self.get_end_time(route_ctx, activity_idx) - self.get_start_time(route_ctx, activity_idx)
             }
-        }
+        };
+
+        (self.job_weight_fn)(&job) * cost
     }
 }
 
@@ -167,10 +195,11 @@ impl FastServiceObjective {
     fn new(
         demand_type_fn: DemandTypeFn,
         is_filtered_job_fn: IsFilteredJobFn,
+        job_weight_fn: JobWeightFn,
         transport: Arc<dyn TransportCost + Send + Sync>,
         activity: Arc<dyn ActivityCost + Send + Sync>,
     ) -> Self {
-        Self { demand_type_fn, is_filtered_job_fn, transport, activity }
+        Self { demand_type_fn, is_filtered_job_fn, job_weight_fn, transport, activity }
     }
 
     fn get_start_time(&self, route_ctx: &RouteContext, activity_idx: usize) -> Timestamp {
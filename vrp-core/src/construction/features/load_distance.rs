@@ -0,0 +1,162 @@
+//! Provides the way to minimize load-distance (e.g. ton-km): the sum over all legs of carried load multiplied
+//! by leg distance. Such objective is known as cumulative VRP objective in the literature.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/load_distance_test.rs"]
+mod load_distance_test;
+
+use super::*;
+use crate::construction::enablers::calculate_travel;
+use crate::construction::features::capacity::CurrentCapacityActivityState;
+use std::marker::PhantomData;
+
+custom_activity_state!(CumulativeDistance typeof Distance);
+
+type LoadFn<T> = Arc<dyn Fn(&T) -> Cost + Send + Sync>;
+
+/// Creates a feature to minimize load-distance: a sum of load carried on each leg multiplied by the leg's distance.
+/// The `load_fn` converts a load into a scalar value, e.g. as a weighted sum of capacity dimensions. It is expected
+/// to be linear as insertion cost estimation relies on that property.
+/// Requires activity load states which are maintained by the feature created with [CapacityFeatureBuilder].
+pub fn create_minimize_load_distance_feature<T: LoadOps>(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    load_fn: impl Fn(&T) -> Cost + Send + Sync + 'static,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(LoadDistanceObjective::<T> {
+            transport: transport.clone(),
+            load_fn: Arc::new(load_fn),
+            phantom: Default::default(),
+        })
+        .with_state(LoadDistanceState { transport })
+        .build()
+}
+
+struct LoadDistanceObjective<T: LoadOps> {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    load_fn: LoadFn<T>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> LoadDistanceObjective<T> {
+    fn get_route_cost(&self, route_ctx: &RouteContext) -> Cost {
+        let state = route_ctx.state();
+        let total = route_ctx.route().tour.total();
+
+        (1..total)
+            .filter_map(|idx| {
+                let distance = state.get_cumulative_distance_at(idx)? - state.get_cumulative_distance_at(idx - 1)?;
+                let load: &T = state.get_current_capacity_at(idx - 1)?;
+
+                Some((self.load_fn)(load) * distance)
+            })
+            .sum()
+    }
+
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let state = route_ctx.state();
+        let prev_idx = activity_ctx.index;
+
+        let demand: Option<&Demand<T>> =
+            activity_ctx.target.job.as_ref().and_then(|single| single.dimens.get_job_demand());
+        let prev_load: T = state.get_current_capacity_at(prev_idx).copied().unwrap_or_default();
+
+        let ((prev_to_tar, tar_to_next), _) = calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+        let prev_to_next = activity_ctx.next.map_or(Distance::default(), |next| {
+            let route = route_ctx.route();
+            let departure = activity_ctx.prev.schedule.departure;
+            self.transport.distance(
+                route,
+                activity_ctx.prev.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            )
+        });
+
+        // NOTE the load carried to prev activity travels now via target
+        let detour_cost = (self.load_fn)(&prev_load) * (prev_to_tar + tar_to_next - prev_to_next);
+
+        let Some(demand) = demand else { return detour_cost };
+
+        let cumulative = |idx: usize| state.get_cumulative_distance_at(idx).copied().unwrap_or_default();
+        let (start_idx, end_idx) = get_route_interval(route_ctx, prev_idx);
+
+        // static delivery is carried from the interval start till target
+        let delivery = demand.delivery.0;
+        let delivery_distance = cumulative(prev_idx) - cumulative(start_idx) + prev_to_tar;
+
+        // static pickup and dynamic change are carried from target till the interval end
+        let pickup = delivery + demand.change();
+        let pickup_distance = if activity_ctx.next.is_some() {
+            tar_to_next + (cumulative(end_idx) - cumulative(prev_idx + 1)).max(0.)
+        } else {
+            Distance::default()
+        };
+
+        detour_cost + (self.load_fn)(&delivery) * delivery_distance + (self.load_fn)(&pickup) * pickup_distance
+    }
+}
+
+impl<T: LoadOps> FeatureObjective for LoadDistanceObjective<T> {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_cost(route_ctx)).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+struct LoadDistanceState {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl FeatureState for LoadDistanceState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+
+        let init = route.tour.start().map(|start| (start.place.location, start.schedule.departure));
+        let Some((location, departure)) = init else { return };
+
+        let (_, _, cumulative_distances) = route.tour.all_activities().skip(1).fold(
+            (location, departure, vec![Distance::default()]),
+            |(location, departure, mut distances), activity| {
+                let distance =
+                    self.transport.distance(route, location, activity.place.location, TravelTime::Departure(departure));
+                distances.push(distances.last().copied().unwrap_or_default() + distance);
+
+                (activity.place.location, activity.schedule.departure, distances)
+            },
+        );
+
+        route_ctx.state_mut().set_cumulative_distance_states(cumulative_distances);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
+}
+
+fn get_route_interval(route_ctx: &RouteContext, activity_idx: usize) -> (usize, usize) {
+    let last_idx = route_ctx.route().tour.total().max(1) - 1;
+
+    route_ctx
+        .state()
+        .get_reload_intervals()
+        .and_then(|intervals| intervals.iter().find(|(start, end)| *start <= activity_idx && *end >= activity_idx))
+        .copied()
+        .unwrap_or((0, last_idx))
+}
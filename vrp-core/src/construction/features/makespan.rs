@@ -0,0 +1,162 @@
+//! Provides the way to minimize makespan: the latest tour completion time across the whole fleet.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/makespan_test.rs"]
+mod makespan_test;
+
+use super::*;
+use crate::construction::enablers::WaitingTimeActivityState;
+use crate::models::common::Timestamp;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Route;
+
+custom_tour_state!(FleetMakespan typeof Timestamp);
+
+/// Creates a feature to minimize makespan: the maximum end time of all tours.
+/// Insertion cost is estimated as an increase of the makespan caused by delaying the tour end.
+pub fn create_minimize_makespan_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MakespanObjective { transport: transport.clone(), activity: activity.clone() })
+        .with_state(MakespanState { transport, activity })
+        .build()
+}
+
+struct MakespanObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl MakespanObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+
+        // NOTE empty tours have no reliable fleet state as they come from registry, so they are not penalized
+        if !route.tour.has_jobs() {
+            return Cost::default();
+        }
+
+        let route_end = get_tour_end(route);
+        let makespan = route_ctx.state().get_fleet_makespan().copied().unwrap_or(route_end);
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        let end_shift = if let Some(next) = activity_ctx.next {
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+            let shift = (self.activity.estimate_departure(route, next, arrival) - next.schedule.departure).max(0.);
+
+            // NOTE waiting time of the following activities absorbs the shift
+            let waiting = route_ctx.state().get_waiting_time_at(activity_ctx.index + 2).copied().unwrap_or_default();
+
+            (shift - waiting).max(0.)
+        } else {
+            (departure - route_end).max(0.)
+        };
+
+        (route_end + end_shift - makespan).max(0.)
+    }
+}
+
+impl FeatureObjective for MakespanObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        get_makespan(&solution.solution)
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+struct MakespanState {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl MakespanState {
+    /// Returns tour end time. Stale routes are rescheduled without modification as their schedule
+    /// might not be updated yet by the feature responsible for that.
+    fn get_route_end(&self, route_ctx: &RouteContext) -> Timestamp {
+        let route = route_ctx.route();
+
+        if !route_ctx.is_stale() {
+            return get_tour_end(route);
+        }
+
+        let init = route.tour.start().map(|start| (start.place.location, start.schedule.departure));
+        let Some(init) = init else { return Timestamp::default() };
+
+        let (_, departure) = route.tour.all_activities().skip(1).fold(init, |(location, departure), activity| {
+            let arrival = departure
+                + self.transport.duration(route, location, activity.place.location, TravelTime::Departure(departure));
+
+            (activity.place.location, self.activity.estimate_departure(route, activity, arrival))
+        });
+
+        departure
+    }
+
+    fn update_fleet_makespan(&self, solution_ctx: &mut SolutionContext) {
+        let makespan = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| self.get_route_end(route_ctx))
+            .max_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            // NOTE fleet makespan is a derived value: setting it should not force route state recalculation
+            let is_stale = route_ctx.is_stale();
+            route_ctx.state_mut().set_fleet_makespan(makespan);
+            route_ctx.mark_stale(is_stale);
+        });
+    }
+}
+
+impl FeatureState for MakespanState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_fleet_makespan(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_fleet_makespan(solution_ctx);
+    }
+}
+
+fn get_tour_end(route: &Route) -> Timestamp {
+    route.tour.end().map(|end| end.schedule.departure).unwrap_or_default()
+}
+
+fn get_makespan(solution_ctx: &SolutionContext) -> Cost {
+    solution_ctx
+        .routes
+        .iter()
+        .map(|route_ctx| get_tour_end(route_ctx.route()))
+        .max_by(|a, b| a.total_cmp(b))
+        .unwrap_or_default()
+}
@@ -27,9 +27,15 @@ pub use self::fleet_usage::*;
 mod groups;
 pub use self::groups::{create_group_feature, JobGroupDimension};
 
+mod load_distance;
+pub use self::load_distance::create_minimize_load_distance_feature;
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
+mod makespan;
+pub use self::makespan::create_minimize_makespan_feature;
+
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
@@ -45,6 +51,9 @@ pub use self::reloads::{ReloadFeatureFactory, ReloadIntervalsTourState, SharedRe
 mod skills;
 pub use self::skills::{create_skills_feature, JobSkills, JobSkillsDimension, VehicleSkillsDimension};
 
+mod soft_limits;
+pub use self::soft_limits::{SoftLimitFn, SoftLimitsFeatureBuilder};
+
 mod total_value;
 pub use self::total_value::*;
 
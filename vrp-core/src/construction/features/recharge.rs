@@ -231,7 +231,7 @@ impl MultiTrip for RechargeableMultiTrip {
     fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
         let routes = &mut solution_ctx.routes;
 
-        let jobs: HashSet<_> = if route_indices.is_empty() {
+        let jobs: Vec<_> = if route_indices.is_empty() {
             solution_ctx
                 .ignored
                 .iter()
@@ -256,9 +256,12 @@ impl MultiTrip for RechargeableMultiTrip {
         if jobs.is_empty() {
             false
         } else {
-            solution_ctx.ignored.retain(|job| !jobs.contains(job));
-            solution_ctx.locked.extend(jobs.iter().cloned());
-            solution_ctx.required.extend(jobs);
+            let mut job_set = jobs.iter().cloned().collect::<HashSet<_>>();
+
+            solution_ctx.ignored.retain(|job| !job_set.contains(job));
+            solution_ctx.locked.extend(job_set.iter().cloned());
+            // NOTE keep original order of jobs to make it deterministic
+            solution_ctx.required.extend(jobs.into_iter().filter(|job| job_set.remove(job)));
 
             true
         }
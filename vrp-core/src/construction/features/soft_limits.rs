@@ -0,0 +1,239 @@
+//! A feature to penalize violations of soft tour limits such as distance, duration, tour size or capacity.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/soft_limits_test.rs"]
+mod soft_limits_test;
+
+use super::*;
+use crate::construction::enablers::{calculate_travel_delta, TotalDistanceTourState, TotalDurationTourState};
+use crate::construction::features::capacity::{MaxFutureCapacityActivityState, MaxPastCapacityActivityState};
+use crate::models::problem::Actor;
+
+/// A function to resolve a soft limit and a penalty per unit of its violation for a given actor.
+pub type SoftLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<(T, Cost)> + Send + Sync>;
+
+type CapacityPenaltyFn = Arc<dyn Fn(&RouteContext, Option<&ActivityContext>) -> Cost + Send + Sync>;
+
+/// Provides a way to build a feature which penalizes violations of soft tour limits. Such limits can be exceeded,
+/// but a penalty proportional to the violation is added to the objective. The feature has no constraint, so the
+/// corresponding hard constraints should not be applied for the same limits.
+pub struct SoftLimitsFeatureBuilder {
+    name: String,
+    transport: Option<Arc<dyn TransportCost + Send + Sync>>,
+    distance_fn: Option<SoftLimitFn<Distance>>,
+    duration_fn: Option<SoftLimitFn<Duration>>,
+    tour_size_fn: Option<SoftLimitFn<usize>>,
+    capacity_fn: Option<CapacityPenaltyFn>,
+}
+
+impl SoftLimitsFeatureBuilder {
+    /// Creates a new instance of `SoftLimitsFeatureBuilder`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            transport: None,
+            distance_fn: None,
+            duration_fn: None,
+            tour_size_fn: None,
+            capacity_fn: None,
+        }
+    }
+
+    /// Sets transport costs used to estimate distance and duration change. Required if travel limits are set.
+    pub fn set_transport(mut self, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets a function to get soft tour distance limit with its penalty per distance unit.
+    pub fn set_distance_limit<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<(Distance, Cost)> + Send + Sync + 'static,
+    {
+        self.distance_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function to get soft tour duration limit with its penalty per time unit.
+    pub fn set_duration_limit<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<(Duration, Cost)> + Send + Sync + 'static,
+    {
+        self.duration_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function to get soft limit of job activities in a tour with its penalty per extra activity.
+    pub fn set_tour_size_limit<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<(usize, Cost)> + Send + Sync + 'static,
+    {
+        self.tour_size_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets functions to penalize vehicle capacity overload. The `overload_fn` returns amount of violation units
+    /// for given max load and vehicle capacity, the `penalty_fn` returns a penalty per unit. Relies on load states
+    /// maintained by the feature created with [CapacityFeatureBuilder] which should be configured as soft.
+    pub fn set_capacity_limit<T, F1, F2>(mut self, overload_fn: F1, penalty_fn: F2) -> Self
+    where
+        T: LoadOps,
+        F1: Fn(&T, &T) -> f64 + Send + Sync + 'static,
+        F2: Fn(&Actor) -> Option<Cost> + Send + Sync + 'static,
+    {
+        self.capacity_fn = Some(Arc::new(move |route_ctx: &RouteContext, activity_ctx: Option<&ActivityContext>| {
+            let actor = route_ctx.route().actor.as_ref();
+            let Some(penalty) = penalty_fn(actor) else { return Cost::default() };
+            let Some(capacity) = actor.vehicle.dimens.get_vehicle_capacity::<T>() else { return Cost::default() };
+
+            let state = route_ctx.state();
+            let last_idx = route_ctx.route().tour.total().max(1) - 1;
+            let default_intervals = vec![(0, last_idx)];
+            let intervals = state.get_reload_intervals().unwrap_or(&default_intervals);
+
+            let overload: f64 = intervals
+                .iter()
+                .map(|&(start_idx, end_idx)| {
+                    let max_load = state.get_max_future_capacity_at::<T>(start_idx).copied().unwrap_or_default();
+
+                    // NOTE estimate how target's demand affects max load similar to hard capacity constraint
+                    let max_load = activity_ctx
+                        .filter(|activity_ctx| start_idx <= activity_ctx.index && activity_ctx.index <= end_idx)
+                        .and_then(|activity_ctx| {
+                            let demand = activity_ctx.target.job.as_ref()?.dimens.get_job_demand::<T>()?;
+                            Some((activity_ctx.index, demand))
+                        })
+                        .map_or(max_load, |(pivot_idx, demand)| {
+                            let past = state.get_max_past_capacity_at::<T>(pivot_idx).copied().unwrap_or_default();
+                            let future = state.get_max_future_capacity_at::<T>(pivot_idx).copied().unwrap_or_default();
+
+                            max_load
+                                .max_load(past + demand.delivery.0)
+                                .max_load(future + demand.pickup.0 + demand.pickup.1 - demand.delivery.1)
+                        });
+
+                    overload_fn(&max_load, capacity)
+                })
+                .sum();
+
+            overload * penalty
+        }));
+        self
+    }
+
+    /// Builds a feature.
+    pub fn build(self) -> GenericResult<Feature> {
+        let has_travel_limits = self.distance_fn.is_some() || self.duration_fn.is_some();
+        if has_travel_limits && self.transport.is_none() {
+            return Err("transport must be set when travel limits are used".into());
+        }
+
+        FeatureBuilder::default()
+            .with_name(self.name.as_str())
+            .with_objective(SoftLimitsObjective {
+                transport: self.transport,
+                distance_fn: self.distance_fn,
+                duration_fn: self.duration_fn,
+                tour_size_fn: self.tour_size_fn,
+                capacity_fn: self.capacity_fn,
+            })
+            .build()
+    }
+}
+
+struct SoftLimitsObjective {
+    transport: Option<Arc<dyn TransportCost + Send + Sync>>,
+    distance_fn: Option<SoftLimitFn<Distance>>,
+    duration_fn: Option<SoftLimitFn<Duration>>,
+    tour_size_fn: Option<SoftLimitFn<usize>>,
+    capacity_fn: Option<CapacityPenaltyFn>,
+}
+
+impl SoftLimitsObjective {
+    fn get_route_penalty(&self, route_ctx: &RouteContext) -> Cost {
+        let actor = route_ctx.route().actor.as_ref();
+        let state = route_ctx.state();
+
+        let distance = get_penalty(actor, &self.distance_fn, state.get_total_distance().copied().unwrap_or_default());
+        let duration = get_penalty(actor, &self.duration_fn, state.get_total_duration().copied().unwrap_or_default());
+        let tour_size = self.tour_size_fn.as_ref().and_then(|tour_size_fn| tour_size_fn(actor)).map_or(
+            Cost::default(),
+            |(limit, penalty)| {
+                let activities = route_ctx.route().tour.job_activity_count();
+                activities.saturating_sub(limit) as Cost * penalty
+            },
+        );
+        let capacity = self.capacity_fn.as_ref().map_or(Cost::default(), |capacity_fn| capacity_fn(route_ctx, None));
+
+        distance + duration + tour_size + capacity
+    }
+
+    fn estimate_route(&self, route_ctx: &RouteContext, job: &Job) -> Cost {
+        let Some((limit, penalty)) =
+            self.tour_size_fn.as_ref().and_then(|tour_size_fn| tour_size_fn(route_ctx.route().actor.as_ref()))
+        else {
+            return Cost::default();
+        };
+
+        let job_activities = match job {
+            Job::Single(_) => 1,
+            Job::Multi(multi) => multi.jobs.len(),
+        };
+        let activities = route_ctx.route().tour.job_activity_count();
+
+        let extra = (activities + job_activities).saturating_sub(limit) - activities.saturating_sub(limit);
+
+        extra as Cost * penalty
+    }
+
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let actor = route_ctx.route().actor.as_ref();
+        let state = route_ctx.state();
+
+        let has_travel_limits = [&self.distance_fn, &self.duration_fn]
+            .into_iter()
+            .any(|limit_fn| limit_fn.as_ref().is_some_and(|limit_fn| limit_fn(actor).is_some()));
+
+        let travel = match (has_travel_limits, self.transport.as_ref()) {
+            (true, Some(transport)) => {
+                let (change_distance, change_duration) =
+                    calculate_travel_delta(route_ctx, activity_ctx, transport.as_ref());
+
+                let distance = state.get_total_distance().copied().unwrap_or_default();
+                let duration = state.get_total_duration().copied().unwrap_or_default();
+
+                get_penalty(actor, &self.distance_fn, distance + change_distance)
+                    - get_penalty(actor, &self.distance_fn, distance)
+                    + get_penalty(actor, &self.duration_fn, duration + change_duration)
+                    - get_penalty(actor, &self.duration_fn, duration)
+            }
+            _ => Cost::default(),
+        };
+
+        let capacity = self.capacity_fn.as_ref().map_or(Cost::default(), |capacity_fn| {
+            capacity_fn(route_ctx, Some(activity_ctx)) - capacity_fn(route_ctx, None)
+        });
+
+        travel + capacity
+    }
+}
+
+impl FeatureObjective for SoftLimitsObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_penalty(route_ctx)).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.estimate_route(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+fn get_penalty(actor: &Actor, limit_fn: &Option<SoftLimitFn<f64>>, value: f64) -> Cost {
+    limit_fn
+        .as_ref()
+        .and_then(|limit_fn| limit_fn(actor))
+        .map_or(Cost::default(), |(limit, penalty)| (value - limit).max(0.) * penalty)
+}
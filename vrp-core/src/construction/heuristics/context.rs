@@ -58,27 +58,10 @@ impl InsertionContext {
     ///
     /// Returns None if cost cannot be calculate as the context is in non-consistent state.
     pub fn get_total_cost(&self) -> Option<Cost> {
-        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
-            costs.fixed
-                + costs.per_distance * distance
-                // NOTE this is incorrect when timing costs are different: fitness value will be
-                // different from actual cost. However we accept this so far as it is simpler for
-                // implementation and pragmatic format does not expose this feature
-                // .
-                // TODO calculate actual cost
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
-        };
-
-        self.solution.routes.iter().try_fold(Cost::default(), |acc, route_ctx| {
-            let actor = &route_ctx.route.actor;
-            let distance = route_ctx.state.get_total_distance();
-            let duration = route_ctx.state.get_total_duration();
-
-            distance.zip(duration).map(|(&distance, &duration)| {
-                acc + get_cost(&actor.vehicle.costs, distance, duration)
-                    + get_cost(&actor.driver.costs, distance, duration)
-            })
-        })
+        self.solution
+            .routes
+            .iter()
+            .try_fold(Cost::default(), |acc, route_ctx| route_ctx.get_route_cost().map(|cost| acc + cost))
     }
 
     /// Restores valid context state.
@@ -211,16 +194,20 @@ impl From<(InsertionContext, Option<TelemetryMetrics>)> for Solution {
         let cost = insertion_ctx.get_total_cost().unwrap_or_default();
         let solution_ctx = insertion_ctx.solution;
 
+        let mut unassigned = solution_ctx
+            .unassigned
+            .iter()
+            .map(|(job, code)| (job.clone(), code.clone()))
+            .chain(solution_ctx.required.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)))
+            .collect::<Vec<_>>();
+        // NOTE sort to have consistent order
+        unassigned.sort_by_key(|(job, _)| insertion_ctx.problem.jobs.position(job));
+
         Solution {
             cost,
             registry: solution_ctx.registry.resources().deep_copy(),
             routes: solution_ctx.routes.iter().map(|rc| rc.route.deep_copy()).collect(),
-            unassigned: solution_ctx
-                .unassigned
-                .iter()
-                .map(|(job, code)| (job.clone(), code.clone()))
-                .chain(solution_ctx.required.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)))
-                .collect(),
+            unassigned,
             telemetry,
         }
     }
@@ -309,6 +296,30 @@ impl RouteContext {
         &mut self.state
     }
 
+    /// Gets total cost of the route.
+    ///
+    /// Returns None if cost cannot be calculate as the route is in non-consistent state.
+    pub fn get_route_cost(&self) -> Option<Cost> {
+        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
+            costs.fixed
+                + costs.per_distance * distance
+                // NOTE this is incorrect when timing costs are different: fitness value will be
+                // different from actual cost. However we accept this so far as it is simpler for
+                // implementation and pragmatic format does not expose this feature
+                // .
+                // TODO calculate actual cost
+                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+        };
+
+        let actor = &self.route.actor;
+        let distance = self.state.get_total_distance();
+        let duration = self.state.get_total_duration();
+
+        distance.zip(duration).map(|(&distance, &duration)| {
+            get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        })
+    }
+
     /// Returns true if context is stale. Context is marked stale when it is accessed by `mut`
     /// methods. A general motivation of the flag is to avoid recalculating non-changed states.
     pub fn is_stale(&self) -> bool {
@@ -359,7 +359,9 @@ impl TryFrom<InsertionResult> for InsertionSuccess {
 }
 
 pub(crate) fn prepare_insertion_ctx(insertion_ctx: &mut InsertionContext) {
-    insertion_ctx.solution.required.extend(insertion_ctx.solution.unassigned.keys().cloned());
+    let mut unassigned = insertion_ctx.solution.unassigned.keys().cloned().collect::<Vec<_>>();
+    unassigned.sort_by_cached_key(|job| insertion_ctx.problem.jobs.position(job));
+    insertion_ctx.solution.required.extend(unassigned);
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
 }
 
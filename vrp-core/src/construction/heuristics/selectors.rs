@@ -183,6 +183,7 @@ impl InsertionEvaluator for PositionInsertionEvaluator {
                 |job| self.evaluate_job(insertion_ctx, job, routes, leg_selection, result_selector),
                 InsertionResult::make_failure,
                 |a, b| result_selector.select_insertion(insertion_ctx, a, b),
+                insertion_ctx.environment.random.as_ref(),
             )
         } else {
             map_reduce(
@@ -190,6 +191,7 @@ impl InsertionEvaluator for PositionInsertionEvaluator {
                 |route| self.evaluate_route(insertion_ctx, route, jobs, leg_selection, result_selector),
                 InsertionResult::make_failure,
                 |a, b| result_selector.select_insertion(insertion_ctx, a, b),
+                insertion_ctx.environment.random.as_ref(),
             )
         }
     }
@@ -69,7 +69,7 @@ fn create_example_goal_ctx(
         CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build()?,
     ];
 
-    GoalContextBuilder::with_features(&features)?.build()
+    GoalContextBuilder::with_features(&features)?.set_cost_feature("min_distance").build()
 }
 
 pub fn build_example_problem() -> GenericResult<Arc<Problem>> {
@@ -32,6 +32,7 @@ use std::sync::Arc;
 pub struct GoalContext {
     goal: Goal,
     alternative_goals: Vec<(Goal, f64)>,
+    cost_objective: Option<Arc<dyn FeatureObjective>>,
     constraints: Vec<Arc<dyn FeatureConstraint>>,
     states: Vec<Arc<dyn FeatureState>>,
 }
@@ -45,6 +46,25 @@ impl GoalContext {
         GoalContext { constraints: constraints.collect(), ..self.clone() }
     }
 
+    /// Creates a new instance of `GoalContext` with the cost objective augmented by the given one: their
+    /// fitness and estimate are summed, while comparison rules of goal layers are kept intact. Both main
+    /// and alternative goals are augmented. Can be used to modify the goal temporarily, e.g. with penalties.
+    /// Returns `None` if the cost objective is not specified, see [GoalContextBuilder::set_cost_feature].
+    pub fn with_augmented_objective(&self, objective: Arc<dyn FeatureObjective>) -> Option<Self> {
+        let cost_objective = self.cost_objective.as_ref()?;
+        let augmented: Arc<dyn FeatureObjective> =
+            Arc::new(AugmentedObjective { inner: cost_objective.clone(), augment: objective });
+
+        let goal = self.goal.with_replaced_objective(cost_objective, &augmented);
+        let alternative_goals = self
+            .alternative_goals
+            .iter()
+            .map(|(goal, weight)| (goal.with_replaced_objective(cost_objective, &augmented), *weight))
+            .collect();
+
+        Some(GoalContext { goal, alternative_goals, cost_objective: Some(augmented), ..self.clone() })
+    }
+
     /// Returns an iterator over internal feature constraints.
     pub fn constraints(&self) -> impl Iterator<Item = Arc<dyn FeatureConstraint>> + '_ {
         self.constraints.iter().cloned()
@@ -66,6 +86,7 @@ impl Debug for GoalContext {
 pub struct GoalContextBuilder {
     main_goal: Option<Goal>,
     alternative_goals: Vec<(Goal, f64)>,
+    cost_feature: Option<String>,
     features: Vec<Feature>,
 }
 
@@ -86,7 +107,7 @@ impl GoalContextBuilder {
 
         let goal = Goal::simple(&features)?;
 
-        Ok(Self { main_goal: Some(goal), alternative_goals: Vec::default(), features })
+        Ok(Self { main_goal: Some(goal), alternative_goals: Vec::default(), cost_feature: None, features })
     }
 
     /// Sets a main goal of optimization.
@@ -101,14 +122,31 @@ impl GoalContextBuilder {
         self
     }
 
+    /// Sets a feature which objective minimizes the cost of the solution. It is used to augment
+    /// the goal temporarily, e.g. by search operators which penalize some solution properties.
+    pub fn set_cost_feature(mut self, name: &str) -> Self {
+        self.cost_feature = Some(name.to_string());
+        self
+    }
+
     /// Builds goal context.
     pub fn build(self) -> GenericResult<GoalContext> {
         let goal = self.main_goal.ok_or_else(|| GenericError::from("missing goal of optimization"))?;
         let alternative_goals = self.alternative_goals;
+        let cost_objective = self
+            .cost_feature
+            .map(|name| {
+                self.features
+                    .iter()
+                    .find(|feature| feature.name == name)
+                    .and_then(|feature| feature.objective.clone())
+                    .ok_or_else(|| GenericError::from(format!("cannot find cost objective with name: '{name}'")))
+            })
+            .transpose()?;
         let states = self.features.iter().filter_map(|feature| feature.state.clone()).collect();
         let constraints = self.features.iter().filter_map(|feature| feature.constraint.clone()).collect();
 
-        Ok(GoalContext { goal, alternative_goals, constraints, states })
+        Ok(GoalContext { goal, alternative_goals, cost_objective, constraints, states })
     }
 }
 
@@ -187,6 +225,52 @@ impl Goal {
     pub fn fitness<'a>(&'a self, solution: &'a InsertionContext) -> impl Iterator<Item = f64> + 'a {
         self.layers.iter().flat_map(|(_, _, objectives)| objectives.iter()).map(|objective| objective.fitness(solution))
     }
+
+    /// Creates a new goal where the target objective is replaced with the given one in all layers.
+    fn with_replaced_objective(
+        &self,
+        target: &Arc<dyn FeatureObjective>,
+        replacement: &Arc<dyn FeatureObjective>,
+    ) -> Self {
+        let layers = self
+            .layers
+            .iter()
+            .map(|(total_order_fn, estimate_fn, objectives)| {
+                let objectives = objectives
+                    .iter()
+                    .map(
+                        |objective| {
+                            if Arc::ptr_eq(objective, target) {
+                                replacement.clone()
+                            } else {
+                                objective.clone()
+                            }
+                        },
+                    )
+                    .collect();
+
+                (total_order_fn.clone(), estimate_fn.clone(), objectives)
+            })
+            .collect();
+
+        Self { layers }
+    }
+}
+
+/// An objective which sums fitness and estimate of two objectives.
+struct AugmentedObjective {
+    inner: Arc<dyn FeatureObjective>,
+    augment: Arc<dyn FeatureObjective>,
+}
+
+impl FeatureObjective for AugmentedObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        self.inner.fitness(solution) + self.augment.fitness(solution)
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        self.inner.estimate(move_ctx) + self.augment.estimate(move_ctx)
+    }
 }
 
 /// Builds a [Goal] - a goal of optimization - composing multiple layers from objective functions
@@ -232,12 +232,14 @@ const MAX_NEIGHBOURS: usize = 512;
 pub struct Jobs {
     jobs: Vec<Job>,
     index: HashMap<usize, JobIndex>,
+    positions: HashMap<Job, usize>,
 }
 
 impl Jobs {
     /// Creates a new [`Jobs`].
     pub fn new(fleet: &Fleet, jobs: Vec<Job>, transport: &(dyn TransportCost + Send + Sync)) -> Jobs {
-        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport) }
+        let positions = jobs.iter().cloned().zip(0..).collect();
+        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport), positions }
     }
 
     /// Returns all jobs in original order.
@@ -266,6 +268,12 @@ impl Jobs {
     pub fn size(&self) -> usize {
         self.jobs.len()
     }
+
+    /// Returns job position in original order. Can be used to order jobs deterministically as job
+    /// hash depends on its memory address.
+    pub fn position(&self, job: &Job) -> Option<usize> {
+        self.positions.get(job).copied()
+    }
 }
 
 impl PartialEq<Job> for Job {
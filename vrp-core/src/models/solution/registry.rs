@@ -3,13 +3,16 @@
 mod actor_test;
 
 use crate::models::problem::{Actor, Fleet};
+use indexmap::IndexSet;
 use rosomaxa::prelude::Random;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
 pub struct Registry {
-    available: HashMap<usize, HashSet<Arc<Actor>>>,
+    // NOTE groups and actors are kept in insertion order as actor hash depends on its memory address:
+    //      this keeps actor selection deterministic
+    available: Vec<IndexSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
     random: Arc<dyn Random + Send + Sync>,
@@ -18,25 +21,38 @@ pub struct Registry {
 impl Registry {
     /// Creates a new instance of `Registry`
     pub fn new(fleet: &Fleet, random: Arc<dyn Random + Send + Sync>) -> Self {
+        let mut group_ids = fleet.groups.keys().copied().collect::<Vec<_>>();
+        group_ids.sort();
+
+        let group_positions =
+            group_ids.iter().enumerate().map(|(idx, group_id)| (*group_id, idx)).collect::<HashMap<_, _>>();
         let index = fleet
             .groups
             .iter()
-            .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
-            .collect();
+            .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), group_positions[group_id])))
+            .collect::<HashMap<_, _>>();
+
+        let available = fleet.actors.iter().filter(|actor| index.contains_key(*actor)).fold(
+            vec![IndexSet::default(); group_ids.len()],
+            |mut acc, actor| {
+                acc[index[actor]].insert(actor.clone());
+                acc
+            },
+        );
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        Self { available, index, all: fleet.actors.to_vec(), random }
     }
 
     /// Removes an actor from the list of available actors.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().remove(actor)
+        self.available[*self.index.get(actor).expect("unknown actor")].swap_remove(actor)
     }
 
     /// Adds actor to the list of available actors.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().insert(actor.clone())
+        self.available[*self.index.get(actor).expect("unknown actor")].insert(actor.clone())
     }
 
     /// Returns all actors.
@@ -46,15 +62,16 @@ impl Registry {
 
     /// Returns list of all available actors.
     pub fn available(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
-        self.available.iter().flat_map(|(_, set)| set.iter().cloned())
+        self.available.iter().flat_map(|actors| actors.iter().cloned())
     }
 
     /// Returns next available actors from each different type.
     pub fn next(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
-        self.available.iter().flat_map(move |(_, set)| {
+        self.available.iter().flat_map(move |actors| {
             // NOTE pick a random actor from set of available actors.
-            let skip_amount = if set.len() < 2 { 0 } else { self.random.uniform_int(0, set.len() as i32 - 1) as usize };
-            set.iter().skip(skip_amount).take(1).cloned()
+            let skip_amount =
+                if actors.len() < 2 { 0 } else { self.random.uniform_int(0, actors.len() as i32 - 1) as usize };
+            actors.get_index(skip_amount).cloned()
         })
     }
 
@@ -74,10 +91,7 @@ impl Registry {
             available: self
                 .available
                 .iter()
-                .map(|(idx, actors)| {
-                    let actors = actors.iter().filter(|actor| filter(actor.as_ref())).cloned().collect::<HashSet<_>>();
-                    (*idx, actors)
-                })
+                .map(|actors| actors.iter().filter(|actor| filter(actor.as_ref())).cloned().collect())
                 .collect(),
             index: self
                 .index
@@ -7,8 +7,8 @@ use crate::models::problem::{Actor, Job, JobIdDimension};
 use crate::models::solution::{Activity, Place};
 use crate::models::OP_START_MSG;
 use crate::utils::{short_type_name, Either};
+use indexmap::IndexSet;
 use rustc_hash::FxHasher;
-use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::hash::BuildHasherDefault;
 use std::iter::once;
@@ -24,8 +24,9 @@ pub struct Tour {
     /// Stores activities in the order the performed.
     activities: Vec<Activity>,
 
-    /// Stores jobs in the order of their activities added.
-    jobs: HashSet<Job, BuildHasherDefault<FxHasher>>,
+    /// Stores jobs in the order of their activities added: removal moves the last job to the place of
+    /// removed one, so the order depends only on tour modifications, not on memory addresses of jobs.
+    jobs: IndexSet<Job, BuildHasherDefault<FxHasher>>,
 
     /// Keeps track whether tour is set as closed.
     is_closed: bool,
@@ -80,7 +81,7 @@ impl Tour {
     /// Removes job within its activities from the tour.
     pub fn remove(&mut self, job: &Job) -> bool {
         self.activities.retain(|a| !a.has_same_job(job));
-        self.jobs.remove(job)
+        self.jobs.swap_remove(job)
     }
 
     /// Removes activity and its job from the tour.
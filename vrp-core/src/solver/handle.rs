@@ -0,0 +1,128 @@
+//! Provides a way to run the solver in the background and control it while the search is in progress.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/handle_test.rs"]
+mod handle_test;
+
+use super::*;
+use rosomaxa::evolution::{HeuristicSolutionProcessing, SharedSolutionProcessing};
+use rosomaxa::termination::Termination;
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::{Mutex, OnceLock};
+use std::thread::JoinHandle;
+
+/// A non-blocking solver handle: the search is run in a background thread, so the caller can get
+/// the best known solution at any time and stop the search earlier than termination criteria
+/// would do it.
+pub struct SolverHandle {
+    state: Arc<HandleState>,
+    worker: JoinHandle<GenericResult<Solution>>,
+}
+
+struct HandleState {
+    is_stopped: AtomicBool,
+    best_known: Mutex<Option<InsertionContext>>,
+    processing: OnceLock<SharedSolutionProcessing<InsertionContext>>,
+}
+
+impl SolverHandle {
+    /// Starts solving the problem in a background thread and returns immediately.
+    /// As evolution config cannot be shared between threads, it is created from `config_fn` within
+    /// the background thread. An error returned by the function is returned by `join`.
+    pub fn start<F>(problem: Arc<Problem>, config_fn: F) -> Self
+    where
+        F: FnOnce() -> GenericResult<EvolutionConfig<RefinementContext, GoalContext, InsertionContext>>
+            + Send
+            + 'static,
+    {
+        let state = Arc::new(HandleState {
+            is_stopped: AtomicBool::new(false),
+            best_known: Mutex::new(None),
+            processing: OnceLock::new(),
+        });
+
+        let worker = std::thread::spawn({
+            let state = state.clone();
+            move || {
+                let mut config = config_fn()?;
+                let _ = state.processing.set(config.processing.share_solution_processing());
+                config.termination = Box::new(HandleTermination { inner: config.termination, state });
+
+                Solver::new(problem, config).solve()
+            }
+        });
+
+        Self { state, worker }
+    }
+
+    /// Returns the best known solution found so far. The solution is updated once per generation and
+    /// post processed in the same way as the final one, e.g. clustered jobs are unwrapped.
+    /// Returns `None` if no solution is built yet.
+    pub fn best_so_far(&self) -> Option<Solution> {
+        let insertion_ctx =
+            self.state.best_known.lock().unwrap().as_ref().map(|insertion_ctx| insertion_ctx.deep_copy())?;
+
+        let insertion_ctx = match self.state.processing.get() {
+            Some(processing) => processing.post_process(insertion_ctx),
+            None => insertion_ctx,
+        };
+
+        Some((insertion_ctx, None).into())
+    }
+
+    /// Requests the search to be stopped. The search stops after the current generation is completed,
+    /// so use `join` to wait for it and to get the final solution.
+    pub fn stop(&self) {
+        self.state.is_stopped.store(true, AtomicOrdering::Relaxed);
+    }
+
+    /// Returns true if the search is completed, e.g. termination criteria are met or it was stopped.
+    pub fn is_finished(&self) -> bool {
+        self.worker.is_finished()
+    }
+
+    /// Waits for the search to be completed and returns the final solution.
+    pub fn join(self) -> GenericResult<Solution> {
+        self.worker.join().map_err(|_| GenericError::from("solver thread panicked"))?
+    }
+}
+
+/// Wraps configured termination to keep track of the best known solution and to stop the search on request.
+struct HandleTermination {
+    inner: Box<dyn Termination<Context = RefinementContext, Objective = GoalContext>>,
+    state: Arc<HandleState>,
+}
+
+impl Termination for HandleTermination {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        if let Some(best) = heuristic_ctx.ranked().next() {
+            let mut best_known = self.state.best_known.lock().unwrap();
+
+            let is_improved = match best_known.as_ref() {
+                Some(best_known) => heuristic_ctx.objective().total_order(best, best_known) == Ordering::Less,
+                None => true,
+            };
+
+            if is_improved {
+                *best_known = Some(best.deep_copy());
+            }
+        }
+
+        // NOTE always call inner termination as some criteria keep track of the search state
+        let is_termination = self.inner.is_termination(heuristic_ctx);
+
+        is_termination || self.state.is_stopped.load(AtomicOrdering::Relaxed)
+    }
+
+    fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
+        if self.state.is_stopped.load(AtomicOrdering::Relaxed) {
+            1.
+        } else {
+            self.inner.estimate(heuristic_ctx)
+        }
+    }
+}
@@ -162,6 +162,27 @@ pub fn get_dynamic_heuristic(
     )
 }
 
+/// Gets adaptive large neighbourhood search heuristic which uses the same operators as dynamic one
+/// and given acceptance criterion.
+pub fn get_adaptive_heuristic(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    acceptance: Box<dyn AcceptanceCriterion>,
+    segment_size: usize,
+    reaction_factor: f64,
+) -> AdaptiveSelective<RefinementContext, GoalContext, InsertionContext> {
+    let search_operators = dynamic::get_operators(problem.clone(), environment.clone());
+    let diversify_operators = create_diversify_operators(problem, environment);
+
+    AdaptiveSelective::<RefinementContext, GoalContext, InsertionContext>::new(
+        search_operators,
+        diversify_operators,
+        acceptance,
+        segment_size,
+        reaction_factor,
+    )
+}
+
 /// Creates elitism population algorithm.
 pub fn create_elitism_population(
     objective: Arc<GoalContext>,
@@ -340,16 +361,29 @@ fn create_diversify_operators(
     let infeasible_search = Arc::new(InfeasibleSearch::new(
         Arc::new(WeightedHeuristicOperator::new(
             vec![
-                dynamic::create_default_inner_ruin_recreate(problem, environment.clone()),
+                dynamic::create_default_inner_ruin_recreate(problem.clone(), environment.clone()),
                 dynamic::create_default_local_search(random.clone()),
             ],
             vec![10, 1],
         )),
-        Arc::new(RecreateWithCheapest::new(random)),
+        Arc::new(RecreateWithCheapest::new(random.clone())),
         4,
         (0.05, 0.2),
         (0.33, 0.75),
     ));
+    let guided_search = Arc::new(GuidedSearch::new(
+        Arc::new(WeightedHeuristicOperator::new(
+            vec![
+                dynamic::create_default_inner_ruin_recreate(problem, environment.clone()),
+                dynamic::create_default_local_search(random),
+            ],
+            vec![10, 1],
+        )),
+        4,
+        0.05,
+        0.1,
+        4,
+    ));
     let local_search = Arc::new(LocalSearch::new(Arc::new(CompositeLocalOperator::new(
         vec![(Arc::new(ExchangeSequence::new(8, 0.5, 0.1)), 1)],
         2,
@@ -357,8 +391,8 @@ fn create_diversify_operators(
     ))));
 
     vec![Arc::new(WeightedHeuristicOperator::new(
-        vec![redistribute_search, local_search, infeasible_search],
-        vec![10, 2, 1],
+        vec![redistribute_search, local_search, infeasible_search, guided_search],
+        vec![10, 2, 1, 1],
     ))]
 }
 
@@ -0,0 +1,278 @@
+//! Provides a way to estimate a lower bound of the solution cost and use it to stop the search when
+//! the optimality gap is small enough.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/lower_bound_test.rs"]
+mod lower_bound_test;
+
+use super::*;
+use crate::construction::features::{JobDemandDimension, VehicleCapacityDimension};
+use crate::models::common::*;
+use crate::models::problem::{Costs, Job, Single, VehicleIdDimension};
+use crate::models::Extras;
+use rosomaxa::termination::Termination;
+
+custom_extra_property!(LowerBound typeof LowerBound);
+
+/// Keeps lower bound estimations of a VRP solution.
+#[derive(Clone, Debug)]
+pub struct LowerBound {
+    /// A lower bound of the total solution cost.
+    pub cost: Cost,
+    /// A lower bound of the total traveled distance.
+    pub distance: Distance,
+    /// A lower bound of the total driving duration.
+    pub duration: Duration,
+    /// A lower bound of the amount of used vehicles.
+    pub vehicles: usize,
+}
+
+impl LowerBound {
+    /// Returns a relative gap between given solution cost and the lower bound cost.
+    pub fn get_gap(&self, cost: Cost) -> f64 {
+        if cost > 0. {
+            ((cost - self.cost) / cost).max(0.)
+        } else {
+            0.
+        }
+    }
+}
+
+/// Estimates a lower bound of the solution cost for given problem using simple bounds:
+/// * minimum spanning tree bound for distance and duration: any set of routes which serves all jobs connects
+///   all job locations with vehicle terminals
+/// * bin packing bound for amount of vehicles: total static demand should fit into used vehicles
+/// * shift time bound for amount of vehicles: total driving and service time should fit into vehicle shifts
+///
+/// Jobs which are specific to a vehicle (e.g. breaks or reloads) are not required to be served, so they are
+/// ignored. Job time windows are not considered, so waiting time is not a part of the bound. The bound assumes
+/// that routing matrix satisfies triangle inequality and travel costs are not time dependent.
+pub fn estimate_lower_bound(problem: &Problem) -> LowerBound {
+    let singles = problem
+        .jobs
+        .all()
+        .filter(is_required_job)
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    if singles.is_empty() || problem.fleet.actors.is_empty() {
+        return LowerBound { cost: 0., distance: 0., duration: 0., vehicles: 0 };
+    }
+
+    let terminals = problem
+        .fleet
+        .actors
+        .iter()
+        .flat_map(|actor| actor.detail.start.iter().chain(actor.detail.end.iter()).map(|place| place.location))
+        .collect::<Vec<_>>();
+    // NOTE jobs with alternative places are skipped: they cannot make the tree heavier due to triangle inequality
+    let locations = singles
+        .iter()
+        .filter_map(|single| match single.places.as_slice() {
+            [place] => place.location,
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let profiles = &problem.fleet.profiles;
+    let transport = problem.transport.as_ref();
+    let distance = get_spanning_tree_weight(terminals.as_slice(), locations.as_slice(), |from, to| {
+        profiles
+            .iter()
+            .map(|profile| {
+                transport.distance_approx(profile, from, to).min(transport.distance_approx(profile, to, from))
+            })
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default()
+    });
+    let duration = get_spanning_tree_weight(terminals.as_slice(), locations.as_slice(), |from, to| {
+        profiles
+            .iter()
+            .map(|profile| {
+                transport.duration_approx(profile, from, to).min(transport.duration_approx(profile, to, from))
+            })
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default()
+    });
+    let service = singles
+        .iter()
+        .map(|single| {
+            single.places.iter().map(|place| place.duration).min_by(|a, b| a.total_cmp(b)).unwrap_or_default()
+        })
+        .sum::<Duration>();
+
+    let vehicles = get_min_vehicles(problem, singles.as_slice(), duration + service);
+
+    let actors = problem.fleet.actors.as_slice();
+    let get_min_rate = |rate_fn: fn(&Costs) -> f64| {
+        actors
+            .iter()
+            .map(|actor| rate_fn(&actor.vehicle.costs) + rate_fn(&actor.driver.costs))
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default()
+    };
+
+    let mut fixed_costs =
+        actors.iter().map(|actor| actor.vehicle.costs.fixed + actor.driver.costs.fixed).collect::<Vec<_>>();
+    fixed_costs.sort_by(|a, b| a.total_cmp(b));
+
+    let cost = fixed_costs.iter().take(vehicles).sum::<Cost>()
+        + distance * get_min_rate(|costs| costs.per_distance)
+        + duration * get_min_rate(|costs| costs.per_driving_time)
+        + service * get_min_rate(|costs| costs.per_service_time);
+
+    LowerBound { cost, distance, duration, vehicles }
+}
+
+/// A termination criteria which stops the search when the best known solution is close enough to
+/// the lower bound.
+pub struct TargetGapTermination {
+    lower_bound: LowerBound,
+    target_gap: f64,
+}
+
+impl TargetGapTermination {
+    /// Creates a new instance of `TargetGapTermination`.
+    pub fn new(lower_bound: LowerBound, target_gap: f64) -> Self {
+        Self { lower_bound, target_gap }
+    }
+}
+
+impl Termination for TargetGapTermination {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        heuristic_ctx
+            .ranked()
+            .next()
+            .and_then(get_complete_solution_cost)
+            .is_some_and(|cost| self.lower_bound.get_gap(cost) <= self.target_gap)
+    }
+
+    fn estimate(&self, _: &Self::Context) -> f64 {
+        0.
+    }
+}
+
+/// Returns a cost of the solution if all required jobs are assigned. Otherwise, the solution cost cannot be
+/// compared with a lower bound or a target cost.
+pub fn get_complete_solution_cost(insertion_ctx: &InsertionContext) -> Option<Cost> {
+    let solution = &insertion_ctx.solution;
+
+    if is_complete_assignment(solution.required.iter().chain(solution.unassigned.keys())) {
+        insertion_ctx.get_total_cost()
+    } else {
+        None
+    }
+}
+
+/// Checks whether none of given not assigned jobs is required to be served. Jobs which are specific to a vehicle
+/// (e.g. breaks or reloads) can be left unassigned in a complete solution.
+pub fn is_complete_assignment<'a>(jobs: impl IntoIterator<Item = &'a Job>) -> bool {
+    !jobs.into_iter().any(is_required_job)
+}
+
+fn is_required_job(job: &Job) -> bool {
+    job.dimens().get_vehicle_id().is_none()
+}
+
+/// Calculates a weight of minimum spanning tree which connects all locations with any of vehicle terminals
+/// using Prim's algorithm. Terminals are considered as a single node.
+fn get_spanning_tree_weight<F>(terminals: &[Location], locations: &[Location], weight_fn: F) -> f64
+where
+    F: Fn(Location, Location) -> f64,
+{
+    let mut weights = locations
+        .iter()
+        .map(|&location| terminals.iter().map(|&terminal| weight_fn(terminal, location)).min_by(|a, b| a.total_cmp(b)))
+        .collect::<Vec<_>>();
+    let mut is_visited = vec![false; locations.len()];
+    let mut total = 0.;
+
+    for _ in 0..locations.len() {
+        // NOTE when there are no terminals, the first location is taken as a root
+        let next = (0..locations.len())
+            .filter(|&idx| !is_visited[idx])
+            .min_by(|&a, &b| weights[a].unwrap_or(f64::MAX).total_cmp(&weights[b].unwrap_or(f64::MAX)));
+
+        let Some(next) = next else { break };
+
+        is_visited[next] = true;
+        total += weights[next].unwrap_or_default();
+
+        (0..locations.len()).filter(|&idx| !is_visited[idx]).for_each(|idx| {
+            let weight = weight_fn(locations[next], locations[idx]);
+            let is_lighter = match weights[idx] {
+                Some(current) => weight < current,
+                None => true,
+            };
+
+            if is_lighter {
+                weights[idx] = Some(weight);
+            }
+        });
+    }
+
+    total
+}
+
+/// Returns minimum amount of vehicles needed to serve given jobs which require given amount of work time.
+fn get_min_vehicles(problem: &Problem, singles: &[Arc<Single>], work: Duration) -> usize {
+    // NOTE vehicle specific jobs, such as reloads, can change capacity usage, so capacity bound is not applicable
+    let has_specific_jobs = problem.jobs.all().any(|job| !is_required_job(&job));
+
+    let capacity_vehicles = if has_specific_jobs {
+        1
+    } else {
+        get_min_vehicles_by_capacity::<SingleDimLoad>(problem, singles)
+            .max(get_min_vehicles_by_capacity::<MultiDimLoad>(problem, singles))
+    };
+
+    capacity_vehicles.max(get_min_vehicles_by_shift_time(problem, work)).max(1).min(problem.fleet.actors.len())
+}
+
+fn get_min_vehicles_by_shift_time(problem: &Problem, work: Duration) -> usize {
+    let mut shifts = problem.fleet.actors.iter().map(|actor| actor.detail.time.duration()).collect::<Vec<_>>();
+    shifts.sort_by(|a, b| b.total_cmp(a));
+
+    // NOTE the longest shifts are used first, so the amount is minimal
+    shifts
+        .iter()
+        .scan(0., |total, shift| {
+            *total += shift;
+            Some(*total)
+        })
+        .position(|total| total >= work)
+        .map_or(shifts.len(), |idx| idx + 1)
+}
+
+fn get_min_vehicles_by_capacity<T: LoadOps>(problem: &Problem, singles: &[Arc<Single>]) -> usize {
+    let max_capacity = problem
+        .fleet
+        .actors
+        .iter()
+        .filter_map(|actor| actor.vehicle.dimens.get_vehicle_capacity::<T>())
+        .fold(None, |acc: Option<T>, capacity| Some(acc.map_or(*capacity, |acc| acc.max_load(*capacity))));
+
+    let Some(max_capacity) = max_capacity else { return 1 };
+
+    let (pickup, delivery) = singles
+        .iter()
+        .filter_map(|single| single.dimens.get_job_demand::<T>())
+        .fold((T::default(), T::default()), |(pickup, delivery), demand| {
+            (pickup + demand.pickup.0, delivery + demand.delivery.0)
+        });
+
+    [pickup, delivery]
+        .iter()
+        .map(|total| total.ratio(&max_capacity))
+        // NOTE ignore infinite values which are possible when some jobs cannot be served at all
+        .filter(|ratio| ratio.is_finite())
+        .map(|ratio| ratio.ceil() as usize)
+        .max()
+        .unwrap_or(1)
+}
@@ -83,14 +83,18 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+pub use self::handle::*;
 pub use self::heuristic::*;
+pub use self::lower_bound::*;
 use rosomaxa::population::Rosomaxa;
 use rosomaxa::utils::Timer;
 
 pub mod processing;
 pub mod search;
 
+mod handle;
 mod heuristic;
+mod lower_bound;
 
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
@@ -172,6 +176,14 @@ impl HeuristicContext for RefinementContext {
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
         self.inner_context.on_result()
     }
+
+    fn snapshot<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Solution> + 'a> {
+        self.inner_context.snapshot()
+    }
+
+    fn on_resume(&mut self, generation: usize) {
+        self.inner_context.on_resume(generation)
+    }
 }
 
 impl Stateful for RefinementContext {
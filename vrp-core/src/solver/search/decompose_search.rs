@@ -107,7 +107,7 @@ impl DecomposeSearch {
     }
 }
 
-fn create_population(insertion_ctx: InsertionContext) -> TargetPopulation {
+pub(super) fn create_population(insertion_ctx: InsertionContext) -> TargetPopulation {
     Box::new(GreedyPopulation::new(insertion_ctx.problem.goal.clone(), 1, Some(insertion_ctx)))
 }
 
@@ -155,7 +155,7 @@ fn create_multiple_insertion_contexts(
     Some(insertion_ctxs)
 }
 
-fn create_partial_insertion_ctx(
+pub(super) fn create_partial_insertion_ctx(
     insertion_ctx: &InsertionContext,
     environment: Arc<Environment>,
     route_indices: HashSet<usize>,
@@ -0,0 +1,264 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/guided_search_test.rs"]
+mod guided_search_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::TransportCost;
+use crate::models::*;
+use crate::solver::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// A penalty decay applied on each search call, so edges are penalized only temporarily.
+const PENALTY_DECAY: f64 = 0.9;
+/// A penalty value below which the edge is not penalized anymore.
+const MIN_PENALTY: f64 = 0.1;
+
+/// A directed edge between two locations.
+type Edge = (Location, Location);
+
+/// A diversification operator inspired by guided local search (GLS): it tracks edge frequencies in elite
+/// solutions and penalizes frequently used expensive edges of the given solution. Penalties are added to the
+/// goal only temporarily while inner search is performed, so they never leak into reported costs.
+///
+/// The idea is described in [`Voudouris & Tsang (1999)`].
+///
+/// [`Voudouris & Tsang (1999)`]: https://doi.org/10.1016/S0377-2217(98)00099-X
+pub struct GuidedSearch {
+    inner_search: TargetSearchOperator,
+    max_repeat_count: usize,
+    penalized_ratio: f64,
+    penalty_factor: f64,
+    elite_size: usize,
+    penalties: Mutex<HashMap<Edge, f64>>,
+}
+
+impl GuidedSearch {
+    /// Creates a new instance of `GuidedSearch`.
+    /// `penalized_ratio` specifies a ratio of solution's edges penalized on each call, `penalty_factor`
+    /// specifies a penalty weight relative to an average edge cost, `elite_size` specifies amount of
+    /// best known solutions used to estimate edge frequencies.
+    pub fn new(
+        inner_search: TargetSearchOperator,
+        max_repeat_count: usize,
+        penalized_ratio: f64,
+        penalty_factor: f64,
+        elite_size: usize,
+    ) -> Self {
+        assert!(max_repeat_count > 0);
+        assert!(penalized_ratio > 0. && penalized_ratio <= 1.);
+
+        Self {
+            inner_search,
+            max_repeat_count,
+            penalized_ratio,
+            penalty_factor,
+            elite_size,
+            penalties: Mutex::new(HashMap::default()),
+        }
+    }
+
+    /// Updates edge penalties using the given solution and elite solutions.
+    /// Returns a snapshot of current penalties.
+    fn update_penalties(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> HashMap<Edge, f64> {
+        let mut penalties = self.penalties.lock().expect("cannot lock edge penalties");
+
+        penalties.retain(|_, penalty| {
+            *penalty *= PENALTY_DECAY;
+            *penalty > MIN_PENALTY
+        });
+
+        let frequencies = refinement_ctx.ranked().take(self.elite_size).fold(HashMap::new(), |mut acc, solution| {
+            solution
+                .solution
+                .routes
+                .iter()
+                .flat_map(get_route_edges)
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .for_each(|edge| *acc.entry(edge).or_insert(0_usize) += 1);
+            acc
+        });
+
+        let transport = insertion_ctx.problem.transport.as_ref();
+        let mut utilities = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| get_route_edges(route_ctx).map(move |edge| (route_ctx, edge)))
+            .map(|(route_ctx, edge)| {
+                let cost = get_edge_cost(route_ctx, transport, edge);
+                let frequency = frequencies.get(&edge).copied().unwrap_or_default() as f64;
+                let penalty = penalties.get(&edge).copied().unwrap_or_default();
+
+                (edge, cost * (1. + frequency) / (1. + penalty))
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE keep order stable to have reproducible results
+        utilities.sort_by(|(a_edge, a), (b_edge, b)| b.total_cmp(a).then_with(|| a_edge.cmp(b_edge)));
+        utilities.dedup_by_key(|(edge, _)| *edge);
+
+        let penalized = ((utilities.len() as f64 * self.penalized_ratio).ceil() as usize).max(1);
+        utilities.into_iter().take(penalized).for_each(|(edge, _)| *penalties.entry(edge).or_insert(0.) += 1.);
+
+        penalties.clone()
+    }
+}
+
+impl HeuristicSearchOperator for GuidedSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+        let insertion_ctx = solution;
+
+        let penalties = self.update_penalties(refinement_ctx, insertion_ctx);
+        let weight = get_penalty_weight(insertion_ctx, self.penalty_factor);
+
+        // NOTE goal without cost objective cannot be penalized, so fallback to inner search
+        let Some(penalized_ctx) =
+            create_penalized_insertion_ctx(insertion_ctx, EdgePenaltyObjective { penalties, weight })
+        else {
+            return self.inner_search.search(refinement_ctx, insertion_ctx);
+        };
+        let mut penalized_refinement_ctx = create_penalized_refinement_ctx(&penalized_ctx);
+
+        let repeat_count = refinement_ctx.environment.random.uniform_int(1, self.max_repeat_count as i32);
+
+        let mut initial = Some(penalized_ctx);
+        for _ in 0..repeat_count {
+            let new_insertion_ctx = match initial.take() {
+                Some(initial) => self.inner_search.search(&penalized_refinement_ctx, &initial),
+                None => {
+                    let Some(selected) = penalized_refinement_ctx.selected().next() else {
+                        break;
+                    };
+                    self.inner_search.search(&penalized_refinement_ctx, selected)
+                }
+            };
+
+            penalized_refinement_ctx.add_solution(new_insertion_ctx);
+        }
+
+        let mut new_insertion_ctx = penalized_refinement_ctx
+            .ranked()
+            .next()
+            .map(|best| best.deep_copy())
+            .unwrap_or_else(|| insertion_ctx.deep_copy());
+
+        // NOTE restore original problem, so penalties are not reported
+        new_insertion_ctx.problem = insertion_ctx.problem.clone();
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+
+        new_insertion_ctx
+    }
+}
+
+/// An objective which adds penalties of used edges.
+struct EdgePenaltyObjective {
+    penalties: HashMap<Edge, f64>,
+    weight: Cost,
+}
+
+impl EdgePenaltyObjective {
+    fn get_penalty(&self, edge: Edge) -> Cost {
+        self.penalties.get(&edge).map(|penalty| penalty * self.weight).unwrap_or_default()
+    }
+}
+
+impl FeatureObjective for EdgePenaltyObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().flat_map(get_route_edges).map(|edge| self.get_penalty(edge)).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { activity_ctx, .. } => {
+                let prev = activity_ctx.prev.place.location;
+                let target = activity_ctx.target.place.location;
+
+                match activity_ctx.next.map(|next| next.place.location) {
+                    Some(next) => {
+                        self.get_penalty((prev, target)) + self.get_penalty((target, next))
+                            - self.get_penalty((prev, next))
+                    }
+                    None => self.get_penalty((prev, target)),
+                }
+            }
+        }
+    }
+}
+
+/// Returns edges used by the route.
+fn get_route_edges(route_ctx: &RouteContext) -> impl Iterator<Item = Edge> + '_ {
+    let tour = &route_ctx.route().tour;
+
+    tour.all_activities()
+        .zip(tour.all_activities().skip(1))
+        .map(|(from, to)| (from.place.location, to.place.location))
+        .filter(|(from, to)| from != to)
+}
+
+/// Estimates edge cost using vehicle costs.
+fn get_edge_cost(route_ctx: &RouteContext, transport: &(dyn TransportCost + Send + Sync), edge: Edge) -> Cost {
+    let vehicle = &route_ctx.route().actor.vehicle;
+    let (from, to) = edge;
+
+    vehicle.costs.per_distance * transport.distance_approx(&vehicle.profile, from, to)
+        + vehicle.costs.per_driving_time * transport.duration_approx(&vehicle.profile, from, to)
+}
+
+/// Returns a weight of one penalty unit which is proportional to an average edge cost in the solution.
+fn get_penalty_weight(insertion_ctx: &InsertionContext, penalty_factor: f64) -> Cost {
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let (total, count) = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| get_route_edges(route_ctx).map(move |edge| get_edge_cost(route_ctx, transport, edge)))
+        .fold((Cost::default(), 0), |(total, count), cost| (total + cost, count + 1));
+
+    if count > 0 {
+        penalty_factor * total / count as f64
+    } else {
+        Cost::default()
+    }
+}
+
+fn create_penalized_insertion_ctx(
+    insertion_ctx: &InsertionContext,
+    objective: EdgePenaltyObjective,
+) -> Option<InsertionContext> {
+    let problem = &insertion_ctx.problem;
+    let goal = problem.goal.with_augmented_objective(Arc::new(objective))?;
+
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    insertion_ctx.problem = Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        goal: Arc::new(goal),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: problem.extras.clone(),
+    });
+
+    Some(insertion_ctx)
+}
+
+fn create_penalized_refinement_ctx(insertion_ctx: &InsertionContext) -> RefinementContext {
+    let problem = insertion_ctx.problem.clone();
+    let environment = insertion_ctx.environment.clone();
+    let population = Box::new(ElitismPopulation::new(problem.goal.clone(), environment.random.clone(), 4, 4));
+
+    RefinementContext::new(problem, population, TelemetryMode::None, environment)
+}
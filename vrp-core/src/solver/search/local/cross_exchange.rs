@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/cross_exchange_test.rs"]
+mod cross_exchange_test;
+
+use super::segment_moves::*;
+use super::*;
+
+/// A local search operator which implements CROSS-exchange move: it swaps two short segments of
+/// consecutive jobs between two routes, so that a job's segment follows its neighbour from another route.
+pub struct CrossExchange {
+    max_segment_size: usize,
+    neighbourhood_size: usize,
+    max_evaluations: usize,
+}
+
+impl CrossExchange {
+    /// Creates a new instance of `CrossExchange`.
+    pub fn new(max_segment_size: usize, neighbourhood_size: usize, max_evaluations: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { max_segment_size, neighbourhood_size, max_evaluations }
+    }
+}
+
+impl Default for CrossExchange {
+    fn default() -> Self {
+        Self::new(3, 10, 100)
+    }
+}
+
+impl LocalOperator for CrossExchange {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_segment_moves(
+            insertion_ctx,
+            self.neighbourhood_size,
+            self.max_evaluations,
+            |index, node, other, moves| {
+                let ((route_idx, node_idx), (other_route_idx, other_idx)) = (node, other);
+
+                if route_idx == other_route_idx {
+                    return;
+                }
+
+                let route_size = index.routes[route_idx].size();
+                let other_route_size = index.routes[other_route_idx].size();
+
+                for segment_size in 1..=self.max_segment_size.min(route_size - node_idx) {
+                    // NOTE other segment starts after neighbour, so the node's segment follows the neighbour
+                    for other_segment_size in 1..=self.max_segment_size.min(other_route_size - other_idx - 1) {
+                        let segment = node_idx..(node_idx + segment_size);
+                        let other_segment = (other_idx + 1)..(other_idx + 1 + other_segment_size);
+
+                        moves.extend(index.create_move(vec![
+                            RouteChange {
+                                route_idx,
+                                range: segment.clone(),
+                                inserted: index.nodes(other_route_idx, other_segment.clone()).collect(),
+                            },
+                            RouteChange {
+                                route_idx: other_route_idx,
+                                range: other_segment,
+                                inserted: index.nodes(route_idx, segment).collect(),
+                            },
+                        ]));
+                    }
+                }
+            },
+        )
+    }
+}
@@ -147,6 +147,7 @@ fn find_best_insertion_pair(
                     },
                     || None,
                     |left, right| reduce_pair_with_noise(left, right, &noise),
+                    insertion_ctx.environment.random.as_ref(),
                 );
 
                 reduce_pair_with_noise(acc, new_result, &noise)
@@ -362,6 +362,7 @@ fn try_exchange_jobs_in_routes(
             Ordering::Less => left,
             _ => right,
         },
+        insertion_ctx.environment.random.as_ref(),
     );
 
     try_exchange_jobs(insertion_ctx, (outer_best, inner_best), leg_selection, result_selector);
@@ -6,6 +6,9 @@ use rosomaxa::prelude::*;
 use std::cmp::Ordering;
 use std::sync::Arc;
 
+mod cross_exchange;
+pub use self::cross_exchange::*;
+
 mod exchange_inter_route;
 pub use self::exchange_inter_route::*;
 
@@ -18,9 +21,17 @@ pub use self::exchange_sequence::*;
 mod exchange_swap_star;
 pub use self::exchange_swap_star::*;
 
+mod or_opt;
+pub use self::or_opt::*;
+
 mod reschedule_departure;
 pub use self::reschedule_departure::*;
 
+mod segment_moves;
+
+mod two_opt;
+pub use self::two_opt::*;
+
 /// Specifies behavior of a local search operator.
 pub trait LocalOperator {
     /// Applies local search operator to passed solution in order to explore possible
@@ -0,0 +1,81 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/or_opt_test.rs"]
+mod or_opt_test;
+
+use super::segment_moves::*;
+use super::*;
+
+/// A local search operator which implements Or-opt move: it relocates a short segment of consecutive
+/// jobs, optionally reversed, after its neighbour job within the same or another route.
+pub struct OrOpt {
+    max_segment_size: usize,
+    neighbourhood_size: usize,
+    max_evaluations: usize,
+}
+
+impl OrOpt {
+    /// Creates a new instance of `OrOpt`.
+    pub fn new(max_segment_size: usize, neighbourhood_size: usize, max_evaluations: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { max_segment_size, neighbourhood_size, max_evaluations }
+    }
+}
+
+impl Default for OrOpt {
+    fn default() -> Self {
+        Self::new(3, 10, 100)
+    }
+}
+
+impl LocalOperator for OrOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_segment_moves(
+            insertion_ctx,
+            self.neighbourhood_size,
+            self.max_evaluations,
+            |index, node, other, moves| {
+                let ((route_idx, node_idx), (other_route_idx, other_idx)) = (node, other);
+                let route_size = index.routes[route_idx].size();
+
+                for segment_size in 1..=self.max_segment_size.min(route_size - node_idx) {
+                    let segment = node_idx..(node_idx + segment_size);
+
+                    for is_reversed in [false, true].into_iter().take(if segment_size > 1 { 2 } else { 1 }) {
+                        let mut inserted = index.nodes(route_idx, segment.clone()).collect::<Vec<_>>();
+                        if is_reversed {
+                            inserted.reverse();
+                        }
+
+                        let changes = if route_idx != other_route_idx {
+                            let position = (other_idx + 1)..(other_idx + 1);
+                            vec![
+                                RouteChange { route_idx, range: segment.clone(), inserted: vec![] },
+                                RouteChange { route_idx: other_route_idx, range: position, inserted },
+                            ]
+                        } else if other_idx >= segment.end {
+                            // NOTE segment is moved forward: shift jobs between segment and neighbour back
+                            let range = segment.start..(other_idx + 1);
+                            let inserted =
+                                index.nodes(route_idx, segment.end..(other_idx + 1)).chain(inserted).collect();
+                            vec![RouteChange { route_idx, range, inserted }]
+                        } else if other_idx + 1 < segment.start {
+                            // NOTE segment is moved backward: shift jobs between neighbour and segment forward
+                            let range = (other_idx + 1)..segment.end;
+                            let shifted = index.nodes(route_idx, (other_idx + 1)..segment.start);
+                            vec![RouteChange {
+                                route_idx,
+                                range,
+                                inserted: inserted.into_iter().chain(shifted).collect(),
+                            }]
+                        } else {
+                            continue;
+                        };
+
+                        moves.extend(index.create_move(changes));
+                    }
+                }
+            },
+        )
+    }
+}
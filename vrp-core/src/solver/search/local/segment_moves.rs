@@ -0,0 +1,403 @@
+//! Provides common logic for deterministic local search operators which are moving segments of jobs
+//! within one route or between routes (2-opt, 2-opt*, Or-opt, CROSS-exchange).
+//!
+//! The moves are generated from granular neighbourhoods defined by `Jobs::neighbors`, pre-filtered by
+//! approximated distance gain and time window segments, and then checked using insertion evaluation
+//! on copies of affected routes. Only moves which pass this check are applied to a copy of the whole
+//! solution and compared using goal's total order.
+
+use crate::construction::enablers::{create_route_time_segments, evaluate_time_segments, RouteSegments, TimeSegment};
+use crate::construction::heuristics::*;
+use crate::models::common::{Distance, Location, Profile};
+use crate::models::problem::{Job, TransportCost};
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A reference to a node: route index and node index inside the route.
+pub(super) type NodeRef = (usize, usize);
+
+/// Specifies a replacement of route's nodes in given range with other nodes.
+pub(super) struct RouteChange {
+    pub route_idx: usize,
+    pub range: Range<usize>,
+    pub inserted: Vec<NodeRef>,
+}
+
+/// A move which consists of one or more route changes.
+pub(super) struct SegmentMove {
+    changes: Vec<RouteChange>,
+    gain: Distance,
+}
+
+/// A job activity inside a route.
+pub(super) struct SegmentNode {
+    job: Job,
+    location: Location,
+    is_movable: bool,
+    time: Option<TimeSegment>,
+}
+
+/// Keeps route nodes with their locations. The first and the last locations are route's start and end.
+pub(super) struct SegmentRoute {
+    profile: Profile,
+    nodes: Vec<SegmentNode>,
+    locations: Vec<Option<Location>>,
+    time_segments: RouteSegments<TimeSegment>,
+}
+
+impl SegmentRoute {
+    /// Returns amount of job nodes in the route.
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Checks whether all nodes in given range are movable.
+    pub fn is_movable(&self, range: Range<usize>) -> bool {
+        range.end <= self.nodes.len() && self.nodes[range].iter().all(|node| node.is_movable)
+    }
+}
+
+/// Keeps information about solution's routes needed to generate segment moves.
+pub(super) struct SegmentIndex {
+    pub routes: Vec<SegmentRoute>,
+    positions: HashMap<Job, NodeRef>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SegmentIndex {
+    /// Creates a new instance of `SegmentIndex`.
+    pub fn new(insertion_ctx: &InsertionContext) -> Self {
+        let problem_jobs = insertion_ctx.problem.jobs.all().collect::<HashSet<_>>();
+        let routes = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| create_segment_route(insertion_ctx, route_ctx, &problem_jobs))
+            .collect::<Vec<_>>();
+
+        let mut index =
+            Self { routes, positions: HashMap::default(), transport: insertion_ctx.problem.transport.clone() };
+        (0..index.routes.len()).for_each(|route_idx| index.add_positions(route_idx));
+
+        index
+    }
+
+    /// Updates the index after given routes are changed. The whole index is rebuilt only when
+    /// the amount of routes is changed, e.g. some route became empty.
+    pub fn update(&mut self, insertion_ctx: &InsertionContext, route_indices: &[usize]) {
+        if insertion_ctx.solution.routes.len() != self.routes.len() {
+            *self = Self::new(insertion_ctx);
+            return;
+        }
+
+        let problem_jobs = insertion_ctx.problem.jobs.all().collect::<HashSet<_>>();
+
+        route_indices.iter().for_each(|&route_idx| {
+            self.routes[route_idx].nodes.iter().for_each(|node| {
+                self.positions.remove(&node.job);
+            });
+        });
+
+        route_indices.iter().for_each(|&route_idx| {
+            let route_ctx = &insertion_ctx.solution.routes[route_idx];
+            self.routes[route_idx] = create_segment_route(insertion_ctx, route_ctx, &problem_jobs);
+            self.add_positions(route_idx);
+        });
+    }
+
+    /// Returns movable nodes with their nearest movable neighbours.
+    pub fn neighbours<'b>(
+        &'b self,
+        insertion_ctx: &'b InsertionContext,
+        neighbourhood_size: usize,
+    ) -> impl Iterator<Item = (NodeRef, NodeRef)> + 'b {
+        self.routes.iter().enumerate().flat_map(move |(route_idx, route)| {
+            route.nodes.iter().enumerate().filter(|(_, node)| node.is_movable).flat_map(move |(node_idx, node)| {
+                insertion_ctx
+                    .problem
+                    .jobs
+                    .neighbors(&route.profile, &node.job, 0.)
+                    .filter_map(|(job, _)| self.positions.get(job).copied())
+                    .take(neighbourhood_size)
+                    .map(move |other| ((route_idx, node_idx), other))
+            })
+        })
+    }
+
+    /// Creates a move from given route changes if all affected nodes are movable, approximated
+    /// distance gain is positive and changes do not introduce time window violations.
+    pub fn create_move(&self, changes: Vec<RouteChange>) -> Option<SegmentMove> {
+        let is_movable = changes.iter().all(|change| {
+            self.routes[change.route_idx].is_movable(change.range.clone())
+                && change
+                    .inserted
+                    .iter()
+                    .all(|&(route_idx, node_idx)| self.routes[route_idx].nodes[node_idx].is_movable)
+        });
+
+        if !is_movable {
+            return None;
+        }
+
+        let gain = changes.iter().map(|change| -self.get_delta(change)).sum::<Distance>();
+
+        if gain > 1E-6 && !changes.iter().any(|change| self.has_time_warp(change)) {
+            Some(SegmentMove { changes, gain })
+        } else {
+            None
+        }
+    }
+
+    /// Returns nodes in given range of the route.
+    pub fn nodes(&self, route_idx: usize, range: Range<usize>) -> impl DoubleEndedIterator<Item = NodeRef> {
+        range.map(move |node_idx| (route_idx, node_idx))
+    }
+
+    fn add_positions(&mut self, route_idx: usize) {
+        let route = &self.routes[route_idx];
+        let positions = route
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.is_movable)
+            .map(|(node_idx, node)| (node.job.clone(), (route_idx, node_idx)));
+
+        self.positions.extend(positions);
+    }
+
+    fn get_delta(&self, change: &RouteChange) -> Distance {
+        let route = &self.routes[change.route_idx];
+        // NOTE node with index i has location at i + 1 as the first location is start
+        let (prev, next) = (route.locations[change.range.start], route.locations[change.range.end + 1]);
+
+        let old_locations = route.locations[change.range.start..=change.range.end + 1].iter().copied();
+        let new_locations = std::iter::once(prev)
+            .chain(
+                change
+                    .inserted
+                    .iter()
+                    .map(|&(route_idx, node_idx)| Some(self.routes[route_idx].nodes[node_idx].location)),
+            )
+            .chain(std::iter::once(next));
+
+        self.get_distance(&route.profile, new_locations) - self.get_distance(&route.profile, old_locations)
+    }
+
+    /// Checks whether the route change introduces time window violations using route's time segments.
+    /// Returns false if it cannot be estimated.
+    fn has_time_warp(&self, change: &RouteChange) -> bool {
+        let route = &self.routes[change.route_idx];
+        let time_segments = &route.time_segments;
+
+        // NOTE do not filter routes which are already violating time windows
+        if time_segments.total().filter(|total| total.time_warp < 1E-6).is_none() {
+            return false;
+        }
+
+        let Some(inserted) = change
+            .inserted
+            .iter()
+            .map(|&(route_idx, node_idx)| self.routes[route_idx].nodes[node_idx].time.as_ref())
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+
+        // NOTE activity index is node index + 1 as the first activity is route's start
+        let segments = time_segments
+            .prefix(change.range.start)
+            .into_iter()
+            .chain(inserted)
+            .chain(time_segments.suffix(change.range.end + 1));
+
+        evaluate_time_segments(&route.profile, self.transport.as_ref(), segments)
+            .is_some_and(|total| total.time_warp > 1E-6)
+    }
+
+    fn get_distance(&self, profile: &Profile, locations: impl Iterator<Item = Option<Location>>) -> Distance {
+        locations
+            .fold((None, Distance::default()), |(prev, total), location| {
+                let distance = match (prev, location) {
+                    (Some(from), Some(to)) => self.transport.distance_approx(profile, from, to),
+                    _ => Distance::default(),
+                };
+
+                (location.or(prev), total + distance)
+            })
+            .1
+    }
+}
+
+/// Explores segment moves generated by given function and applies improving ones until no improvement
+/// is found or evaluation limit is reached. Returns `None` if solution is not improved.
+pub(super) fn explore_segment_moves<F>(
+    insertion_ctx: &InsertionContext,
+    neighbourhood_size: usize,
+    max_evaluations: usize,
+    generate_fn: F,
+) -> Option<InsertionContext>
+where
+    F: Fn(&SegmentIndex, NodeRef, NodeRef, &mut Vec<SegmentMove>),
+{
+    let mut evaluations = 0;
+    let mut best: Option<InsertionContext> = None;
+    let mut index = SegmentIndex::new(insertion_ctx);
+
+    while evaluations < max_evaluations {
+        let current = best.as_ref().unwrap_or(insertion_ctx);
+
+        let mut moves = Vec::default();
+        index
+            .neighbours(current, neighbourhood_size)
+            .for_each(|(node, other)| generate_fn(&index, node, other, &mut moves));
+        moves.sort_by(|a, b| b.gain.total_cmp(&a.gain));
+
+        let improved = moves.iter().take(max_evaluations - evaluations).find_map(|segment_move| {
+            evaluations += 1;
+            // NOTE the whole solution is copied only when the move is feasible for affected routes
+            let routes = apply_to_route_copies(current, &index, segment_move)?;
+            let route_indices = routes.iter().map(|(route_idx, _)| *route_idx).collect::<Vec<_>>();
+
+            Some((create_insertion_ctx(current, routes), route_indices))
+                .filter(|(new_ctx, _)| current.problem.goal.total_order(current, new_ctx) == Ordering::Greater)
+        });
+
+        match improved {
+            Some((new_ctx, route_indices)) => {
+                index.update(&new_ctx, route_indices.as_slice());
+                best = Some(new_ctx);
+            }
+            None => break,
+        }
+    }
+
+    best
+}
+
+fn create_segment_route(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    problem_jobs: &HashSet<Job>,
+) -> SegmentRoute {
+    let route = route_ctx.route();
+    let nodes = route
+        .tour
+        .all_activities()
+        .filter_map(|activity| {
+            let location = activity.place.location;
+            activity.retrieve_job().map(|job| {
+                let is_movable = matches!(job, Job::Single(_))
+                    && problem_jobs.contains(&job)
+                    && !insertion_ctx.solution.locked.contains(&job);
+                // NOTE a job with alternative time windows can be rescheduled by insertion
+                let has_single_time = job.as_single().is_some_and(|single| {
+                    single.places.iter().map(|place| place.times.len().max(1)).sum::<usize>() == 1
+                });
+                let time = has_single_time.then(|| TimeSegment::from_activity(activity));
+
+                SegmentNode { job, location, is_movable, time }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let start = route.tour.start().map(|activity| activity.place.location);
+    let end = route.tour.end().filter(|activity| activity.job.is_none()).map(|a| a.place.location);
+    let locations =
+        std::iter::once(start).chain(nodes.iter().map(|node| Some(node.location))).chain(std::iter::once(end));
+
+    SegmentRoute {
+        profile: route.actor.vehicle.profile.clone(),
+        locations: locations.collect(),
+        nodes,
+        time_segments: create_route_time_segments(route, insertion_ctx.problem.transport.as_ref()),
+    }
+}
+
+/// Applies the move to copies of affected routes. Returns `None` if some job cannot be inserted back.
+fn apply_to_route_copies(
+    insertion_ctx: &InsertionContext,
+    index: &SegmentIndex,
+    segment_move: &SegmentMove,
+) -> Option<Vec<(usize, RouteContext)>> {
+    let goal = insertion_ctx.problem.goal.as_ref();
+    let get_job = |&(route_idx, node_idx): &NodeRef| index.routes[route_idx].nodes[node_idx].job.clone();
+
+    let mut routes: Vec<(usize, RouteContext)> = Vec::with_capacity(segment_move.changes.len());
+
+    // NOTE remove all affected jobs first as they can be moved between routes
+    for change in segment_move.changes.iter() {
+        let position = match routes.iter().position(|(route_idx, _)| *route_idx == change.route_idx) {
+            Some(position) => position,
+            None => {
+                routes.push((change.route_idx, insertion_ctx.solution.routes.get(change.route_idx)?.deep_copy()));
+                routes.len() - 1
+            }
+        };
+        let route_ctx = &mut routes[position].1;
+
+        for job in index.nodes(change.route_idx, change.range.clone()).map(|node| get_job(&node)) {
+            if !route_ctx.route_mut().tour.remove(&job) {
+                return None;
+            }
+        }
+        goal.accept_route_state(route_ctx);
+    }
+
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    for change in segment_move.changes.iter() {
+        let (_, route_ctx) = routes.iter_mut().find(|(route_idx, _)| *route_idx == change.route_idx)?;
+
+        let anchor = change.range.start.checked_sub(1).map(|node_idx| get_job(&(change.route_idx, node_idx)));
+        let start_leg_idx = match anchor {
+            Some(anchor) => route_ctx.route().tour.index(&anchor)?,
+            None => 0,
+        };
+
+        for (leg_idx, job) in (start_leg_idx..).zip(change.inserted.iter().map(get_job)) {
+            let eval_ctx = EvaluationContext {
+                goal: &insertion_ctx.problem.goal,
+                job: &job,
+                leg_selection: &leg_selection,
+                result_selector: &result_selector,
+            };
+
+            let result = eval_job_insertion_in_route(
+                insertion_ctx,
+                &eval_ctx,
+                route_ctx,
+                InsertionPosition::Concrete(leg_idx),
+                InsertionResult::make_failure(),
+            );
+
+            match result {
+                InsertionResult::Success(success) => {
+                    let route = route_ctx.route_mut();
+                    success.activities.into_iter().for_each(|(activity, idx)| {
+                        route.tour.insert_at(activity, idx + 1);
+                    });
+                    goal.accept_route_state(route_ctx);
+                }
+                InsertionResult::Failure(_) => return None,
+            }
+        }
+    }
+
+    Some(routes)
+}
+
+/// Creates a copy of the solution with affected routes replaced by their changed versions.
+fn create_insertion_ctx(insertion_ctx: &InsertionContext, routes: Vec<(usize, RouteContext)>) -> InsertionContext {
+    let mut new_ctx = insertion_ctx.deep_copy();
+
+    routes.into_iter().for_each(|(route_idx, route_ctx)| new_ctx.solution.routes[route_idx] = route_ctx);
+
+    finalize_insertion_ctx(&mut new_ctx);
+    new_ctx.solution.remove_empty_routes();
+
+    new_ctx
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/two_opt_test.rs"]
+mod two_opt_test;
+
+use super::segment_moves::*;
+use super::*;
+
+/// A local search operator which implements classic 2-opt move: it reverses a segment inside one route
+/// in order to remove crossing legs. Candidate moves are generated from nearest job neighbours.
+pub struct TwoOpt {
+    neighbourhood_size: usize,
+    max_evaluations: usize,
+}
+
+impl TwoOpt {
+    /// Creates a new instance of `TwoOpt`.
+    pub fn new(neighbourhood_size: usize, max_evaluations: usize) -> Self {
+        Self { neighbourhood_size, max_evaluations }
+    }
+}
+
+impl Default for TwoOpt {
+    fn default() -> Self {
+        Self::new(10, 100)
+    }
+}
+
+impl LocalOperator for TwoOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_segment_moves(
+            insertion_ctx,
+            self.neighbourhood_size,
+            self.max_evaluations,
+            |index, node, other, moves| {
+                let ((route_idx, node_idx), (other_route_idx, other_idx)) = (node, other);
+
+                if route_idx != other_route_idx {
+                    return;
+                }
+
+                // NOTE reverse a segment between nodes to make them adjacent
+                let range = match node_idx.cmp(&other_idx) {
+                    Ordering::Less if other_idx > node_idx + 1 => (node_idx + 1)..(other_idx + 1),
+                    Ordering::Greater if node_idx > other_idx + 1 => other_idx..node_idx,
+                    _ => return,
+                };
+
+                let inserted = index.nodes(route_idx, range.clone()).rev().collect();
+                moves.extend(index.create_move(vec![RouteChange { route_idx, range, inserted }]));
+            },
+        )
+    }
+}
+
+/// A local search operator which implements 2-opt* move: it exchanges tails of two routes, so that
+/// a job is followed by its neighbour from another route.
+pub struct TwoOptStar {
+    neighbourhood_size: usize,
+    max_evaluations: usize,
+}
+
+impl TwoOptStar {
+    /// Creates a new instance of `TwoOptStar`.
+    pub fn new(neighbourhood_size: usize, max_evaluations: usize) -> Self {
+        Self { neighbourhood_size, max_evaluations }
+    }
+}
+
+impl Default for TwoOptStar {
+    fn default() -> Self {
+        Self::new(10, 100)
+    }
+}
+
+impl LocalOperator for TwoOptStar {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_segment_moves(
+            insertion_ctx,
+            self.neighbourhood_size,
+            self.max_evaluations,
+            |index, node, other, moves| {
+                let ((route_idx, node_idx), (other_route_idx, other_idx)) = (node, other);
+
+                if route_idx == other_route_idx {
+                    return;
+                }
+
+                let tail = (node_idx + 1)..index.routes[route_idx].size();
+                let other_tail = other_idx..index.routes[other_route_idx].size();
+
+                moves.extend(index.create_move(vec![
+                    RouteChange {
+                        route_idx,
+                        range: tail.clone(),
+                        inserted: index.nodes(other_route_idx, other_tail.clone()).collect(),
+                    },
+                    RouteChange {
+                        route_idx: other_route_idx,
+                        range: other_tail,
+                        inserted: index.nodes(route_idx, tail).collect(),
+                    },
+                ]));
+            },
+        )
+    }
+}
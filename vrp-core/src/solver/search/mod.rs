@@ -27,18 +27,30 @@ pub(crate) use self::utils::*;
 mod decompose_search;
 pub use self::decompose_search::DecomposeSearch;
 
+mod guided_search;
+pub use self::guided_search::GuidedSearch;
+
 mod infeasible_search;
 pub use self::infeasible_search::InfeasibleSearch;
 
 mod local_search;
 pub use self::local_search::LocalSearch;
 
+mod popmusic_search;
+pub use self::popmusic_search::PopmusicSearch;
+
 mod redistribute_search;
 pub use self::redistribute_search::RedistributeSearch;
 
 mod ruin_recreate;
 pub use self::ruin_recreate::RuinAndRecreate;
 
+mod selective_route_exchange;
+pub use self::selective_route_exchange::SelectiveRouteExchange;
+
+mod set_partitioning_search;
+pub use self::set_partitioning_search::SetPartitioningSearch;
+
 /// Provides the way to pick one heuristic operator from the group.
 pub struct WeightedHeuristicOperator {
     mutations: Vec<TargetSearchOperator>,
@@ -0,0 +1,230 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/popmusic_search_test.rs"]
+mod popmusic_search_test;
+
+use super::decompose_search::{create_partial_insertion_ctx, create_population};
+use crate::construction::heuristics::*;
+use crate::models::problem::Actor;
+use crate::models::{GoalContext, Problem};
+use crate::solver::search::create_environment_with_custom_quota;
+use crate::solver::*;
+use rand::prelude::SliceRandom;
+use rosomaxa::utils::parallel_into_collect;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::iter::once;
+
+/// A search operator which implements POPMUSIC (Partial OPtimization Metaheuristic Under Special
+/// Intensification Conditions) idea: it takes a seed route together with its nearest routes by medoid,
+/// optimizes such subproblem independently and merges it back when it is improved. Seed routes of
+/// subproblems which were not improved are not used again, while routes of improved subproblems become
+/// seeds. Disjoint subproblems are optimized in parallel, which makes the operator suitable for problems
+/// with a large amount of jobs.
+pub struct PopmusicSearch {
+    inner_search: TargetSearchOperator,
+    subproblem_routes: usize,
+    repeat_count: usize,
+    max_rounds: usize,
+    quota_limit: usize,
+}
+
+impl PopmusicSearch {
+    /// Creates a new instance of `PopmusicSearch`.
+    /// `subproblem_routes` specifies amount of routes in one subproblem, `repeat_count` specifies amount
+    /// of inner search iterations for each subproblem, `max_rounds` limits amount of rounds within one
+    /// search call.
+    pub fn new(
+        inner_search: TargetSearchOperator,
+        subproblem_routes: usize,
+        repeat_count: usize,
+        max_rounds: usize,
+        quota_limit: usize,
+    ) -> Self {
+        assert!(subproblem_routes > 1);
+
+        Self { inner_search, subproblem_routes, repeat_count, max_rounds, quota_limit }
+    }
+}
+
+impl HeuristicSearchOperator for PopmusicSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+        let mut insertion_ctx = solution.deep_copy();
+        let mut seeds = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| route_ctx.route().actor.clone())
+            .collect::<HashSet<_>>();
+
+        for _ in 0..self.max_rounds {
+            let is_quota_reached = refinement_ctx.environment.quota.as_ref().is_some_and(|quota| quota.is_reached());
+            if seeds.is_empty() || is_quota_reached {
+                break;
+            }
+
+            let subproblems = create_subproblems(&insertion_ctx, &seeds, self.subproblem_routes);
+            if subproblems.is_empty() {
+                break;
+            }
+
+            // NOTE make limit a bit higher than median
+            let median = refinement_ctx.statistics().speed.get_median();
+            let limit = median.map(|median| (median * self.repeat_count).max(self.quota_limit));
+            let environment = create_environment_with_custom_quota(limit, refinement_ctx.environment.as_ref());
+
+            let results = parallel_into_collect(subproblems, |(seed_idx, route_indices)| {
+                let (partial_ctx, route_indices) =
+                    create_partial_insertion_ctx(&insertion_ctx, environment.clone(), route_indices);
+                let improved = self.optimize(refinement_ctx.problem.clone(), environment.clone(), partial_ctx);
+
+                (seed_idx, route_indices, improved)
+            });
+
+            insertion_ctx = merge_subproblems(insertion_ctx, results, &mut seeds);
+        }
+
+        insertion_ctx
+    }
+}
+
+impl PopmusicSearch {
+    /// Optimizes subproblem using its own evolution. Returns a new solution if it is better.
+    fn optimize(
+        &self,
+        problem: Arc<Problem>,
+        environment: Arc<Environment>,
+        partial_ctx: InsertionContext,
+    ) -> Option<InsertionContext> {
+        let population = create_population(partial_ctx.deep_copy());
+        let mut refinement_ctx = RefinementContext::new(problem, population, TelemetryMode::None, environment);
+
+        for _ in 0..self.repeat_count {
+            let Some(insertion_ctx) = refinement_ctx.selected().next() else {
+                break;
+            };
+            let insertion_ctx = self.inner_search.search(&refinement_ctx, insertion_ctx);
+            refinement_ctx.add_solution(insertion_ctx);
+
+            if refinement_ctx.environment.quota.as_ref().is_some_and(|quota| quota.is_reached()) {
+                break;
+            }
+        }
+
+        let best = refinement_ctx
+            .ranked()
+            .next()
+            .filter(|best| partial_ctx.problem.goal.total_order(best, &partial_ctx) == Ordering::Less)
+            .map(|best| best.deep_copy());
+
+        best
+    }
+}
+
+/// Creates disjoint subproblems from seed routes and their nearest routes.
+/// Returns index of the seed route and indices of all subproblem routes.
+fn create_subproblems(
+    insertion_ctx: &InsertionContext,
+    seeds: &HashSet<Arc<Actor>>,
+    subproblem_routes: usize,
+) -> Vec<(usize, HashSet<usize>)> {
+    let Some(route_proximity) = group_routes_by_proximity(insertion_ctx) else {
+        return Vec::default();
+    };
+
+    let mut seed_indices = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| seeds.contains(&route_ctx.route().actor))
+        .map(|(route_idx, _)| route_idx)
+        .collect::<Vec<_>>();
+    seed_indices.shuffle(&mut insertion_ctx.environment.random.get_rng());
+
+    let mut used_indices = HashSet::new();
+
+    seed_indices
+        .into_iter()
+        .filter_map(|seed_idx| {
+            if used_indices.contains(&seed_idx) {
+                return None;
+            }
+
+            let route_indices = once(seed_idx)
+                .chain(
+                    route_proximity[seed_idx]
+                        .iter()
+                        .map(|(route_idx, _)| *route_idx)
+                        .filter(|route_idx| !used_indices.contains(route_idx)),
+                )
+                .take(subproblem_routes)
+                .collect::<HashSet<_>>();
+
+            used_indices.extend(route_indices.iter().copied());
+
+            Some((seed_idx, route_indices))
+        })
+        .collect()
+}
+
+/// Replaces routes of improved subproblems and updates seeds.
+fn merge_subproblems(
+    insertion_ctx: InsertionContext,
+    results: Vec<(usize, HashSet<usize>, Option<InsertionContext>)>,
+    seeds: &mut HashSet<Arc<Actor>>,
+) -> InsertionContext {
+    let mut insertion_ctx = insertion_ctx;
+    let solution = &mut insertion_ctx.solution;
+
+    let mut replaced_indices = HashSet::new();
+    let mut improved_ctxs = Vec::new();
+
+    results.into_iter().for_each(|(seed_idx, route_indices, improved_ctx)| match improved_ctx {
+        Some(improved_ctx) => {
+            replaced_indices.extend(route_indices);
+            improved_ctxs.push(improved_ctx);
+        }
+        None => {
+            seeds.remove(&solution.routes[seed_idx].route().actor);
+        }
+    });
+
+    if improved_ctxs.is_empty() {
+        return insertion_ctx;
+    }
+
+    let routes = std::mem::take(&mut solution.routes);
+    routes.into_iter().enumerate().for_each(|(route_idx, route_ctx)| {
+        if replaced_indices.contains(&route_idx) {
+            solution.registry.free_route(route_ctx);
+        } else {
+            solution.routes.push(route_ctx);
+        }
+    });
+
+    improved_ctxs.into_iter().for_each(|improved_ctx| {
+        let improved = improved_ctx.solution;
+
+        improved.routes.into_iter().for_each(|route_ctx| {
+            // NOTE subproblem uses only actors of its own routes
+            assert!(solution.registry.get_route(&route_ctx.route().actor).is_some());
+            seeds.insert(route_ctx.route().actor.clone());
+            solution.routes.push(route_ctx);
+        });
+
+        solution.required.extend(improved.required);
+        solution.unassigned.extend(improved.unassigned);
+    });
+
+    seeds.retain(|actor| solution.routes.iter().any(|route_ctx| route_ctx.route().actor == *actor));
+
+    insertion_ctx.restore();
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    insertion_ctx
+}
@@ -20,6 +20,9 @@ pub use self::recreate_with_blinks::RecreateWithBlinks;
 mod recreate_with_cheapest;
 pub use self::recreate_with_cheapest::RecreateWithCheapest;
 
+mod recreate_with_ejection_chain;
+pub use self::recreate_with_ejection_chain::RecreateWithEjectionChain;
+
 mod recreate_with_farthest;
 pub use self::recreate_with_farthest::RecreateWithFarthest;
 
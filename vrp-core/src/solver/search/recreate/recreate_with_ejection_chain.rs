@@ -0,0 +1,236 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/recreate/recreate_with_ejection_chain_test.rs"]
+mod recreate_with_ejection_chain_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::solver::search::get_route_jobs;
+use crate::solver::search::recreate::Recreate;
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A recreate method which tries to insert jobs left unassigned by inner recreate method using
+/// ejection chains: a job which cannot be inserted directly takes a place of one of its neighbours,
+/// and the ejected neighbour is inserted in the same way, up to a given chain depth.
+pub struct RecreateWithEjectionChain {
+    inner: Arc<dyn Recreate + Send + Sync>,
+    max_depth: usize,
+    max_ejections: usize,
+}
+
+impl RecreateWithEjectionChain {
+    /// Creates a new instance of `RecreateWithEjectionChain`.
+    /// `max_depth` specifies max amount of relocated jobs in the chain, `max_ejections` specifies
+    /// how many neighbours are tried to be ejected on each chain level.
+    pub fn new(inner: Arc<dyn Recreate + Send + Sync>, max_depth: usize, max_ejections: usize) -> Self {
+        Self { inner, max_depth, max_ejections }
+    }
+
+    /// Creates a new instance of `RecreateWithEjectionChain` with cheapest insertion as inner method.
+    pub fn new_with_defaults(random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self::new(Arc::new(super::RecreateWithCheapest::new(random)), 2, 8)
+    }
+}
+
+impl Recreate for RecreateWithEjectionChain {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = self.inner.run(refinement_ctx, insertion_ctx);
+
+        let problem_jobs = insertion_ctx.problem.jobs.all().collect::<HashSet<_>>();
+        let unassigned = insertion_ctx
+            .solution
+            .unassigned
+            .keys()
+            .filter(|job| problem_jobs.contains(*job) && !insertion_ctx.solution.ignored.contains(*job))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let chain_ctx = ChainContext { problem_jobs: &problem_jobs, max_ejections: self.max_ejections };
+        let mut route_jobs = get_route_jobs(&insertion_ctx.solution);
+
+        for job in unassigned {
+            if insertion_ctx.environment.quota.as_ref().is_some_and(|quota| quota.is_reached()) {
+                break;
+            }
+
+            // NOTE evaluation skips jobs which are unassigned with a concrete reason in unmodified routes
+            let Some(info) = insertion_ctx.solution.unassigned.remove(&job) else {
+                continue;
+            };
+            let ejections =
+                chain_ctx.find(&insertion_ctx, &route_jobs, &[], &job, self.max_depth, &HashSet::from([job.clone()]));
+            insertion_ctx.solution.unassigned.insert(job.clone(), info);
+
+            let new_ctx = ejections
+                .and_then(|ejections| chain_ctx.apply(&insertion_ctx, &job, ejections))
+                .filter(|new_ctx| insertion_ctx.problem.goal.total_order(&insertion_ctx, new_ctx) == Ordering::Greater);
+
+            if let Some(new_ctx) = new_ctx {
+                insertion_ctx = new_ctx;
+                route_jobs = get_route_jobs(&insertion_ctx.solution);
+            }
+        }
+
+        insertion_ctx
+    }
+}
+
+/// A job ejected from the route with given index.
+type Ejection = (Job, usize);
+
+struct ChainContext<'a> {
+    problem_jobs: &'a HashSet<Job>,
+    max_ejections: usize,
+}
+
+impl ChainContext<'_> {
+    /// Finds a chain of ejections which allows to insert the job: the job takes a place of the first ejected
+    /// neighbour, which takes a place of the next one and so on. The last ejected job (or the job itself if
+    /// the chain is empty) is inserted directly. Only copies of affected routes are modified, they are kept
+    /// in `changed` with their indices.
+    fn find(
+        &self,
+        insertion_ctx: &InsertionContext,
+        route_jobs: &HashMap<Job, usize>,
+        changed: &[(usize, &RouteContext)],
+        job: &Job,
+        depth: usize,
+        tabu: &HashSet<Job>,
+    ) -> Option<Vec<Ejection>> {
+        let get_route = |route_idx: usize| {
+            changed
+                .iter()
+                .find(|(changed_idx, _)| *changed_idx == route_idx)
+                .map(|(_, route_ctx)| *route_ctx)
+                .or_else(|| insertion_ctx.solution.routes.get(route_idx))
+        };
+
+        let routes = (0..insertion_ctx.solution.routes.len())
+            .filter_map(get_route)
+            .chain(insertion_ctx.solution.registry.next_route());
+        if evaluate_insertion(insertion_ctx, job, routes).as_success().is_some() {
+            return Some(Vec::default());
+        }
+
+        if depth == 0 {
+            return None;
+        }
+
+        self.get_ejection_candidates(insertion_ctx, route_jobs, job, tabu).into_iter().find_map(
+            |(ejected, route_idx)| {
+                let goal = insertion_ctx.problem.goal.as_ref();
+
+                let mut route_ctx = get_route(route_idx)?.deep_copy();
+                if !route_ctx.route_mut().tour.remove(&ejected) {
+                    return None;
+                }
+                goal.accept_route_state(&mut route_ctx);
+
+                let success = match evaluate_insertion(insertion_ctx, job, std::iter::once(&route_ctx)) {
+                    InsertionResult::Success(success) => success,
+                    InsertionResult::Failure(_) => return None,
+                };
+                let route = route_ctx.route_mut();
+                success.activities.into_iter().for_each(|(activity, idx)| {
+                    route.tour.insert_at(activity, idx + 1);
+                });
+                goal.accept_route_state(&mut route_ctx);
+
+                let changed = changed
+                    .iter()
+                    .filter(|(changed_idx, _)| *changed_idx != route_idx)
+                    .copied()
+                    .chain(std::iter::once((route_idx, &route_ctx)))
+                    .collect::<Vec<_>>();
+                let tabu = tabu.iter().cloned().chain(std::iter::once(ejected.clone())).collect();
+
+                self.find(insertion_ctx, route_jobs, changed.as_slice(), &ejected, depth - 1, &tabu)
+                    .map(|ejections| std::iter::once((ejected.clone(), route_idx)).chain(ejections).collect())
+            },
+        )
+    }
+
+    /// Applies the chain of ejections to a copy of the solution. Insertions are evaluated again as
+    /// the chain is found using copies of affected routes only.
+    fn apply(&self, insertion_ctx: &InsertionContext, job: &Job, ejections: Vec<Ejection>) -> Option<InsertionContext> {
+        let mut new_ctx = insertion_ctx.deep_copy();
+        new_ctx.solution.unassigned.remove(job);
+
+        let job = ejections.into_iter().try_fold(job.clone(), |job, (ejected, route_idx)| {
+            let route_ctx = new_ctx.solution.routes.get_mut(route_idx)?;
+            if !route_ctx.route_mut().tour.remove(&ejected) {
+                return None;
+            }
+            new_ctx.problem.goal.accept_route_state(route_ctx);
+
+            match evaluate_insertion(&new_ctx, &job, std::iter::once(&new_ctx.solution.routes[route_idx])) {
+                InsertionResult::Success(success) => apply_insertion_success(&mut new_ctx, success),
+                InsertionResult::Failure(_) => return None,
+            }
+
+            Some(ejected)
+        })?;
+
+        let routes = new_ctx.solution.routes.iter().chain(new_ctx.solution.registry.next_route());
+        match evaluate_insertion(&new_ctx, &job, routes) {
+            InsertionResult::Success(success) => apply_insertion_success(&mut new_ctx, success),
+            InsertionResult::Failure(_) => return None,
+        }
+
+        finalize_insertion_ctx(&mut new_ctx);
+
+        Some(new_ctx)
+    }
+
+    /// Returns assigned neighbours of the job which can be ejected with their route indices.
+    fn get_ejection_candidates(
+        &self,
+        insertion_ctx: &InsertionContext,
+        route_jobs: &HashMap<Job, usize>,
+        job: &Job,
+        tabu: &HashSet<Job>,
+    ) -> Vec<Ejection> {
+        if !self.problem_jobs.contains(job) {
+            return Vec::default();
+        }
+
+        let solution = &insertion_ctx.solution;
+        let mut visited = HashSet::new();
+
+        insertion_ctx
+            .problem
+            .fleet
+            .profiles
+            .iter()
+            .flat_map(|profile| insertion_ctx.problem.jobs.neighbors(profile, job, 0.))
+            .filter(|(other, _)| !tabu.contains(*other) && !solution.locked.contains(*other))
+            .filter(|(other, _)| self.problem_jobs.contains(*other) && visited.insert((*other).clone()))
+            .filter_map(|(other, _)| route_jobs.get(other).map(|route_idx| (other.clone(), *route_idx)))
+            .take(self.max_ejections)
+            .collect()
+    }
+}
+
+/// Evaluates insertion of the job into given routes.
+fn evaluate_insertion<'a>(
+    insertion_ctx: &InsertionContext,
+    job: &Job,
+    routes: impl Iterator<Item = &'a RouteContext>,
+) -> InsertionResult {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    routes.fold(InsertionResult::make_failure(), |acc, route_ctx| {
+        eval_job_insertion_in_route(insertion_ctx, &eval_ctx, route_ctx, InsertionPosition::Any, acc)
+    })
+}
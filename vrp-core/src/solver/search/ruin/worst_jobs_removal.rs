@@ -103,7 +103,11 @@ fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(Profile, Ve
             })
             .drain()
             .collect();
-        savings.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Less));
+        // NOTE use job position to break ties as hash map iteration order is not deterministic
+        let jobs = &insertion_ctx.problem.jobs;
+        savings.sort_by(|(a_job, a), (b_job, b)| {
+            b.partial_cmp(a).unwrap_or(Less).then_with(|| jobs.position(a_job).cmp(&jobs.position(b_job)))
+        });
 
         (route_ctx.route().actor.vehicle.profile.clone(), savings)
     })
@@ -0,0 +1,137 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/selective_route_exchange_test.rs"]
+mod selective_route_exchange_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::models::GoalContext;
+use rosomaxa::prelude::*;
+use rosomaxa::utils::SelectionSamplingIterator;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// A crossover operator which implements selective route exchange (SREX) idea: it takes some routes
+/// from the second parent and uses them to replace the most similar routes of the first parent.
+/// Jobs which are left unassigned after exchange are inserted back using recreate method.
+pub struct SelectiveRouteExchange {
+    recreate: Arc<dyn Recreate + Send + Sync>,
+    max_routes: usize,
+}
+
+impl SelectiveRouteExchange {
+    /// Creates a new instance of `SelectiveRouteExchange`.
+    pub fn new(recreate: Arc<dyn Recreate + Send + Sync>, max_routes: usize) -> Self {
+        assert!(max_routes > 0);
+
+        Self { recreate, max_routes }
+    }
+}
+
+impl HeuristicCrossoverOperator for SelectiveRouteExchange {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn crossover(
+        &self,
+        heuristic_ctx: &Self::Context,
+        first: &Self::Solution,
+        second: &Self::Solution,
+    ) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+        let mut child = first.deep_copy();
+
+        let max_routes = self.max_routes.min(child.solution.routes.len()).min(second.solution.routes.len());
+        if max_routes == 0 {
+            return child;
+        }
+
+        let random = child.environment.random.clone();
+        let amount = random.uniform_int(1, max_routes as i32) as usize;
+
+        let sequences = SelectionSamplingIterator::new(second.solution.routes.iter(), amount, random)
+            .map(|route_ctx| get_movable_jobs(&child, route_ctx))
+            .filter(|jobs| !jobs.is_empty())
+            .collect::<Vec<_>>();
+
+        let route_pairs = remove_exchanged_jobs(&mut child, sequences.as_slice());
+        route_pairs.into_iter().zip(sequences.iter()).for_each(|(route_idx, jobs)| {
+            insert_sequence(&mut child, route_idx, jobs.as_slice());
+        });
+
+        let mut child = self.recreate.run(refinement_ctx, child);
+        finalize_insertion_ctx(&mut child);
+        child.solution.remove_empty_routes();
+
+        child
+    }
+}
+
+/// Returns jobs of the route in the order of their first appearance, excluding locked ones.
+fn get_movable_jobs(insertion_ctx: &InsertionContext, route_ctx: &RouteContext) -> Vec<Job> {
+    let mut visited = HashSet::new();
+
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .filter(|job| !insertion_ctx.solution.locked.contains(job))
+        .filter(|job| visited.insert(job.clone()))
+        .collect()
+}
+
+/// Removes exchanged jobs from all routes and clears the most similar route for each sequence.
+/// Returns indices of cleared routes in the same order as sequences.
+fn remove_exchanged_jobs(insertion_ctx: &mut InsertionContext, sequences: &[Vec<Job>]) -> Vec<usize> {
+    let mut route_pairs: Vec<usize> = Vec::with_capacity(sequences.len());
+
+    for jobs in sequences.iter() {
+        let jobs = jobs.iter().collect::<HashSet<_>>();
+        let route_idx = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(route_idx, _)| !route_pairs.contains(route_idx))
+            .map(|(route_idx, route_ctx)| {
+                (route_idx, route_ctx.route().tour.jobs().filter(|job| jobs.contains(job)).count())
+            })
+            // NOTE prefer the first route in case of the same overlap
+            .min_by(|(a_idx, a_overlap), (b_idx, b_overlap)| b_overlap.cmp(a_overlap).then(a_idx.cmp(b_idx)))
+            .map(|(route_idx, _)| route_idx);
+
+        if let Some(route_idx) = route_idx {
+            route_pairs.push(route_idx);
+        }
+    }
+
+    let exchanged = sequences.iter().flat_map(|jobs| jobs.iter()).cloned().collect::<HashSet<_>>();
+    let solution = &mut insertion_ctx.solution;
+
+    for (route_idx, route_ctx) in solution.routes.iter_mut().enumerate() {
+        let is_paired = route_pairs.contains(&route_idx);
+        let removed = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter(|job| !solution.locked.contains(*job))
+            .filter(|job| is_paired || exchanged.contains(*job))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if removed.is_empty() {
+            continue;
+        }
+
+        removed.iter().for_each(|job| {
+            route_ctx.route_mut().tour.remove(job);
+        });
+        solution.required.extend(removed);
+
+        insertion_ctx.problem.goal.accept_route_state(route_ctx);
+    }
+
+    route_pairs
+}
@@ -0,0 +1,287 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/set_partitioning_search_test.rs"]
+mod set_partitioning_search_test;
+
+use super::*;
+use crate::algorithms::covering::set_partitioning::*;
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use crate::models::{GoalContext, Problem};
+use rosomaxa::prelude::*;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// A search operator which keeps a pool of routes discovered in accepted solutions and periodically
+/// recombines them: it selects the cheapest combination of pooled routes which serves the same jobs as
+/// the given solution by solving a set partitioning problem. This idea is known as route-based matheuristic.
+pub struct SetPartitioningSearch {
+    recreate: Arc<dyn Recreate + Send + Sync>,
+    pool: Mutex<RoutePool>,
+    pool_size: usize,
+    max_solutions: usize,
+    max_nodes: usize,
+}
+
+impl SetPartitioningSearch {
+    /// Creates a new instance of `SetPartitioningSearch`.
+    /// `pool_size` specifies max amount of routes kept in the pool, `max_solutions` specifies how many
+    /// best known solutions are used to collect routes, `max_nodes` limits branch and bound search.
+    pub fn new(
+        recreate: Arc<dyn Recreate + Send + Sync>,
+        pool_size: usize,
+        max_solutions: usize,
+        max_nodes: usize,
+    ) -> Self {
+        assert!(pool_size > 0);
+        assert!(max_nodes > 0);
+
+        Self { recreate, pool: Mutex::new(RoutePool::default()), pool_size, max_solutions, max_nodes }
+    }
+
+    /// Creates a new instance of `SetPartitioningSearch` with default parameters.
+    pub fn new_with_defaults(random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self::new(Arc::new(RecreateWithCheapest::new(random)), 2000, 4, 10000)
+    }
+}
+
+impl HeuristicSearchOperator for SetPartitioningSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+        let insertion_ctx = solution;
+        let pool_index = PoolIndex::new(insertion_ctx.problem.as_ref());
+
+        let pooled = {
+            let mut pool = self.pool.lock().expect("cannot lock route pool");
+
+            std::iter::once(insertion_ctx)
+                .chain(refinement_ctx.ranked().take(self.max_solutions))
+                .for_each(|solution| pool.add(&pool_index, solution));
+            pool.shrink(self.pool_size);
+
+            // NOTE keep columns order stable to have reproducible results
+            let mut pooled = pool.routes.values().cloned().collect::<Vec<_>>();
+            pooled.sort_by(|a, b| (&a.job_indices, a.group).cmp(&(&b.job_indices, b.group)));
+
+            pooled
+        };
+
+        self.recombine(refinement_ctx, insertion_ctx, &pool_index, pooled.as_slice())
+            .unwrap_or_else(|| insertion_ctx.deep_copy())
+    }
+}
+
+impl SetPartitioningSearch {
+    fn recombine(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+        pool_index: &PoolIndex,
+        pooled: &[PoolRoute],
+    ) -> Option<InsertionContext> {
+        // NOTE routes with locked jobs or jobs unknown to the pool are kept as is
+        let free_routes = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route_ctx)| {
+                route_ctx
+                    .route()
+                    .tour
+                    .jobs()
+                    .all(|job| !insertion_ctx.solution.locked.contains(job) && pool_index.jobs.contains_key(job))
+            })
+            .map(|(route_idx, _)| route_idx)
+            .collect::<HashSet<_>>();
+
+        let items = free_routes
+            .iter()
+            .flat_map(|&route_idx| insertion_ctx.solution.routes[route_idx].route().tour.jobs())
+            .filter_map(|job| pool_index.jobs.get(job).copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .enumerate()
+            .map(|(item, job_idx)| (job_idx, item))
+            .collect::<HashMap<_, _>>();
+
+        let (columns, column_routes): (Vec<_>, Vec<_>) = pooled
+            .iter()
+            .filter_map(|pool_route| {
+                let column_items = pool_route
+                    .job_indices
+                    .iter()
+                    .map(|job_idx| items.get(job_idx).copied())
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some((Column { items: column_items, group: pool_route.group, cost: pool_route.cost }, pool_route))
+            })
+            .unzip();
+
+        let upper_bound = free_routes.iter().try_fold(Cost::default(), |acc, &route_idx| {
+            insertion_ctx.solution.routes[route_idx].get_route_cost().map(|cost| acc + cost)
+        })?;
+
+        let group_limits =
+            insertion_ctx.problem.fleet.groups.iter().fold(HashMap::new(), |mut acc, (group, actors)| {
+                let used = insertion_ctx
+                    .solution
+                    .routes
+                    .iter()
+                    .enumerate()
+                    .filter(|(route_idx, route_ctx)| {
+                        !free_routes.contains(route_idx) && actors.contains(&route_ctx.route().actor)
+                    })
+                    .count();
+                acc.insert(*group, actors.len().saturating_sub(used));
+                acc
+            });
+
+        let problem =
+            SetPartitioningProblem { items: items.len(), columns: columns.as_slice(), group_limits: &group_limits };
+        let selected = solve_set_partitioning(&problem, upper_bound, self.max_nodes)?;
+
+        let mut new_ctx = insertion_ctx.deep_copy();
+        let routes = std::mem::take(&mut new_ctx.solution.routes);
+        routes.into_iter().enumerate().for_each(|(route_idx, route_ctx)| {
+            if free_routes.contains(&route_idx) {
+                new_ctx.solution.required.extend(route_ctx.route().tour.jobs().cloned());
+                new_ctx.solution.registry.free_route(route_ctx);
+            } else {
+                new_ctx.solution.routes.push(route_ctx);
+            }
+        });
+
+        selected.into_iter().map(|column_idx| column_routes[column_idx]).for_each(|pool_route| {
+            let route_ctx = new_ctx.solution.registry.get_route(pool_route.actor.as_ref()).or_else(|| {
+                let actor = new_ctx
+                    .solution
+                    .registry
+                    .resources()
+                    .available()
+                    .find(|actor| pool_index.groups.get(actor) == Some(&pool_route.group))?;
+                new_ctx.solution.registry.get_route(actor.as_ref())
+            });
+
+            if let Some(route_ctx) = route_ctx {
+                let route_idx = new_ctx.solution.routes.len();
+                new_ctx.solution.routes.push(route_ctx);
+                insert_sequence(&mut new_ctx, route_idx, pool_route.jobs.as_slice());
+            }
+        });
+
+        let mut new_ctx = self.recreate.run(refinement_ctx, new_ctx);
+        finalize_insertion_ctx(&mut new_ctx);
+        new_ctx.solution.remove_empty_routes();
+
+        Some(new_ctx)
+    }
+}
+
+/// Keeps indices of problem jobs and fleet groups.
+struct PoolIndex {
+    jobs: HashMap<Job, usize>,
+    groups: HashMap<Arc<Actor>, usize>,
+}
+
+impl PoolIndex {
+    fn new(problem: &Problem) -> Self {
+        let jobs = problem.jobs.all().enumerate().map(|(job_idx, job)| (job, job_idx)).collect();
+        let groups = problem
+            .fleet
+            .groups
+            .iter()
+            .flat_map(|(group, actors)| actors.iter().map(move |actor| (actor.clone(), *group)))
+            .collect();
+
+        Self { jobs, groups }
+    }
+}
+
+/// Represents a route stored in the pool.
+#[derive(Clone)]
+struct PoolRoute {
+    jobs: Vec<Job>,
+    job_indices: Vec<usize>,
+    actor: Arc<Actor>,
+    group: usize,
+    cost: Cost,
+    generation: usize,
+}
+
+/// Keeps the cheapest known routes for each unique combination of jobs and actor group.
+#[derive(Default)]
+struct RoutePool {
+    routes: HashMap<(Vec<usize>, usize), PoolRoute>,
+    generation: usize,
+}
+
+impl RoutePool {
+    /// Adds routes of the solution to the pool.
+    fn add(&mut self, pool_index: &PoolIndex, insertion_ctx: &InsertionContext) {
+        self.generation += 1;
+        let generation = self.generation;
+
+        insertion_ctx.solution.routes.iter().for_each(|route_ctx| {
+            let mut visited = HashSet::new();
+            let jobs = route_ctx
+                .route()
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job())
+                .filter(|job| visited.insert(job.clone()))
+                .collect::<Vec<_>>();
+
+            let job_indices = jobs.iter().map(|job| pool_index.jobs.get(job).copied()).collect::<Option<Vec<_>>>();
+            let group = pool_index.groups.get(&route_ctx.route().actor).copied();
+            let cost = route_ctx.get_route_cost();
+
+            let (Some(mut job_indices), Some(group), Some(cost)) = (job_indices, group, cost) else {
+                return;
+            };
+
+            if jobs.is_empty() {
+                return;
+            }
+
+            job_indices.sort_unstable();
+
+            let pool_route = PoolRoute {
+                jobs,
+                job_indices: job_indices.clone(),
+                actor: route_ctx.route().actor.clone(),
+                group,
+                cost,
+                generation,
+            };
+
+            self.routes
+                .entry((job_indices, group))
+                .and_modify(|existing| {
+                    existing.generation = generation;
+                    if pool_route.cost < existing.cost {
+                        *existing = pool_route.clone();
+                    }
+                })
+                .or_insert(pool_route);
+        });
+    }
+
+    /// Removes the least recently seen routes to keep the pool within the given size.
+    fn shrink(&mut self, pool_size: usize) {
+        if self.routes.len() <= pool_size {
+            return;
+        }
+
+        let mut keys = self.routes.iter().map(|(key, route)| (key.clone(), route.generation)).collect::<Vec<_>>();
+        keys.sort_by(|(a_key, a_gen), (b_key, b_gen)| a_gen.cmp(b_gen).then_with(|| a_key.cmp(b_key)));
+
+        keys.into_iter().take(self.routes.len() - pool_size).for_each(|(key, _)| {
+            self.routes.remove(&key);
+        });
+    }
+}
@@ -0,0 +1,32 @@
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+
+/// Inserts jobs into the route keeping their order. Jobs which cannot be inserted are left as required.
+pub(crate) fn insert_sequence(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: &[Job]) {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let mut leg_idx = 0;
+
+    // NOTE multi jobs are inserted later by recreate method
+    for job in jobs.iter().filter(|job| matches!(job, Job::Single(_))) {
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let result = eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            &insertion_ctx.solution.routes[route_idx],
+            InsertionPosition::Concrete(leg_idx),
+            InsertionResult::make_failure(),
+        );
+
+        if let InsertionResult::Success(success) = result {
+            apply_insertion_success(insertion_ctx, success);
+            leg_idx += 1;
+        }
+    }
+}
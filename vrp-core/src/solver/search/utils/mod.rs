@@ -1,3 +1,6 @@
+mod insertion;
+pub(crate) use self::insertion::*;
+
 mod removal;
 pub(crate) use self::removal::*;
 
@@ -11,8 +11,8 @@ custom_solution_state!(TabuList typeof TabuList);
 /// A simple solution's tabu list to keep track of recently affected jobs and actors.
 #[derive(Clone)]
 pub struct TabuList {
-    actors: HashSet<Arc<Actor>>,
-    jobs: HashSet<Job>,
+    actors: TabuItems<Arc<Actor>>,
+    jobs: TabuItems<Job>,
     max_actors: usize,
     max_jobs: usize,
     random: Arc<dyn Random + Send + Sync>,
@@ -31,12 +31,12 @@ impl TabuList {
 
     /// Checks whether given an actor is in the tabu list.
     pub fn is_actor_tabu(&self, actor: &Actor) -> bool {
-        self.actors.contains(actor)
+        self.actors.index.contains(actor)
     }
 
     /// Checks whether given a job is in the tabu list.
     pub fn is_job_tabu(&self, job: &Job) -> bool {
-        self.jobs.contains(job)
+        self.jobs.index.contains(job)
     }
 
     /// Stores tabu list in insertion ctx.
@@ -73,22 +73,37 @@ impl From<&InsertionContext> for TabuList {
     }
 }
 
+/// Keeps tabu items in insertion order to make random eviction independent from hash set iteration order.
+#[derive(Clone)]
+struct TabuItems<T> {
+    index: HashSet<T>,
+    items: Vec<T>,
+}
+
+impl<T> Default for TabuItems<T> {
+    fn default() -> Self {
+        Self { index: HashSet::default(), items: Vec::default() }
+    }
+}
+
 fn add_with_limits<T: Clone + Eq + PartialEq + Hash>(
     new_item: T,
-    old_items: &mut HashSet<T>,
+    old_items: &mut TabuItems<T>,
     limits: usize,
     random: &(dyn Random + Send + Sync),
 ) {
     // NOTE do not use tabu list when limit is zero
-    if limits == 0 {
+    if limits == 0 || old_items.index.contains(&new_item) {
         return;
     }
 
-    if old_items.len() == limits {
-        if let Some(item) = old_items.iter().choose(&mut random.get_rng()).cloned() {
-            old_items.remove(&item);
+    if old_items.items.len() >= limits {
+        if let Some(idx) = (0..old_items.items.len()).choose(&mut random.get_rng()) {
+            let item = old_items.items.swap_remove(idx);
+            old_items.index.remove(&item);
         }
     }
 
-    old_items.insert(new_item);
+    old_items.index.insert(new_item.clone());
+    old_items.items.push(new_item);
 }
@@ -13,6 +13,7 @@ pub fn test_random() -> Arc<dyn Random + Send + Sync> {
 
 pub struct TestGoalContextBuilder {
     features: Vec<Feature>,
+    cost_feature: Option<String>,
 }
 
 impl Default for TestGoalContextBuilder {
@@ -23,13 +24,14 @@ impl Default for TestGoalContextBuilder {
                 .with_objective(TestObjective)
                 .build()
                 .unwrap()],
+            cost_feature: None,
         }
     }
 }
 
 impl TestGoalContextBuilder {
     pub fn empty() -> Self {
-        Self { features: vec![] }
+        Self { features: vec![], cost_feature: None }
     }
 
     pub fn with_transport_feature() -> Self {
@@ -40,6 +42,7 @@ impl TestGoalContextBuilder {
                 .set_activity_cost(TestActivityCost::new_shared())
                 .build_minimize_cost()
                 .unwrap()],
+            cost_feature: Some("transport".to_string()),
         }
     }
 
@@ -53,11 +56,19 @@ impl TestGoalContextBuilder {
         self
     }
 
+    pub fn set_cost_feature(mut self, name: &str) -> Self {
+        self.cost_feature = Some(name.to_string());
+        self
+    }
+
     pub fn build(self) -> GoalContext {
-        GoalContextBuilder::with_features(&self.features)
-            .expect("cannot create builder")
-            .build()
-            .expect("cannot build context")
+        let builder = GoalContextBuilder::with_features(&self.features).expect("cannot create builder");
+        let builder = match self.cost_feature {
+            Some(name) => builder.set_cost_feature(name.as_str()),
+            None => builder,
+        };
+
+        builder.build().expect("cannot build context")
     }
 }
 
@@ -43,6 +43,7 @@ pub fn generate_matrix_routes_with_defaults(rows: usize, cols: usize, is_open_vr
                         .build_minimize_cost()
                         .unwrap(),
                 )
+                .set_cost_feature("transport")
                 .build()
         },
         |id, location| TestSingleBuilder::default().id(id).location(location).build_shared(),
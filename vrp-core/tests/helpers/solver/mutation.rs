@@ -1,7 +1,9 @@
 use super::*;
 use crate::construction::heuristics::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
 use crate::models::common::Schedule;
 use crate::models::solution::{Activity, Place};
+use crate::solver::search::LocalOperator;
 use std::collections::HashMap;
 
 /// Promotes given job ids to locked in given context.
@@ -108,3 +110,26 @@ pub fn rearrange_jobs_in_routes(insertion_ctx: &mut InsertionContext, job_order:
 
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
 }
+
+/// Runs local operator on matrix routes rearranged in given order and returns customer ids of improved
+/// solution. Routes are open and problem uses the same transport as goal to have consistent job neighbourhood and distances.
+pub fn explore_with_local_operator(
+    operator: &(dyn LocalOperator + Send + Sync),
+    matrix: (usize, usize),
+    jobs_order: Vec<Vec<&str>>,
+    locked_ids: &[&str],
+) -> Option<Vec<Vec<String>>> {
+    let (problem, solution) = generate_matrix_routes_with_disallow_list(matrix.0, matrix.1, true, vec![]);
+    let transport = TestTransportCost::new_shared();
+    let jobs = Jobs::new(problem.fleet.as_ref(), problem.jobs.all().collect(), transport.as_ref());
+    let problem = Problem { jobs: Arc::new(jobs), transport, ..problem };
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids,
+    );
+    rearrange_jobs_in_routes(&mut insertion_ctx, jobs_order.as_slice());
+
+    operator
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .map(|insertion_ctx| get_customer_ids_from_routes(&insertion_ctx))
+}
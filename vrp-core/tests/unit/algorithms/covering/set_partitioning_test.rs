@@ -0,0 +1,48 @@
+use super::*;
+
+fn create_columns(data: &[(&[usize], usize, f64)]) -> Vec<Column> {
+    data.iter().map(|(items, group, cost)| Column { items: items.to_vec(), group: *group, cost: *cost }).collect()
+}
+
+fn solve(columns: &[(&[usize], usize, f64)], group_limits: &[(usize, usize)], upper_bound: f64) -> Option<Vec<usize>> {
+    let columns = create_columns(columns);
+    let group_limits = group_limits.iter().cloned().collect::<HashMap<_, _>>();
+    let problem = SetPartitioningProblem { items: 4, columns: columns.as_slice(), group_limits: &group_limits };
+
+    solve_set_partitioning(&problem, upper_bound, 1000).map(|mut selected| {
+        selected.sort();
+        selected
+    })
+}
+
+const COLUMNS: &[(&[usize], usize, f64)] =
+    &[(&[0, 1], 0, 2.), (&[2, 3], 0, 2.), (&[0, 1, 2, 3], 1, 3.), (&[0], 0, 1.), (&[1, 2, 3], 0, 5.)];
+
+parameterized_test! {can_solve_set_partitioning_problem, (group_limits, upper_bound, expected), {
+    can_solve_set_partitioning_problem_impl(group_limits, upper_bound, expected);
+}}
+
+can_solve_set_partitioning_problem! {
+    case01_unlimited: (&[], f64::MAX, Some(vec![2])),
+    case02_limited_group: (&[(1, 0)], f64::MAX, Some(vec![0, 1])),
+    case03_limited_both_groups: (&[(0, 1), (1, 0)], f64::MAX, None),
+    case04_no_better_than_bound: (&[], 3., None),
+    case05_better_than_bound: (&[(1, 0)], 6., Some(vec![0, 1])),
+}
+
+fn can_solve_set_partitioning_problem_impl(
+    group_limits: &[(usize, usize)],
+    upper_bound: f64,
+    expected: Option<Vec<usize>>,
+) {
+    let result = solve(COLUMNS, group_limits, upper_bound);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_none_when_item_is_not_covered() {
+    let result = solve(&[(&[0, 1], 0, 1.), (&[2], 0, 1.)], &[], f64::MAX);
+
+    assert!(result.is_none());
+}
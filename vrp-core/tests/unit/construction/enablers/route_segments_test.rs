@@ -0,0 +1,98 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::construction::heuristics::RouteContext;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route_ctx(activities: &[(Location, (f64, f64))]) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(activities.iter().map(|&(location, (start, end))| {
+                    ActivityBuilder::with_location_and_tw(location, TimeWindow::new(start, end)).build()
+                }))
+                .build(),
+        )
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+parameterized_test! {can_merge_time_segments, (left_tw, right_tw, expected), {
+    can_merge_time_segments_impl(left_tw, right_tw, expected);
+}}
+
+can_merge_time_segments! {
+    case01_no_waiting: ((0., 100.), (0., 100.), (25., 0., 0., 80.)),
+    case02_shifted_start: ((0., 100.), (50., 100.), (25., 0., 30., 80.)),
+    case03_waiting: ((0., 10.), (50., 100.), (45., 0., 10., 10.)),
+    case04_time_warp: ((0., 100.), (0., 10.), (25., 10., 0., 0.)),
+}
+
+fn can_merge_time_segments_impl(left_tw: (f64, f64), right_tw: (f64, f64), expected: (f64, f64, f64, f64)) {
+    let left = TimeSegment::new(0, &TimeWindow::new(left_tw.0, left_tw.1), 10.);
+    let right = TimeSegment::new(1, &TimeWindow::new(right_tw.0, right_tw.1), 5.);
+
+    let result = left.merge(&right, 10.);
+
+    assert_eq!((result.first, result.last), (0, 1));
+    assert_eq!((result.duration, result.time_warp, result.earliest, result.latest), expected);
+}
+
+#[test]
+fn can_get_prefix_and_suffix_of_route_segments() {
+    let segments = RouteSegments::new(vec![1, 2, 3], |left, right| left + right);
+
+    assert_eq!(
+        (0..4).map(|idx| segments.prefix(idx).copied()).collect::<Vec<_>>(),
+        vec![Some(1), Some(3), Some(6), None]
+    );
+    assert_eq!(
+        (0..4).map(|idx| segments.suffix(idx).copied()).collect::<Vec<_>>(),
+        vec![Some(6), Some(5), Some(3), None]
+    );
+    assert_eq!(segments.total(), Some(&6));
+}
+
+#[test]
+fn can_create_route_time_segments() {
+    let route_ctx = create_route_ctx(&[(10, (0., 100.)), (20, (50., 100.)), (30, (0., 100.))]);
+    let tour = &route_ctx.route().tour;
+    let expected_duration = tour.end().unwrap().schedule.arrival - tour.start().unwrap().schedule.departure;
+
+    let segments = create_route_time_segments(route_ctx.route(), &TestTransportCost::default());
+    let total = segments.total().unwrap();
+
+    assert_eq!(total.duration, expected_duration);
+    assert_eq!(total.time_warp, 0.);
+}
+
+parameterized_test! {can_evaluate_reordered_route_using_time_segments, (activities, expected_time_warp), {
+    can_evaluate_reordered_route_using_time_segments_impl(activities, expected_time_warp);
+}}
+
+can_evaluate_reordered_route_using_time_segments! {
+    case01_no_violation: (&[(10, (0., 100.)), (20, (0., 100.))], 0.),
+    case02_violation: (&[(10, (0., 15.)), (20, (0., 25.))], 15.),
+}
+
+fn can_evaluate_reordered_route_using_time_segments_impl(
+    activities: &[(Location, (f64, f64))],
+    expected_time_warp: f64,
+) {
+    let route_ctx = create_route_ctx(activities);
+    let segments = create_route_time_segments(route_ctx.route(), &TestTransportCost::default());
+    let activity_segment = |idx: usize| TimeSegment::from_activity(route_ctx.route().tour.get(idx).unwrap());
+    let (first, second) = (activity_segment(1), activity_segment(2));
+    let profile = &route_ctx.route().actor.vehicle.profile;
+
+    let result = evaluate_time_segments(
+        profile,
+        &TestTransportCost::default(),
+        [segments.prefix(0).unwrap(), &second, &first, segments.suffix(3).unwrap()],
+    )
+    .unwrap();
+
+    assert_eq!(result.time_warp, expected_time_warp);
+}
@@ -176,6 +176,41 @@ mod global_estimation {
         assert_eq!(fitness, 30.)
     }
 
+    #[test]
+    fn can_get_solution_fitness_with_job_weights() {
+        let objective = FastServiceFeatureBuilder::new("fast_service")
+            .set_transport(TestTransportCost::new_shared())
+            .set_activity(TestActivityCost::new_shared())
+            .set_demand_type_fn(|single| {
+                single.dimens.get_job_demand().map(|demand: &Demand<SingleDimLoad>| demand.get_type())
+            })
+            .set_job_weight_fn(|job| {
+                let is_heavy = job.as_single().and_then(|single| single.places.first()).and_then(|p| p.location);
+                if is_heavy == Some(20) {
+                    2.
+                } else {
+                    1.
+                }
+            })
+            .build()
+            .unwrap()
+            .objective
+            .expect("no objective");
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .add_activity(ActivityBuilder::with_location(10).build())
+                    .add_activity(ActivityBuilder::with_location(20).build())
+                    .build(),
+            )
+            .build();
+        let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+        let fitness = objective.fitness(&insertion_ctx);
+
+        assert_eq!(fitness, 50.)
+    }
+
     #[test]
     fn can_get_solution_fitness_with_reload() {
         let reload_filter_enabled = true;
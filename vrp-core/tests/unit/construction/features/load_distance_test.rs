@@ -0,0 +1,112 @@
+use super::*;
+use crate::helpers::construction::features::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, SingleDimLoad};
+use crate::models::solution::Activity;
+
+fn create_features() -> (Feature, Feature) {
+    let capacity = CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build().unwrap();
+    let load_distance = create_minimize_load_distance_feature::<SingleDimLoad>(
+        "load_distance",
+        TestTransportCost::new_shared(),
+        |load| load.value as Cost,
+    )
+    .unwrap();
+
+    (capacity, load_distance)
+}
+
+fn create_activity(location: Location, demand: Option<Demand<SingleDimLoad>>) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location));
+    if let Some(demand) = demand {
+        builder.demand(demand);
+    }
+
+    ActivityBuilder::with_location(location).job(Some(builder.build_shared())).build()
+}
+
+fn accept_route_state(features: &(Feature, Feature), route_ctx: &mut RouteContext) {
+    features.0.state.as_ref().unwrap().accept_route_state(route_ctx);
+    features.1.state.as_ref().unwrap().accept_route_state(route_ctx);
+}
+
+fn create_route_ctx(features: &(Feature, Feature), activities: Vec<(Location, i32)>) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(
+                    activities
+                        .into_iter()
+                        .map(|(location, demand)| create_activity(location, Some(create_simple_demand(demand)))),
+                )
+                .build(),
+        )
+        .build();
+    accept_route_state(features, &mut route_ctx);
+
+    route_ctx
+}
+
+fn get_fitness(features: &(Feature, Feature), route_ctx: RouteContext) -> Cost {
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    features.1.objective.as_ref().unwrap().fitness(&insertion_ctx)
+}
+
+parameterized_test! {can_calculate_fitness, (activities, expected), {
+    can_calculate_fitness_impl(activities, expected);
+}}
+
+can_calculate_fitness! {
+    case_01_deliveries: (vec![(10, -2), (20, -3)], 80.),
+    case_02_pickups: (vec![(10, 2), (20, 3)], 2. * 10. + 5. * 20.),
+    case_03_mixed: (vec![(10, -2), (20, 3)], 2. * 10. + 3. * 20.),
+    case_04_empty: (vec![], 0.),
+}
+
+fn can_calculate_fitness_impl(activities: Vec<(Location, i32)>, expected: Cost) {
+    let features = create_features();
+    let route_ctx = create_route_ctx(&features, activities);
+
+    let result = get_fitness(&features, route_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_activity_insertion_as_fitness_change, (index, location, demand), {
+    can_estimate_activity_insertion_as_fitness_change_impl(index, location, demand);
+}}
+
+can_estimate_activity_insertion_as_fitness_change! {
+    case_01_delivery_first: (0, 5, Some(-4)),
+    case_02_delivery_middle: (1, 15, Some(-4)),
+    case_03_delivery_last: (2, 25, Some(-4)),
+    case_04_pickup_first: (0, 5, Some(4)),
+    case_05_pickup_middle: (1, 15, Some(4)),
+    case_06_pickup_last: (2, 25, Some(4)),
+    case_07_no_demand_middle: (1, 25, None),
+    case_08_no_demand_first: (0, 25, None),
+}
+
+fn can_estimate_activity_insertion_as_fitness_change_impl(index: usize, location: Location, demand: Option<i32>) {
+    let features = create_features();
+    let route_ctx = create_route_ctx(&features, vec![(10, -2), (20, 3)]);
+    let target = create_activity(location, demand.map(create_simple_demand));
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let original = get_fitness(&features, route_ctx.deep_copy());
+
+    let estimate = features.1.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    let mut route_ctx = route_ctx.deep_copy();
+    route_ctx.route_mut().tour.insert_at(target.deep_copy(), index + 1);
+    accept_route_state(&features, &mut route_ctx);
+    assert_eq!(estimate, get_fitness(&features, route_ctx) - original);
+}
@@ -0,0 +1,105 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, TimeWindow};
+
+fn create_makespan_feature() -> Feature {
+    create_minimize_makespan_feature("makespan", TestTransportCost::new_shared(), TestActivityCost::new_shared())
+        .unwrap()
+}
+
+fn create_route_ctx(activities: &[(Location, (f64, f64))]) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(activities.iter().map(|&(location, (start, end))| {
+                    ActivityBuilder::with_location_and_tw(location, TimeWindow::new(start, end)).build()
+                }))
+                .build(),
+        )
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+parameterized_test! {can_calculate_fitness, (route_activities, expected), {
+    can_calculate_fitness_impl(route_activities, expected);
+}}
+
+can_calculate_fitness! {
+    case_01_single_route: (vec![vec![10, 20]], 40.),
+    case_02_two_routes: (vec![vec![10, 20], vec![5]], 40.),
+    case_03_two_routes: (vec![vec![10], vec![30]], 60.),
+    case_04_no_routes: (vec![], 0.),
+}
+
+fn can_calculate_fitness_impl(route_activities: Vec<Vec<Location>>, expected: Cost) {
+    let routes = route_activities
+        .into_iter()
+        .map(|locations| {
+            create_route_ctx(locations.into_iter().map(|l| (l, (0., 1000.))).collect::<Vec<_>>().as_slice())
+        })
+        .collect();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    let objective = create_makespan_feature().objective.unwrap();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (activities, index, location, makespan, expected), {
+    can_estimate_activity_insertion_impl(activities, index, location, makespan, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case_01_no_shift: (vec![(10, (0., 1000.)), (20, (0., 1000.))], 1, 15, Some(40.), 0.),
+    case_02_shift_critical: (vec![(10, (0., 1000.)), (20, (0., 1000.))], 1, 25, Some(40.), 10.),
+    case_03_shift_partially_absorbed: (vec![(10, (0., 1000.)), (20, (0., 1000.))], 1, 25, Some(45.), 5.),
+    case_04_shift_non_critical: (vec![(10, (0., 1000.)), (20, (0., 1000.))], 1, 25, Some(60.), 0.),
+    case_05_shift_last_leg: (vec![(10, (0., 1000.)), (20, (0., 1000.))], 2, 30, Some(40.), 20.),
+    case_06_waiting_absorbs: (vec![(10, (0., 1000.)), (20, (50., 1000.))], 0, 15, Some(70.), 0.),
+    case_07_waiting_absorbs_partially: (vec![(10, (0., 1000.)), (20, (25., 1000.))], 0, 15, Some(45.), 5.),
+    case_08_no_fleet_state: (vec![(10, (0., 1000.)), (20, (0., 1000.))], 1, 25, None, 10.),
+}
+
+fn can_estimate_activity_insertion_impl(
+    activities: Vec<(Location, (f64, f64))>,
+    index: usize,
+    location: Location,
+    makespan: Option<f64>,
+    expected: Cost,
+) {
+    let mut route_ctx = create_route_ctx(activities.as_slice());
+    if let Some(makespan) = makespan {
+        route_ctx.state_mut().set_fleet_makespan(makespan);
+    }
+    let objective = create_makespan_feature().objective.unwrap();
+    let target = ActivityBuilder::with_location(location).build();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_update_fleet_makespan_on_solution_state() {
+    let routes = vec![create_route_ctx(&[(10, (0., 1000.))]), create_route_ctx(&[(30, (0., 1000.))])];
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    let state = create_makespan_feature().state.unwrap();
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx.solution.routes.iter().for_each(|route_ctx| {
+        assert_eq!(route_ctx.state().get_fleet_makespan().copied(), Some(60.));
+    });
+}
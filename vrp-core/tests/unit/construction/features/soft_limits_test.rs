@@ -0,0 +1,213 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, SingleDimLoad};
+use crate::models::problem::Vehicle;
+use crate::models::solution::Activity;
+
+fn create_route_ctx(vehicle: Vehicle, activities: Vec<Activity>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+fn create_travel_route_ctx(locations: &[Location]) -> RouteContext {
+    create_route_ctx(
+        TestVehicleBuilder::default().id("v1").build(),
+        locations.iter().map(|&location| ActivityBuilder::with_location(location).build()).collect(),
+    )
+}
+
+fn create_demand_activity(location: Location, demand: i32) -> Activity {
+    let job = TestSingleBuilder::default().location(Some(location)).demand(create_simple_demand(demand)).build_shared();
+    ActivityBuilder::with_location(location).job(Some(job)).build()
+}
+
+fn create_capacity_route_ctx(capacity: i32, demands: &[i32]) -> RouteContext {
+    let mut route_ctx = create_route_ctx(
+        TestVehicleBuilder::default().id("v1").capacity(capacity).build(),
+        demands.iter().enumerate().map(|(idx, &demand)| create_demand_activity(idx as Location + 1, demand)).collect(),
+    );
+    create_soft_capacity_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_soft_capacity_feature() -> Feature {
+    CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").set_is_soft_capacity(|_| true).build().unwrap()
+}
+
+fn create_soft_capacity_objective(penalty: Cost) -> Arc<dyn FeatureObjective> {
+    SoftLimitsFeatureBuilder::new("soft_limits")
+        .set_capacity_limit::<SingleDimLoad, _, _>(
+            |load, capacity| (load.value - capacity.value).max(0) as f64,
+            move |_| Some(penalty),
+        )
+        .build()
+        .unwrap()
+        .objective
+        .unwrap()
+}
+
+parameterized_test! {can_calculate_travel_penalty, (locations, distance_limit, duration_limit, expected), {
+    can_calculate_travel_penalty_impl(locations, distance_limit, duration_limit, expected);
+}}
+
+can_calculate_travel_penalty! {
+    case_01_no_violation: (vec![10, 20], Some((50., 2.)), None, 0.),
+    case_02_distance_violation: (vec![10, 20], Some((30., 2.)), None, 20.),
+    case_03_duration_violation: (vec![10, 20], None, Some((35., 3.)), 15.),
+    case_04_both_violations: (vec![10, 20], Some((30., 2.)), Some((35., 3.)), 35.),
+    case_05_no_limits: (vec![10, 20], None, None, 0.),
+}
+
+fn can_calculate_travel_penalty_impl(
+    locations: Vec<Location>,
+    distance_limit: Option<(Distance, Cost)>,
+    duration_limit: Option<(Duration, Cost)>,
+    expected: Cost,
+) {
+    let route_ctx = create_travel_route_ctx(locations.as_slice());
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let objective = SoftLimitsFeatureBuilder::new("soft_limits")
+        .set_transport(TestTransportCost::new_shared())
+        .set_distance_limit(move |_| distance_limit)
+        .set_duration_limit(move |_| duration_limit)
+        .build()
+        .unwrap()
+        .objective
+        .unwrap();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_distance_penalty_for_activity_insertion, (index, location, limit, expected), {
+    can_estimate_distance_penalty_for_activity_insertion_impl(index, location, limit, expected);
+}}
+
+can_estimate_distance_penalty_for_activity_insertion! {
+    case_01_no_violation: (1, 15, 40., 0.),
+    case_02_new_violation: (2, 25, 40., 20.),
+    case_03_existing_violation: (2, 25, 30., 20.),
+    case_04_partial_violation: (2, 25, 45., 10.),
+}
+
+fn can_estimate_distance_penalty_for_activity_insertion_impl(
+    index: usize,
+    location: Location,
+    limit: Distance,
+    expected: Cost,
+) {
+    let route_ctx = create_travel_route_ctx(&[10, 20]);
+    let target = ActivityBuilder::with_location(location).build();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let objective = SoftLimitsFeatureBuilder::new("soft_limits")
+        .set_transport(TestTransportCost::new_shared())
+        .set_distance_limit(move |_| Some((limit, 2.)))
+        .build()
+        .unwrap()
+        .objective
+        .unwrap();
+
+    let result = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_tour_size_penalty_for_route_insertion, (activities, limit, expected), {
+    can_estimate_tour_size_penalty_for_route_insertion_impl(activities, limit, expected);
+}}
+
+can_estimate_tour_size_penalty_for_route_insertion! {
+    case_01_no_violation: (2, 3, 0.),
+    case_02_new_violation: (3, 3, 5.),
+    case_03_existing_violation: (4, 3, 5.),
+}
+
+fn can_estimate_tour_size_penalty_for_route_insertion_impl(activities: usize, limit: usize, expected: Cost) {
+    let route_ctx = create_travel_route_ctx((0..activities).map(|idx| idx as Location).collect::<Vec<_>>().as_slice());
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let job = TestSingleBuilder::default().id("job1").build_as_job_ref();
+    let objective = SoftLimitsFeatureBuilder::new("soft_limits")
+        .set_tour_size_limit(move |_| Some((limit, 5.)))
+        .build()
+        .unwrap()
+        .objective
+        .unwrap();
+
+    let result = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_capacity_penalty() {
+    let route_ctx = create_capacity_route_ctx(5, &[-3, -4]);
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let result = create_soft_capacity_objective(10.).fitness(&insertion_ctx);
+
+    assert_eq!(result, 20.);
+}
+
+parameterized_test! {can_estimate_capacity_penalty_for_activity_insertion, (demands, index, demand, expected), {
+    can_estimate_capacity_penalty_for_activity_insertion_impl(demands, index, demand, expected);
+}}
+
+can_estimate_capacity_penalty_for_activity_insertion! {
+    case_01_delivery_fits: (vec![-2, -1], 1, -2, 0.),
+    case_02_delivery_overload: (vec![-2, -2], 1, -3, 20.),
+    case_03_pickup_overload: (vec![-2, 2], 0, 4, 10.),
+    case_04_existing_overload: (vec![-4, -3], 1, -1, 10.),
+}
+
+fn can_estimate_capacity_penalty_for_activity_insertion_impl(
+    demands: Vec<i32>,
+    index: usize,
+    demand: i32,
+    expected: Cost,
+) {
+    let route_ctx = create_capacity_route_ctx(5, demands.as_slice());
+    let target = create_demand_activity(10, demand);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = create_soft_capacity_objective(10.).estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_skip_capacity_constraint_when_soft() {
+    let route_ctx = create_capacity_route_ctx(5, &[-3, -2]);
+    let target = create_demand_activity(10, -3);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+    let constraint = create_soft_capacity_feature().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, None);
+}
@@ -159,6 +159,65 @@ fn can_use_objective_estimate_impl(feature_map: &[&str], expected_cost: &[Cost])
     assert_eq!(result, InsertionCost::new(expected_cost));
 }
 
+#[test]
+fn can_augment_cost_objective() {
+    let route_ctx = RouteContext::new(test_actor());
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &ActivityBuilder::default().job(None).build(),
+        target: &ActivityBuilder::default().job(None).build(),
+        next: None,
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+    let features = vec![create_feature("o_1", 1., None), create_feature("o_2", 1., None)];
+    let goal_ctx = GoalContextBuilder::with_features(&features)
+        .unwrap()
+        .add_alternative_goal(Goal::subset_of(&features, &["o_2", "o_1"]).unwrap(), 1.)
+        .set_cost_feature("o_1")
+        .build()
+        .unwrap();
+    let objective = create_feature("o_3", 2., None).objective.unwrap();
+
+    let result = goal_ctx.with_augmented_objective(objective).expect("cost objective should be set");
+
+    assert_eq!(result.estimate(&move_ctx), InsertionCost::new(&[3., 1.]));
+    assert_eq!(result.fitness(&insertion_ctx).collect::<Vec<_>>(), vec![3., 1.]);
+    assert_eq!(result.get_alternative(0).estimate(&move_ctx), InsertionCost::new(&[1., 3.]));
+    assert_eq!(goal_ctx.estimate(&move_ctx), InsertionCost::new(&[1., 1.]));
+}
+
+#[test]
+fn can_keep_layer_total_order_when_augmenting_cost_objective() {
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+    let features = vec![create_feature("o_1", 1., None), create_feature("o_2", 1., None)];
+    let objectives = features.iter().filter_map(|feature| feature.objective.clone()).collect::<Vec<_>>();
+    let goal = GoalBuilder::default()
+        .add_multi(&objectives, |_, _, _| Ordering::Greater, |objectives, move_ctx| objectives[1].estimate(move_ctx))
+        .build()
+        .unwrap();
+    let goal_ctx = GoalContextBuilder::with_features(&features)
+        .unwrap()
+        .set_main_goal(goal)
+        .set_cost_feature("o_2")
+        .build()
+        .unwrap();
+    let objective = create_feature("o_3", 2., None).objective.unwrap();
+
+    let result = goal_ctx.with_augmented_objective(objective).expect("cost objective should be set");
+
+    assert_eq!(result.total_order(&insertion_ctx, &insertion_ctx), Ordering::Greater);
+    assert_eq!(result.fitness(&insertion_ctx).collect::<Vec<_>>(), vec![1., 3.]);
+}
+
+#[test]
+fn can_skip_augmenting_without_cost_objective() {
+    let goal_ctx = TestGoalContextBuilder::empty().add_feature(create_feature("o_1", 1., None)).build();
+    let objective = create_feature("o_2", 2., None).objective.unwrap();
+
+    assert!(goal_ctx.with_augmented_objective(objective).is_none());
+}
+
 parameterized_test! {can_use_objective_total_order, (left_fitness, right_fitness, expected), {
     can_use_objective_total_order_impl(left_fitness, right_fitness, expected);
 }}
@@ -96,6 +96,22 @@ fn can_remove_job() {
     assert_eq!(tour.job_count(), 1);
 }
 
+#[test]
+fn can_keep_jobs_order_independent_from_memory_addresses() {
+    let mut tour = get_test_tour();
+    (0..3).for_each(|_| {
+        tour.insert_last(ActivityBuilder::default().build());
+    });
+    let jobs = tour.jobs().cloned().collect::<Vec<_>>();
+
+    tour.remove(&jobs[1]);
+
+    assert_eq!(
+        tour.jobs().cloned().collect::<Vec<_>>(),
+        vec![jobs[0].clone(), jobs[4].clone(), jobs[2].clone(), jobs[3].clone()]
+    );
+}
+
 #[test]
 fn can_get_activities_for_job() {
     let mut tour = get_test_tour();
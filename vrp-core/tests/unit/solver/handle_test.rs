@@ -0,0 +1,43 @@
+use super::*;
+use crate::models::examples::create_example_problem;
+use std::time::Duration;
+
+fn start_example_handle(max_generations: usize) -> SolverHandle {
+    let problem = create_example_problem();
+
+    SolverHandle::start(problem.clone(), move || {
+        VrpConfigBuilder::new(problem).prebuild()?.with_max_generations(Some(max_generations)).build()
+    })
+}
+
+#[test]
+fn can_join_solver_handle() {
+    let handle = start_example_handle(10);
+
+    let solution = handle.join().expect("cannot get solution");
+
+    assert_eq!(solution.cost, 84.);
+}
+
+#[test]
+fn can_get_best_so_far_and_stop_solver_handle() {
+    let handle = start_example_handle(usize::MAX);
+
+    while handle.best_so_far().is_none() {
+        assert!(!handle.is_finished());
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    handle.stop();
+    let solution = handle.join().expect("cannot get solution");
+
+    assert_eq!(solution.unassigned.len(), 0);
+}
+
+#[test]
+fn can_return_config_error_on_join() {
+    let handle = SolverHandle::start(create_example_problem(), || Err("cannot create config".into()));
+
+    let result = handle.join();
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some("cannot create config".to_string()));
+}
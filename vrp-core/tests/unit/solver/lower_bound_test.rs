@@ -0,0 +1,159 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::models::domain::ProblemBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::models::problem::Vehicle;
+use rosomaxa::prelude::Environment;
+
+fn create_test_problem(vehicles: Vec<Vehicle>, jobs: Vec<Job>) -> Problem {
+    ProblemBuilder::default()
+        .with_fleet(
+            FleetBuilder::default().add_driver(test_driver_with_costs(empty_costs())).add_vehicles(vehicles).build(),
+        )
+        .with_jobs(jobs)
+        .build()
+}
+
+#[test]
+fn can_estimate_lower_bound_using_spanning_tree() {
+    let jobs = vec![8, 2, 5]
+        .into_iter()
+        .map(|location| TestSingleBuilder::default().location(Some(location)).duration(1.).build_as_job_ref())
+        .collect();
+    let problem = create_test_problem(vec![test_vehicle_with_id("v1")], jobs);
+
+    let lower_bound = estimate_lower_bound(&problem);
+
+    assert_eq!(lower_bound.distance, 8.);
+    assert_eq!(lower_bound.duration, 8.);
+    assert_eq!(lower_bound.vehicles, 1);
+    assert_eq!(lower_bound.cost, 8. + 8. + 3.);
+}
+
+parameterized_test! {can_estimate_min_vehicles_by_capacity, (demands, capacity, expected_vehicles), {
+    can_estimate_min_vehicles_by_capacity_impl(demands, capacity, expected_vehicles);
+}}
+
+can_estimate_min_vehicles_by_capacity! {
+    case01_single: (vec![-3], 10, 1),
+    case02_fit_exactly: (vec![-5, -5], 10, 1),
+    case03_two_deliveries: (vec![-6, -6], 10, 2),
+    case04_mixed: (vec![-6, 4, 6, -5], 10, 2),
+    case05_limited_by_fleet: (vec![-10, -10, -10, -10], 10, 3),
+}
+
+fn can_estimate_min_vehicles_by_capacity_impl(demands: Vec<i32>, capacity: i32, expected_vehicles: usize) {
+    let jobs = demands
+        .into_iter()
+        .map(|demand| TestSingleBuilder::default().demand(create_simple_demand(demand)).build_as_job_ref())
+        .collect();
+    let vehicles = (0..3)
+        .map(|idx| {
+            TestVehicleBuilder::default().id(format!("v{idx}").as_str()).capacity(capacity).costs(fixed_costs()).build()
+        })
+        .collect();
+    let problem = create_test_problem(vehicles, jobs);
+
+    let lower_bound = estimate_lower_bound(&problem);
+
+    assert_eq!(lower_bound.vehicles, expected_vehicles);
+    assert!(lower_bound.cost >= expected_vehicles as f64 * fixed_costs().fixed);
+}
+
+parameterized_test! {can_estimate_min_vehicles_by_shift_time, (durations, expected_vehicles), {
+    can_estimate_min_vehicles_by_shift_time_impl(durations, expected_vehicles);
+}}
+
+can_estimate_min_vehicles_by_shift_time! {
+    case01_single: (vec![600.], 1),
+    case02_fit_exactly: (vec![500., 500.], 1),
+    case03_two_shifts: (vec![600., 600., 600.], 2),
+    case04_limited_by_fleet: (vec![900., 900., 900., 900.], 3),
+}
+
+fn can_estimate_min_vehicles_by_shift_time_impl(durations: Vec<f64>, expected_vehicles: usize) {
+    let jobs = durations
+        .into_iter()
+        .map(|duration| TestSingleBuilder::default().location(Some(0)).duration(duration).build_as_job_ref())
+        .collect();
+    let vehicles = (0..3).map(|idx| test_vehicle_with_id(format!("v{idx}").as_str())).collect();
+    let problem = create_test_problem(vehicles, jobs);
+
+    let lower_bound = estimate_lower_bound(&problem);
+
+    assert_eq!(lower_bound.vehicles, expected_vehicles);
+}
+
+#[test]
+fn can_ignore_vehicle_specific_jobs() {
+    let mut specific_job = TestSingleBuilder::default();
+    specific_job.location(Some(100)).dimens_mut().set_vehicle_id("v1".to_string());
+    let jobs = vec![
+        TestSingleBuilder::default().location(Some(5)).duration(0.).build_as_job_ref(),
+        specific_job.build_as_job_ref(),
+    ];
+    let problem = create_test_problem(vec![test_vehicle_with_id("v1")], jobs);
+
+    let lower_bound = estimate_lower_bound(&problem);
+
+    assert_eq!(lower_bound.distance, 5.);
+}
+
+parameterized_test! {can_check_complete_assignment, (jobs, expected), {
+    let jobs = jobs
+        .into_iter()
+        .map(|vehicle_id: Option<&str>| {
+            let mut builder = TestSingleBuilder::default();
+            if let Some(vehicle_id) = vehicle_id {
+                builder.dimens_mut().set_vehicle_id(vehicle_id.to_string());
+            }
+            builder.build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(is_complete_assignment(jobs.iter()), expected);
+}}
+
+can_check_complete_assignment! {
+    case01_empty: (vec![], true),
+    case02_vehicle_specific: (vec![Some("v1"), Some("v2")], true),
+    case03_required: (vec![None], false),
+    case04_mixed: (vec![Some("v1"), None], false),
+}
+
+parameterized_test! {can_get_gap, (lower_bound, cost, expected), {
+    let lower_bound = LowerBound { cost: lower_bound, distance: 0., duration: 0., vehicles: 0 };
+
+    assert_eq!(lower_bound.get_gap(cost), expected);
+}}
+
+can_get_gap! {
+    case01_same: (100., 100., 0.),
+    case02_higher: (80., 100., 0.2),
+    case03_lower: (120., 100., 0.),
+    case04_zero: (0., 0., 0.),
+}
+
+parameterized_test! {can_terminate_when_gap_is_reached, (target_gap, expected), {
+    can_terminate_when_gap_is_reached_impl(target_gap, expected);
+}}
+
+can_terminate_when_gap_is_reached! {
+    case01_reached: (0.7, true),
+    case02_not_reached: (0.5, false),
+}
+
+fn can_terminate_when_gap_is_reached_impl(target_gap: f64, expected: bool) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem.clone(), (solution, None), Arc::new(Environment::default()));
+    let mut refinement_ctx = create_default_refinement_ctx(problem.clone());
+    refinement_ctx.add_solution(insertion_ctx);
+    let termination = TargetGapTermination::new(estimate_lower_bound(problem.as_ref()), target_gap);
+
+    let result = termination.is_termination(&mut refinement_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::helpers::models::solution::{test_actor, ActivityBuilder};
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::create_default_heuristic_operator;
+use rosomaxa::prelude::*;
+
+fn create_insertion_ctx(rows: usize, cols: usize) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment)
+}
+
+fn create_refinement_ctx(insertion_ctx: &InsertionContext) -> RefinementContext {
+    let problem = insertion_ctx.problem.clone();
+    let population = Box::new(GreedyPopulation::new(problem.goal.clone(), 1, None));
+
+    RefinementContext::new(problem, population, TelemetryMode::None, insertion_ctx.environment.clone())
+}
+
+parameterized_test! {can_estimate_edge_penalty, (next, expected), {
+    can_estimate_edge_penalty_impl(next, expected);
+}}
+
+can_estimate_edge_penalty! {
+    case01_between_activities: (Some(3), 3.),
+    case02_last_activity: (None, 2.),
+}
+
+fn can_estimate_edge_penalty_impl(next: Option<Location>, expected: Cost) {
+    let objective = EdgePenaltyObjective {
+        penalties: vec![((1, 2), 1.), ((2, 3), 2.), ((1, 3), 1.5)].into_iter().collect(),
+        weight: 2.,
+    };
+    let route_ctx = RouteContext::new(test_actor());
+    let next = next.map(|location| ActivityBuilder::with_location(location).build());
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &ActivityBuilder::with_location(1).build(),
+        target: &ActivityBuilder::with_location(2).build(),
+        next: next.as_ref(),
+    };
+
+    let result = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_penalize_expensive_edges_temporarily() {
+    let insertion_ctx = create_insertion_ctx(3, 3);
+    let refinement_ctx = create_refinement_ctx(&insertion_ctx);
+    let inner_search =
+        create_default_heuristic_operator(insertion_ctx.problem.clone(), insertion_ctx.environment.clone());
+    let guided_search = GuidedSearch::new(inner_search, 1, 0.01, 0.1, 4);
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let max_cost = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| get_route_edges(route_ctx).map(move |edge| get_edge_cost(route_ctx, transport, edge)))
+        .max_by(|a, b| a.total_cmp(b))
+        .unwrap();
+
+    let penalties = guided_search.update_penalties(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(penalties.len(), 1);
+    let (edge, penalty) = penalties.iter().next().unwrap();
+    assert_eq!(*penalty, 1.);
+    let route_ctx =
+        insertion_ctx.solution.routes.iter().find(|route_ctx| get_route_edges(route_ctx).any(|e| e == *edge));
+    assert_eq!(get_edge_cost(route_ctx.unwrap(), transport, *edge), max_cost);
+
+    let penalties = guided_search.update_penalties(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(penalties.len(), 2);
+    assert_eq!(penalties.get(edge).copied(), Some(PENALTY_DECAY));
+}
+
+#[test]
+fn can_perform_search_without_leaking_penalties() {
+    let insertion_ctx = create_insertion_ctx(3, 3);
+    let refinement_ctx = create_refinement_ctx(&insertion_ctx);
+    let inner_search =
+        create_default_heuristic_operator(insertion_ctx.problem.clone(), insertion_ctx.environment.clone());
+    let guided_search = GuidedSearch::new(inner_search, 2, 0.2, 1., 4);
+
+    let result = guided_search.search(&refinement_ctx, &insertion_ctx);
+
+    assert!(Arc::ptr_eq(&result.problem, &insertion_ctx.problem));
+    assert_eq!(
+        result.problem.goal.fitness(&result).count(),
+        insertion_ctx.problem.goal.fitness(&insertion_ctx).count()
+    );
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 9);
+}
@@ -0,0 +1,25 @@
+use super::*;
+use crate::helpers::solver::explore_with_local_operator;
+
+parameterized_test! { can_use_cross_exchange, (jobs_order, locked_ids, expected), {
+    can_use_cross_exchange_impl(jobs_order, locked_ids, expected);
+}}
+
+can_use_cross_exchange! {
+    case_01_exchange_segments: (
+        vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]],
+        &[],
+        Some(vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]]),
+    ),
+    case_02_locked_jobs: (vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]], &["c1", "c4"], None),
+    case_03_optimal_routes: (vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]], &[], None),
+}
+
+fn can_use_cross_exchange_impl(jobs_order: Vec<Vec<&str>>, locked_ids: &[&str], expected: Option<Vec<Vec<&str>>>) {
+    let result = explore_with_local_operator(&CrossExchange::default(), (3, 2), jobs_order, locked_ids);
+
+    assert_eq!(
+        result,
+        expected.map(|routes| routes.iter().map(|r| r.iter().map(|id| id.to_string()).collect()).collect())
+    );
+}
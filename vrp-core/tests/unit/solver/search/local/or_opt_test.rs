@@ -0,0 +1,27 @@
+use super::*;
+use crate::helpers::solver::explore_with_local_operator;
+
+parameterized_test! { can_use_or_opt, (matrix, jobs_order, locked_ids, expected), {
+    can_use_or_opt_impl(matrix, jobs_order, locked_ids, expected);
+}}
+
+can_use_or_opt! {
+    case_01_move_single: ((5, 1), vec![vec!["c0", "c2", "c3", "c1", "c4"]], &[], Some(vec![vec!["c0", "c1", "c2", "c3", "c4"]])),
+    case_02_move_segment: ((5, 1), vec![vec!["c0", "c3", "c4", "c1", "c2"]], &[], Some(vec![vec!["c0", "c1", "c2", "c3", "c4"]])),
+    case_03_locked_job: ((5, 1), vec![vec!["c0", "c2", "c3", "c1", "c4"]], &["c1", "c2", "c3"], None),
+    case_04_optimal_route: ((5, 1), vec![vec!["c0", "c1", "c2", "c3", "c4"]], &[], None),
+}
+
+fn can_use_or_opt_impl(
+    matrix: (usize, usize),
+    jobs_order: Vec<Vec<&str>>,
+    locked_ids: &[&str],
+    expected: Option<Vec<Vec<&str>>>,
+) {
+    let result = explore_with_local_operator(&OrOpt::default(), matrix, jobs_order, locked_ids);
+
+    assert_eq!(
+        result,
+        expected.map(|routes| routes.iter().map(|r| r.iter().map(|id| id.to_string()).collect()).collect())
+    );
+}
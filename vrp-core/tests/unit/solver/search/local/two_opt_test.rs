@@ -0,0 +1,40 @@
+use super::*;
+use crate::helpers::solver::explore_with_local_operator;
+
+parameterized_test! { can_use_two_opt, (jobs_order, locked_ids, expected), {
+    can_use_two_opt_impl(jobs_order, locked_ids, expected);
+}}
+
+can_use_two_opt! {
+    case_01_reverse_segment: (vec![vec!["c0", "c3", "c2", "c1", "c4"]], &[], Some(vec![vec!["c0", "c1", "c2", "c3", "c4"]])),
+    case_02_optimal_route: (vec![vec!["c0", "c1", "c2", "c3", "c4"]], &[], None),
+    case_03_locked_segment: (vec![vec!["c0", "c3", "c2", "c1", "c4"]], &["c2"], None),
+}
+
+fn can_use_two_opt_impl(jobs_order: Vec<Vec<&str>>, locked_ids: &[&str], expected: Option<Vec<Vec<&str>>>) {
+    let result = explore_with_local_operator(&TwoOpt::default(), (5, 1), jobs_order, locked_ids);
+
+    assert_eq!(result, expected.map(|routes| to_ids(routes.as_slice())));
+}
+
+parameterized_test! { can_use_two_opt_star, (jobs_order, expected), {
+    can_use_two_opt_star_impl(jobs_order, expected);
+}}
+
+can_use_two_opt_star! {
+    case_01_exchange_tails: (
+        vec![vec!["c0", "c1", "c5"], vec!["c3", "c4", "c2"]],
+        Some(vec![vec!["c0", "c1", "c2", "c3", "c4", "c5"]]),
+    ),
+    case_02_optimal_routes: (vec![vec!["c0", "c1", "c2", "c3", "c4", "c5"], vec![]], None),
+}
+
+fn can_use_two_opt_star_impl(jobs_order: Vec<Vec<&str>>, expected: Option<Vec<Vec<&str>>>) {
+    let result = explore_with_local_operator(&TwoOptStar::default(), (3, 2), jobs_order, &[]);
+
+    assert_eq!(result, expected.map(|routes| to_ids(routes.as_slice())));
+}
+
+fn to_ids(routes: &[Vec<&str>]) -> Vec<Vec<String>> {
+    routes.iter().map(|route| route.iter().map(|id| id.to_string()).collect()).collect()
+}
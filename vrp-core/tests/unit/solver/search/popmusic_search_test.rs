@@ -0,0 +1,86 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::create_default_heuristic_operator;
+use rosomaxa::prelude::*;
+
+fn create_insertion_ctx(rows: usize, cols: usize) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment)
+}
+
+parameterized_test! {can_create_disjoint_subproblems, (seeds, subproblem_routes, expected_subproblems), {
+    can_create_disjoint_subproblems_impl(seeds, subproblem_routes, expected_subproblems);
+}}
+
+can_create_disjoint_subproblems! {
+    case01_all_seeds: (None, 2, (3, 4)),
+    case02_all_seeds_single: (None, 7, (1, 1)),
+    case03_one_seed: (Some(vec![3]), 3, (1, 1)),
+    case04_no_seeds: (Some(vec![]), 2, (0, 0)),
+}
+
+fn can_create_disjoint_subproblems_impl(
+    seeds: Option<Vec<usize>>,
+    subproblem_routes: usize,
+    expected_subproblems: (usize, usize),
+) {
+    let insertion_ctx = create_insertion_ctx(5, 7);
+    let routes = &insertion_ctx.solution.routes;
+    let seeds = match seeds {
+        Some(seeds) => seeds.iter().map(|idx| routes[*idx].route().actor.clone()).collect(),
+        None => routes.iter().map(|route_ctx| route_ctx.route().actor.clone()).collect(),
+    };
+
+    let subproblems = create_subproblems(&insertion_ctx, &seeds, subproblem_routes);
+
+    let (min, max) = expected_subproblems;
+    assert!(subproblems.len() >= min && subproblems.len() <= max);
+    let all_indices = subproblems.iter().flat_map(|(_, indices)| indices.iter()).collect::<Vec<_>>();
+    assert_eq!(all_indices.len(), all_indices.iter().collect::<HashSet<_>>().len());
+    subproblems.iter().for_each(|(seed_idx, indices)| {
+        assert!(seeds.contains(&routes[*seed_idx].route().actor));
+        assert!(indices.contains(seed_idx));
+        assert!(indices.len() <= subproblem_routes);
+    });
+}
+
+#[test]
+fn can_select_nearest_routes_for_subproblem() {
+    let insertion_ctx = create_insertion_ctx(5, 7);
+    let routes = &insertion_ctx.solution.routes;
+    let seeds = once(routes[0].route().actor.clone()).collect();
+    let medoid_distances = group_routes_by_proximity(&insertion_ctx).unwrap();
+
+    let subproblems = create_subproblems(&insertion_ctx, &seeds, 3);
+
+    assert_eq!(subproblems.len(), 1);
+    let (seed_idx, indices) = &subproblems[0];
+    assert_eq!(*seed_idx, 0);
+    let expected = once(0).chain(medoid_distances[0].iter().take(2).map(|(idx, _)| *idx)).collect::<HashSet<_>>();
+    assert_eq!(*indices, expected);
+}
+
+#[test]
+fn can_perform_search() {
+    let environment = Arc::new(Environment::default());
+    let insertion_ctx = create_insertion_ctx(5, 7);
+    let problem = insertion_ctx.problem.clone();
+    let population = Box::new(GreedyPopulation::new(problem.goal.clone(), 1, None));
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone());
+    let inner_search = create_default_heuristic_operator(problem, environment);
+    let popmusic_search = PopmusicSearch::new(inner_search, 3, 10, 4, 1000);
+
+    let result = popmusic_search.search(&refinement_ctx, &insertion_ctx);
+
+    let solution = &result.solution;
+    assert!(solution.unassigned.is_empty());
+    assert!(solution.required.is_empty());
+    assert!(!solution.routes.is_empty());
+    assert_eq!(
+        solution.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).collect::<HashSet<_>>().len(),
+        35
+    );
+    assert_ne!(result.problem.goal.total_order(&result, &insertion_ctx), Ordering::Greater);
+}
@@ -0,0 +1,78 @@
+use super::*;
+use crate::construction::features::{CapacityFeatureBuilder, MinimizeUnassignedBuilder, TransportFeatureBuilder};
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::models::domain::{get_customer_ids_from_routes_sorted, ProblemBuilder, TestGoalContextBuilder};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::ActivityBuilder;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::SingleDimLoad;
+use crate::models::problem::Job;
+use crate::solver::search::RecreateWithCheapest;
+
+fn create_insertion_ctx() -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            TestVehicleBuilder::default().id("v1").capacity(1).build(),
+            TestVehicleBuilder::default().id("v2").capacity(2).build(),
+        ])
+        .build();
+    let small =
+        TestSingleBuilder::default().id("small").location(Some(1)).demand(create_simple_demand(1)).build_shared();
+    let large =
+        TestSingleBuilder::default().id("large").location(Some(2)).demand(create_simple_demand(2)).build_shared();
+    let goal = TestGoalContextBuilder::empty()
+        .add_feature(MinimizeUnassignedBuilder::new("min_unassigned").build().unwrap())
+        .add_feature(CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").set_violation_code(1).build().unwrap())
+        .add_feature(
+            TransportFeatureBuilder::new("transport")
+                .set_violation_code(2)
+                .set_transport_cost(TestTransportCost::new_shared())
+                .set_activity_cost(TestActivityCost::new_shared())
+                .build_minimize_cost()
+                .unwrap(),
+        )
+        .build();
+    let problem = ProblemBuilder::default()
+        .with_fleet(fleet)
+        .with_jobs(vec![Job::Single(small.clone()), Job::Single(large.clone())])
+        .with_goal(goal)
+        .build();
+
+    let mut insertion_ctx = InsertionContext::new_empty(Arc::new(problem), Arc::new(Environment::default()));
+
+    // NOTE small job occupies a vehicle which is the only one able to serve large job
+    let actor = get_test_actor_from_fleet(insertion_ctx.problem.fleet.as_ref(), "v2");
+    let mut route_ctx = insertion_ctx.solution.registry.get_route(&actor).expect("cannot get route");
+    route_ctx.route_mut().tour.insert_last(ActivityBuilder::with_location(1).job(Some(small)).build());
+    insertion_ctx.problem.goal.accept_route_state(&mut route_ctx);
+    insertion_ctx.solution.routes.push(route_ctx);
+    insertion_ctx.solution.unassigned.insert(Job::Single(large), UnassignmentInfo::Unknown);
+    insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+parameterized_test! {can_insert_job_using_ejection_chain, (max_depth, expected_unassigned, expected_routes), {
+    can_insert_job_using_ejection_chain_impl(max_depth, expected_unassigned, expected_routes);
+}}
+
+can_insert_job_using_ejection_chain! {
+    case_01_no_chain: (0, 1, vec![vec!["small"]]),
+    case_02_chain_of_one: (1, 0, vec![vec!["large"], vec!["small"]]),
+}
+
+fn can_insert_job_using_ejection_chain_impl(
+    max_depth: usize,
+    expected_unassigned: usize,
+    expected_routes: Vec<Vec<&str>>,
+) {
+    let insertion_ctx = create_insertion_ctx();
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let inner = Arc::new(RecreateWithCheapest::new(insertion_ctx.environment.random.clone()));
+
+    let result = RecreateWithEjectionChain::new(inner, max_depth, 4).run(&refinement_ctx, insertion_ctx);
+
+    assert_eq!(result.solution.unassigned.len(), expected_unassigned);
+    assert_eq!(get_customer_ids_from_routes_sorted(&result), expected_routes);
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+
+fn create_parents(first_order: &[Vec<&str>], second_order: &[Vec<&str>]) -> (InsertionContext, InsertionContext) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let mut first = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let mut second = first.deep_copy();
+
+    rearrange_jobs_in_routes(&mut first, first_order);
+    rearrange_jobs_in_routes(&mut second, second_order);
+
+    (first, second)
+}
+
+fn is_subsequence(sequence: &[String], route: &[String]) -> bool {
+    let mut route = route.iter();
+    sequence.iter().all(|id| route.any(|other| other == id))
+}
+
+#[test]
+fn can_exchange_routes_from_second_parent() {
+    let second_order = vec![vec!["c0", "c1", "c3"], vec!["c2", "c4", "c5"]];
+    let (first, second) = create_parents(&[vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]], &second_order);
+    let refinement_ctx = create_default_refinement_ctx(first.problem.clone());
+    let recreate = Arc::new(RecreateWithCheapest::new(first.environment.random.clone()));
+
+    let child = SelectiveRouteExchange::new(recreate, 1).crossover(&refinement_ctx, &first, &second);
+
+    let child_routes = get_customer_ids_from_routes(&child);
+    assert!(child.solution.required.is_empty());
+    assert!(child.solution.unassigned.is_empty());
+    assert_eq!(child_routes.iter().map(|route| route.len()).sum::<usize>(), 6);
+    assert!(second_order.iter().any(|sequence| {
+        let sequence = sequence.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        child_routes.iter().any(|route| is_subsequence(sequence.as_slice(), route.as_slice()))
+    }));
+}
+
+#[test]
+fn can_keep_locked_jobs_in_first_parent_routes() {
+    let (first, second) = create_parents(
+        &[vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]],
+        &[vec!["c3", "c4", "c5"], vec!["c0", "c1", "c2"]],
+    );
+    let first = promote_to_locked(first, &["c0", "c1", "c2", "c3", "c4", "c5"]);
+    let refinement_ctx = create_default_refinement_ctx(first.problem.clone());
+    let recreate = Arc::new(RecreateWithCheapest::new(first.environment.random.clone()));
+
+    let child = SelectiveRouteExchange::new(recreate, 2).crossover(&refinement_ctx, &first, &second);
+
+    assert_eq!(get_customer_ids_from_routes(&child), get_customer_ids_from_routes(&first));
+}
@@ -0,0 +1,62 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes_sorted;
+use crate::helpers::solver::*;
+
+fn create_solutions(good_order: &[Vec<&str>], bad_order: &[Vec<&str>]) -> (InsertionContext, InsertionContext) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let mut good = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let mut bad = good.deep_copy();
+
+    rearrange_jobs_in_routes(&mut good, good_order);
+    rearrange_jobs_in_routes(&mut bad, bad_order);
+
+    (good, bad)
+}
+
+fn create_search() -> SetPartitioningSearch {
+    let recreate = Arc::new(RecreateWithCheapest::new(Environment::default().random));
+
+    SetPartitioningSearch::new(recreate, 100, 4, 1000)
+}
+
+#[test]
+fn can_recombine_routes_from_population() {
+    let good_order = vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]];
+    let (good, bad) = create_solutions(&good_order, &[vec!["c0", "c4", "c2"], vec!["c3", "c1", "c5"]]);
+    let mut refinement_ctx = create_default_refinement_ctx(good.problem.clone());
+    refinement_ctx.add_solution(good.deep_copy());
+
+    let result = create_search().search(&refinement_ctx, &bad);
+
+    assert!(result.solution.required.is_empty());
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes_sorted(&result), get_customer_ids_from_routes_sorted(&good));
+    assert_eq!(result.get_total_cost(), good.get_total_cost());
+}
+
+#[test]
+fn can_keep_solution_without_better_combination() {
+    let order = vec![vec!["c0", "c4", "c2"], vec!["c3", "c1", "c5"]];
+    let (_, bad) = create_solutions(&order, &order);
+    let refinement_ctx = create_default_refinement_ctx(bad.problem.clone());
+
+    let result = create_search().search(&refinement_ctx, &bad);
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&result), get_customer_ids_from_routes_sorted(&bad));
+}
+
+#[test]
+fn can_keep_routes_with_locked_jobs() {
+    let (good, bad) = create_solutions(
+        &[vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]],
+        &[vec!["c0", "c4", "c2"], vec!["c3", "c1", "c5"]],
+    );
+    let bad = promote_to_locked(bad, &["c4"]);
+    let mut refinement_ctx = create_default_refinement_ctx(good.problem.clone());
+    refinement_ctx.add_solution(good);
+
+    let result = create_search().search(&refinement_ctx, &bad);
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&result), get_customer_ids_from_routes_sorted(&bad));
+}
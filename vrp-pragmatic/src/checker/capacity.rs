@@ -8,7 +8,7 @@ use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
-/// * max vehicle's capacity is not violated
+/// * max vehicle's capacity is not violated unless it is soft
 /// * load change is correct
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
     combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
@@ -16,7 +16,13 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericErr
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), GenericError> {
     context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let capacity = MultiDimLoad::new(vehicle.capacity.clone());
+        let is_soft_capacity = vehicle
+            .limits
+            .as_ref()
+            .and_then(|limits| limits.penalties.as_ref())
+            .is_some_and(|penalties| penalties.capacity.is_some());
         let intervals = get_intervals(context, tour);
 
         intervals
@@ -43,7 +49,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), Generic
                         let from_load = MultiDimLoad::new(from.load().clone());
                         let to_load = MultiDimLoad::new(to.load().clone());
 
-                        if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
+                        if !is_soft_capacity && (!capacity.can_fit(&from_load) || !capacity.can_fit(&to_load)) {
                             return Err(format!("load exceeds capacity in tour '{}'", tour.vehicle_id).into());
                         }
 
@@ -13,12 +13,17 @@ pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
 /// Check that shift limits are not violated:
 /// * max shift time
 /// * max distance
+/// * tour size
+///
+/// Soft limits, which have a penalty defined, are skipped.
 fn check_shift_limits(context: &CheckerContext) -> Result<(), GenericError> {
     context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
 
         if let Some(ref limits) = vehicle.limits {
-            if let Some(max_distance) = limits.max_distance {
+            let penalties = limits.penalties.as_ref();
+
+            if let Some(max_distance) = limits.max_distance.filter(|_| penalties.and_then(|p| p.max_distance).is_none()) {
                 if tour.statistic.distance as f64 > max_distance {
                     return Err(format!(
                         "max distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
@@ -27,7 +32,7 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), GenericError> {
                 }
             }
 
-            if let Some(max_duration) = limits.max_duration {
+            if let Some(max_duration) = limits.max_duration.filter(|_| penalties.and_then(|p| p.max_duration).is_none()) {
                 if tour.statistic.duration as f64 > max_duration {
                     return Err(format!(
                         "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
@@ -36,7 +41,7 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), GenericError> {
                 }
             }
 
-            if let Some(tour_size_limit) = limits.tour_size {
+            if let Some(tour_size_limit) = limits.tour_size.filter(|_| penalties.and_then(|p| p.tour_size).is_none()) {
                 let shift = context.get_vehicle_shift(tour)?;
 
                 let extra_activities = if shift.end.is_some() { 2 } else { 1 };
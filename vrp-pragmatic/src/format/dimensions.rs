@@ -16,6 +16,25 @@ custom_dimension!(JobOrder typeof i32);
 
 custom_dimension!(JobValue typeof f64);
 
+custom_dimension!(JobServiceWeight typeof f64);
+
+custom_dimension!(JobUnassignedCost typeof f64);
+
 custom_dimension!(JobType typeof String);
 
 custom_dimension!(BreakPolicy typeof BreakPolicy);
+
+custom_dimension!(VehicleSoftLimits typeof VehicleSoftLimits);
+
+/// Keeps soft vehicle limits: each limit is specified with a penalty per unit of its violation.
+#[derive(Clone, Debug, Default)]
+pub struct VehicleSoftLimits {
+    /// Max distance limit with its penalty.
+    pub max_distance: Option<(f64, f64)>,
+    /// Max duration limit with its penalty.
+    pub max_duration: Option<(f64, f64)>,
+    /// Tour size limit with its penalty.
+    pub tour_size: Option<(usize, f64)>,
+    /// Capacity penalty.
+    pub capacity: Option<f64>,
+}
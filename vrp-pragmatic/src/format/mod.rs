@@ -24,6 +24,7 @@ mod location_fallback;
 pub use self::location_fallback::*;
 
 pub mod problem;
+pub mod reoptimization;
 pub mod solution;
 
 /// Represents a location type.
@@ -109,7 +109,12 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
         let profile = Profile::new(index, vehicle.profile.scale);
 
-        let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let soft_limits = get_vehicle_soft_limits(vehicle.limits.as_ref());
+        let tour_size = vehicle
+            .limits
+            .as_ref()
+            .and_then(|l| l.tour_size)
+            .filter(|_| soft_limits.as_ref().and_then(|soft| soft.tour_size).is_none());
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
@@ -148,6 +153,10 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(soft_limits) = soft_limits.clone() {
+                    dimens.set_vehicle_soft_limits(soft_limits);
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_vehicle_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
@@ -227,3 +236,15 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         })
         .collect()
 }
+
+fn get_vehicle_soft_limits(limits: Option<&VehicleLimits>) -> Option<VehicleSoftLimits> {
+    let limits = limits?;
+    let penalties = limits.penalties.as_ref()?;
+
+    Some(VehicleSoftLimits {
+        max_distance: limits.max_distance.zip(penalties.max_distance),
+        max_duration: limits.max_duration.zip(penalties.max_duration),
+        tour_size: limits.tour_size.zip(penalties.tour_size),
+        capacity: penalties.capacity,
+    })
+}
@@ -69,7 +69,20 @@ pub(super) fn create_goal_context(
         )?);
     }
 
-    GoalContextBuilder::with_features(&features)?.set_main_goal(goal_builder.build()?).build()
+    let builder = GoalContextBuilder::with_features(&features)?.set_main_goal(goal_builder.build()?);
+    let builder = match get_cost_feature_name(&features) {
+        Some(name) => builder.set_cost_feature(name),
+        None => builder,
+    };
+
+    builder.build()
+}
+
+/// Returns a name of the feature which objective is used to minimize the cost of the solution.
+fn get_cost_feature_name(features: &[Feature]) -> Option<&str> {
+    ["min_cost", "min_distance", "min_duration"]
+        .into_iter()
+        .find(|name| features.iter().any(|feature| feature.name == *name && feature.objective.is_some()))
 }
 
 /// Layer retains information about whether a feature is defined as standalone or as having some competitive.
@@ -100,11 +113,7 @@ fn get_objective_feature_layer(
     props: &ProblemProperties,
 ) -> GenericResult<FeatureLayer> {
     let feature = match objective {
-        Objective::MinimizeCost => TransportFeatureBuilder::new("min_cost")
-            .set_violation_code(TIME_CONSTRAINT_CODE)
-            .set_transport_cost(blocks.transport.clone())
-            .set_activity_cost(blocks.activity.clone())
-            .build_minimize_cost(),
+        Objective::MinimizeCost => get_cost_feature("min_cost", blocks, props),
         Objective::MinimizeDistance => TransportFeatureBuilder::new("min_distance")
             .set_violation_code(TIME_CONSTRAINT_CODE)
             .set_transport_cost(blocks.transport.clone())
@@ -148,6 +157,11 @@ fn get_objective_feature_layer(
                 let break_value = *breaks;
                 let default_value = 1.;
                 move |_, job| {
+                    // NOTE jobs with unassignment cost are estimated by cost objective
+                    if job.dimens().get_job_unassigned_cost().is_some() {
+                        return 0.;
+                    }
+
                     if let Some(clusters) = job.dimens().get_cluster_info() {
                         clusters.len() as f64 * default_value
                     } else {
@@ -161,6 +175,29 @@ fn get_objective_feature_layer(
             .build(),
 
         Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
+        Objective::MinimizeMakespan => {
+            create_minimize_makespan_feature("min_makespan", blocks.transport.clone(), blocks.activity.clone())
+        }
+        Objective::MinimizeLoadDistance { weights } => {
+            let weights = weights.clone().unwrap_or_default();
+            let get_weight = move |idx: usize| weights.get(idx).copied().unwrap_or(1.);
+
+            if props.has_multi_dimen_capacity {
+                create_minimize_load_distance_feature::<MultiDimLoad>(
+                    "min_load_distance",
+                    blocks.transport.clone(),
+                    move |load| {
+                        load.load.iter().take(load.size).enumerate().map(|(idx, v)| *v as f64 * get_weight(idx)).sum()
+                    },
+                )
+            } else {
+                create_minimize_load_distance_feature::<SingleDimLoad>(
+                    "min_load_distance",
+                    blocks.transport.clone(),
+                    move |load| load.value as f64 * get_weight(0),
+                )
+            }
+        }
         Objective::BalanceMaxLoad => {
             if props.has_multi_dimen_capacity {
                 create_max_load_balanced_feature::<MultiDimLoad>(
@@ -193,6 +230,7 @@ fn get_objective_feature_layer(
         }
         Objective::TourOrder => create_tour_order_soft_feature("tour_order", get_tour_order_fn()),
         Objective::FastService => get_fast_service_feature("fast_service", blocks),
+        Objective::MinimizeLimitViolations => get_soft_limits_feature("min_limit_violations", blocks, props),
         Objective::MultiObjective { objectives, strategy: composition_type } => {
             let features = objectives
                 .iter()
@@ -214,6 +252,24 @@ fn get_objective_feature_layer(
     Ok(FeatureLayer::Single(feature))
 }
 
+fn get_cost_feature(name: &str, blocks: &ProblemBlocks, props: &ProblemProperties) -> GenericResult<Feature> {
+    let transport_feature = TransportFeatureBuilder::new(name)
+        .set_violation_code(TIME_CONSTRAINT_CODE)
+        .set_transport_cost(blocks.transport.clone())
+        .set_activity_cost(blocks.activity.clone())
+        .build_minimize_cost()?;
+
+    if !props.has_unassigned_costs {
+        return Ok(transport_feature);
+    }
+
+    let unassigned_cost_feature = MinimizeUnassignedBuilder::new("unassigned_cost")
+        .set_job_estimator(|_, job| job.dimens().get_job_unassigned_cost().copied().unwrap_or_default())
+        .build()?;
+
+    FeatureCombinator::default().use_name(name).add_features(&[transport_feature, unassigned_cost_feature]).combine()
+}
+
 fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Objective> {
     if let Some(objectives) = api_problem.objectives.clone() {
         objectives
@@ -221,6 +277,10 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ob
         let mut objectives =
             vec![Objective::MinimizeUnassigned { breaks: Some(1.) }, Objective::MinimizeTours, Objective::MinimizeCost];
 
+        if props.has_soft_limits {
+            objectives.insert(1, Objective::MinimizeLimitViolations)
+        }
+
         if props.has_value {
             objectives.insert(0, Objective::MaximizeValue { breaks: None })
         }
@@ -316,12 +376,25 @@ fn get_capacity_feature(
             })
         }
     } else if props.has_multi_dimen_capacity {
-        CapacityFeatureBuilder::<MultiDimLoad>::new(name).set_violation_code(CAPACITY_CONSTRAINT_CODE).build()
+        create_capacity_feature::<MultiDimLoad>(name, props)
     } else {
-        CapacityFeatureBuilder::<SingleDimLoad>::new(name).set_violation_code(CAPACITY_CONSTRAINT_CODE).build()
+        create_capacity_feature::<SingleDimLoad>(name, props)
     }
 }
 
+fn create_capacity_feature<T: LoadOps>(name: &str, props: &ProblemProperties) -> GenericResult<Feature> {
+    let builder = CapacityFeatureBuilder::<T>::new(name).set_violation_code(CAPACITY_CONSTRAINT_CODE);
+
+    if props.has_soft_limits {
+        builder.set_is_soft_capacity(|vehicle| {
+            vehicle.dimens.get_vehicle_soft_limits().is_some_and(|limits| limits.capacity.is_some())
+        })
+    } else {
+        builder
+    }
+    .build()
+}
+
 fn get_fast_service_feature(name: &str, blocks: &ProblemBlocks) -> GenericResult<Feature> {
     let (transport, activity) = (blocks.transport.clone(), blocks.activity.clone());
 
@@ -335,9 +408,39 @@ fn get_fast_service_feature(name: &str, blocks: &ProblemBlocks) -> GenericResult
             demand_single.map(|d| d.get_type()).or_else(|| demand_multi.map(|d| d.get_type()))
         })
         .set_is_filtered_job(|job| job.dimens().get_job_type().map_or(false, |job_type| job_type == "reload"))
+        .set_job_weight_fn(|job| job.dimens().get_job_service_weight().copied().unwrap_or(1.))
         .build()
 }
 
+fn get_soft_limits_feature(name: &str, blocks: &ProblemBlocks, props: &ProblemProperties) -> GenericResult<Feature> {
+    fn get_soft_limits(actor: &Actor) -> Option<&VehicleSoftLimits> {
+        actor.vehicle.dimens.get_vehicle_soft_limits()
+    }
+
+    let builder = SoftLimitsFeatureBuilder::new(name)
+        .set_transport(blocks.transport.clone())
+        .set_distance_limit(|actor| get_soft_limits(actor).and_then(|limits| limits.max_distance))
+        .set_duration_limit(|actor| get_soft_limits(actor).and_then(|limits| limits.max_duration))
+        .set_tour_size_limit(|actor| get_soft_limits(actor).and_then(|limits| limits.tour_size));
+
+    let get_capacity_penalty = |actor: &Actor| get_soft_limits(actor).and_then(|limits| limits.capacity);
+
+    if props.has_multi_dimen_capacity {
+        builder.set_capacity_limit::<MultiDimLoad, _, _>(
+            |load, capacity| {
+                load.load.iter().zip(capacity.load.iter()).map(|(load, capacity)| (load - capacity).max(0) as f64).sum()
+            },
+            get_capacity_penalty,
+        )
+    } else {
+        builder.set_capacity_limit::<SingleDimLoad, _, _>(
+            |load, capacity| (load.value - capacity.value).max(0) as f64,
+            get_capacity_penalty,
+        )
+    }
+    .build()
+}
+
 fn create_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<f64, Output = T>>(
     name: &str,
     api_problem: &ApiProblem,
@@ -393,13 +496,20 @@ fn get_tour_limit_feature(
         .iter()
         .filter_map(|vehicle| vehicle.limits.as_ref().map(|limits| (vehicle, limits)))
         .fold((HashMap::new(), HashMap::new()), |(mut distances, mut durations), (vehicle, limits)| {
-            limits.max_distance.iter().for_each(|max_distance| {
-                distances.insert(vehicle.type_id.clone(), *max_distance);
-            });
+            // NOTE soft limits are handled by a separate objective
+            let penalties = limits.penalties.as_ref();
 
-            limits.max_duration.iter().for_each(|max_duration| {
-                durations.insert(vehicle.type_id.clone(), *max_duration);
-            });
+            limits.max_distance.iter().filter(|_| penalties.and_then(|p| p.max_distance).is_none()).for_each(
+                |max_distance| {
+                    distances.insert(vehicle.type_id.clone(), *max_distance);
+                },
+            );
+
+            limits.max_duration.iter().filter(|_| penalties.and_then(|p| p.max_duration).is_none()).for_each(
+                |max_duration| {
+                    durations.insert(vehicle.type_id.clone(), *max_duration);
+                },
+            );
 
             (distances, durations)
         });
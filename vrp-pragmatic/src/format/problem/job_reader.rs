@@ -419,6 +419,14 @@ fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
         dimens.set_job_value(value);
     }
 
+    if let Some(weight) = job.service_weight {
+        dimens.set_job_service_weight(weight);
+    }
+
+    if let Some(cost) = job.unassigned_cost {
+        dimens.set_job_unassigned_cost(cost);
+    }
+
     if let Some(group) = job.group.clone() {
         dimens.set_job_group(group);
     }
@@ -113,6 +113,8 @@ struct ProblemProperties {
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_soft_limits: bool,
+    has_unassigned_costs: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -115,6 +115,15 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
 
+    /// Job service weight used by `fast-service` objective: bigger weight - earlier service.
+    #[serde(rename = "serviceWeight", skip_serializing_if = "Option::is_none")]
+    pub service_weight: Option<f64>,
+
+    /// Job unassignment cost: a price of leaving the job unassigned, e.g. outsourcing it, which is
+    /// added to the `minimize-cost` objective.
+    #[serde(rename = "unassignedCost", skip_serializing_if = "Option::is_none")]
+    pub unassigned_cost: Option<f64>,
+
     /// Job group: jobs of the same group are assigned to the same tour or unassigned.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
@@ -356,6 +365,32 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Penalties per unit of limit violation. When penalty is specified, the corresponding limit
+    /// becomes soft: it can be violated, but the penalty is minimized by `minimize-limit-violations` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalties: Option<VehicleLimitPenalties>,
+}
+
+/// Vehicle limit penalties which make limits soft.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleLimitPenalties {
+    /// A penalty per distance unit exceeding max distance limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<f64>,
+
+    /// A penalty per time unit exceeding max duration limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<f64>,
+
+    /// A penalty per job activity exceeding tour size limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tour_size: Option<f64>,
+
+    /// A penalty per load unit exceeding vehicle capacity in any dimension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<f64>,
 }
 
 /// Vehicle optional break time variant.
@@ -560,6 +595,19 @@ pub enum Objective {
     /// An objective to minimize sum of arrival times from all routes.
     MinimizeArrivalTime,
 
+    /// An objective to minimize makespan: the latest tour completion time across all routes.
+    MinimizeMakespan,
+
+    /// An objective to minimize load-distance: a sum of carried load multiplied by traveled distance on each leg.
+    MinimizeLoadDistance {
+        /// Specifies a weight per capacity dimension. Default is 1 for each dimension.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        weights: Option<Vec<f64>>,
+    },
+
+    /// An objective to minimize penalties of soft vehicle limits violations.
+    MinimizeLimitViolations,
+
     /// An objective to balance max load across all tours.
     BalanceMaxLoad,
 
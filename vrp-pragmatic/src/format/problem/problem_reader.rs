@@ -150,6 +150,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.);
+    let has_unassigned_costs = api_problem.plan.jobs.iter().any(|job| job.unassigned_cost.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
@@ -160,6 +161,13 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_soft_limits = api_problem.fleet.vehicles.iter().any(|v| {
+        v.limits
+            .as_ref()
+            .and_then(|l| l.penalties.as_ref())
+            .is_some_and(|p| p.max_distance.or(p.max_duration).or(p.tour_size).or(p.capacity).is_some())
+    });
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -173,6 +181,8 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_soft_limits,
+        has_unassigned_costs,
     }
 }
 
@@ -0,0 +1,386 @@
+//! Provides the way to re-optimize a solution while vehicles are en route.
+//!
+//! The idea is to take the current solution together with current vehicle positions and times and
+//! create a new problem where the executed part of each tour is frozen using a strict relation which
+//! starts from the departure and ends at a special job representing vehicle's current position.
+//! Remaining part of the current solution is used as an initial solution, so the search starts from
+//! the existing plan and new jobs are inserted there.
+
+#[cfg(test)]
+#[path = "../../tests/unit/format/reoptimization_test.rs"]
+mod reoptimization_test;
+
+use crate::format::problem::*;
+use crate::format::solution::{Activity, PointStop, Schedule, Solution, Stop};
+use crate::format::Location;
+use crate::validation::is_reserved_job_id;
+use crate::{format_time, parse_time, parse_time_safe};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+use vrp_core::prelude::GenericError;
+
+/// A time horizon used for vehicle position when vehicle shift has no end.
+const POSITION_HORIZON: f64 = 365. * 86400.;
+
+/// Specifies a current state of the vehicle which is en route.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleState {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Index of the vehicle shift which is executed. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+    /// Current vehicle location.
+    pub location: Location,
+    /// Current time in RFC3339 format: all activities of the vehicle's tour with arrival time
+    /// before or equal to it are considered as executed.
+    pub time: String,
+}
+
+/// Deserializes vehicle states in json format from `BufReader`.
+pub fn deserialize_vehicle_states<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleState>, GenericError> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize vehicle states: {err}").into())
+}
+
+/// Returns job id used to represent current position of the vehicle.
+pub fn get_position_job_id(vehicle_id: &str) -> String {
+    format!("{vehicle_id}_position")
+}
+
+/// Creates a problem and an initial solution for re-optimization of the current solution using
+/// given vehicle states:
+/// - executed jobs are kept in the plan, but locked to the vehicle in the executed order using strict
+///   relation; their time windows are removed as they are not relevant anymore;
+/// - a new job with zero duration is added to represent the vehicle's current position and time, it is
+///   the last job in the strict relation, so the rest of the tour starts from it;
+/// - remaining tasks of partially executed multi jobs are kept assigned to the same vehicle;
+/// - unexecuted part of the current solution is used as an initial solution;
+/// - if `keep_plan` relation type is specified, unexecuted jobs of en route vehicles are kept assigned to
+///   the same vehicle using a relation of that type, so the solver inserts new jobs without reassigning
+///   already planned ones. Jobs with multiple places or time windows and jobs from existing relations
+///   are not kept as relations cannot be used for them.
+///
+/// New jobs should be added to the problem plan before calling this function: they are not present in the
+/// current solution and will be inserted by the solver.
+/// NOTE: executed breaks, reloads and recharges are not frozen.
+pub fn create_reoptimization(
+    problem: &Problem,
+    solution: &Solution,
+    states: &[VehicleState],
+    keep_plan: Option<RelationType>,
+) -> Result<(Problem, Solution), GenericError> {
+    let mut problem = problem.clone();
+    let mut solution = solution.clone();
+    let mut relations = problem.plan.relations.take().unwrap_or_default();
+
+    let mut frozen = HashSet::new();
+    let mut new_relations = Vec::new();
+    let mut position_jobs = Vec::new();
+
+    for state in states {
+        let time = parse_time_safe(&state.time)?;
+        let shift_index = state.shift_index.unwrap_or(0);
+        let tour = solution
+            .tours
+            .iter_mut()
+            .find(|tour| tour.vehicle_id == state.vehicle_id && tour.shift_index == shift_index)
+            .ok_or_else(|| {
+                format!("vehicle '{}' has no tour with shift {shift_index} in the current solution", state.vehicle_id)
+            })?;
+        let shift = get_vehicle_shift(&problem, &tour.vehicle_id, tour.shift_index)?;
+        let end =
+            shift.end.as_ref().map(|end| end.latest.clone()).unwrap_or_else(|| format_time(time + POSITION_HORIZON));
+
+        let position_id = get_position_job_id(&state.vehicle_id);
+        if problem.plan.jobs.iter().any(|job| job.id == position_id) {
+            return Err(format!("job id '{position_id}' is reserved for vehicle position").into());
+        }
+
+        // NOTE departure stop is always executed, arrival stop is never executed
+        let executed_size = tour
+            .stops
+            .iter()
+            .enumerate()
+            .take_while(|(idx, stop)| {
+                *idx == 0
+                    || (parse_time(&stop.schedule().arrival) <= time
+                        && stop.activities().iter().all(|activity| activity.activity_type != "arrival"))
+            })
+            .count();
+
+        let executed = tour.stops[..executed_size]
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| is_job_activity(activity))
+            .map(|(stop, activity)| (activity, activity.location.clone().or_else(|| get_stop_location(stop))))
+            .collect::<Vec<_>>();
+
+        let mut executed_tasks = HashMap::<String, Vec<(&Activity, Option<Location>)>>::new();
+        executed.iter().for_each(|(activity, location)| {
+            executed_tasks.entry(activity.job_id.clone()).or_default().push((activity, location.clone()))
+        });
+
+        let mut strict_jobs = vec!["departure".to_string()];
+        let mut partial_jobs = HashSet::new();
+
+        for (job_id, tasks) in executed_tasks.iter() {
+            let job = problem
+                .plan
+                .jobs
+                .iter_mut()
+                .find(|job| job.id == *job_id)
+                .ok_or_else(|| format!("cannot find job '{job_id}' from the current solution in the plan"))?;
+
+            if freeze_job_tasks(job, tasks.as_slice())? {
+                frozen.insert(job_id.clone());
+            } else {
+                partial_jobs.insert(job_id.clone());
+            }
+        }
+
+        // NOTE keep execution order in strict relation
+        strict_jobs.extend(
+            executed.iter().map(|(activity, _)| activity.job_id.clone()).filter(|job_id| frozen.contains(job_id)),
+        );
+        strict_jobs.push(position_id.clone());
+
+        // NOTE executed tasks of partially executed jobs are removed from the plan, so remove them from the tour too
+        tour.stops.iter_mut().take(executed_size).for_each(|stop| {
+            stop.activities_mut()
+                .retain(|activity| !(is_job_activity(activity) && partial_jobs.contains(&activity.job_id)))
+        });
+        let mut stop_idx = 0;
+        let stops_size = tour.stops.len();
+        tour.stops.retain(|stop| {
+            let is_kept = stop_idx == 0 || stop_idx >= executed_size || !stop.activities().is_empty();
+            stop_idx += 1;
+            is_kept
+        });
+        let executed_size = executed_size - (stops_size - tour.stops.len());
+
+        new_relations.push(Relation {
+            type_field: RelationType::Strict,
+            jobs: strict_jobs,
+            vehicle_id: state.vehicle_id.clone(),
+            shift_index: Some(tour.shift_index),
+        });
+
+        let mut partial_jobs = partial_jobs.into_iter().collect::<Vec<_>>();
+        partial_jobs.sort();
+        new_relations.extend(
+            partial_jobs
+                .into_iter()
+                .filter_map(|job_id| problem.plan.jobs.iter().find(|job| job.id == job_id))
+                .filter(|job| !relations.iter().any(|relation| relation.jobs.contains(&job.id)))
+                .filter(|job| get_tasks(job).all(|task| task.places.len() == 1 && get_times_size(&task.places[0]) <= 1))
+                .map(|job| Relation {
+                    type_field: RelationType::Any,
+                    jobs: get_tasks(job).map(|_| job.id.clone()).collect(),
+                    vehicle_id: state.vehicle_id.clone(),
+                    shift_index: Some(tour.shift_index),
+                }),
+        );
+
+        position_jobs.push(Job {
+            id: position_id.clone(),
+            pickups: None,
+            deliveries: None,
+            replacements: None,
+            services: Some(vec![JobTask {
+                places: vec![JobPlace {
+                    location: state.location.clone(),
+                    duration: 0.,
+                    times: Some(vec![vec![state.time.clone(), end]]),
+                    tag: None,
+                }],
+                demand: None,
+                order: None,
+            }]),
+            skills: None,
+            value: None,
+            service_weight: None,
+            unassigned_cost: None,
+            group: None,
+            compatibility: None,
+        });
+
+        let distance = tour.stops[..executed_size]
+            .iter()
+            .rev()
+            .find_map(|stop| match stop {
+                Stop::Point(point) => Some(point.distance),
+                Stop::Transit(_) => None,
+            })
+            .unwrap_or_default();
+        let load = tour.stops[executed_size - 1].load().clone();
+        tour.stops.insert(
+            executed_size,
+            Stop::Point(PointStop {
+                location: state.location.clone(),
+                time: Schedule { arrival: state.time.clone(), departure: state.time.clone() },
+                distance,
+                load,
+                parking: None,
+                activities: vec![Activity {
+                    job_id: position_id,
+                    activity_type: "service".to_string(),
+                    location: None,
+                    time: None,
+                    job_tag: None,
+                    commute: None,
+                }],
+            }),
+        );
+
+        if let Some(relation_type) = keep_plan.as_ref() {
+            let kept_jobs = tour.stops[executed_size + 1..]
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| is_job_activity(activity))
+                .map(|activity| activity.job_id.clone())
+                .filter(|job_id| !relations.iter().chain(new_relations.iter()).any(|r| r.jobs.contains(job_id)))
+                .filter(|job_id| {
+                    problem.plan.jobs.iter().find(|job| job.id == *job_id).is_some_and(|job| {
+                        get_tasks(job).all(|task| task.places.len() == 1 && get_times_size(&task.places[0]) <= 1)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            if !kept_jobs.is_empty() {
+                new_relations.push(Relation {
+                    type_field: relation_type.clone(),
+                    jobs: kept_jobs,
+                    vehicle_id: state.vehicle_id.clone(),
+                    shift_index: Some(tour.shift_index),
+                });
+            }
+        }
+    }
+
+    // NOTE existing relations of en route vehicles which start from departure conflict with the frozen part
+    let en_route = solution
+        .tours
+        .iter()
+        .filter(|tour| {
+            states
+                .iter()
+                .any(|state| state.vehicle_id == tour.vehicle_id && state.shift_index.unwrap_or(0) == tour.shift_index)
+        })
+        .map(|tour| (tour.vehicle_id.clone(), tour.shift_index))
+        .collect::<HashSet<_>>();
+    relations.iter_mut().for_each(|relation| relation.jobs.retain(|job_id| !frozen.contains(job_id)));
+    relations.retain(|relation| {
+        let is_en_route = en_route.contains(&(relation.vehicle_id.clone(), relation.shift_index.unwrap_or(0)));
+        let has_departure = relation.jobs.first().is_some_and(|job_id| job_id == "departure");
+        let has_jobs = relation.jobs.iter().any(|job_id| !is_reserved_job_id(job_id));
+
+        has_jobs && !(is_en_route && has_departure)
+    });
+    relations.extend(new_relations);
+
+    problem.plan.jobs.extend(position_jobs);
+    problem.plan.relations = Some(relations);
+
+    Ok((problem, solution))
+}
+
+/// Removes time windows and alternative places of executed tasks. Returns true if all job tasks are
+/// executed, otherwise removes executed tasks from the job and returns false.
+fn freeze_job_tasks(job: &mut Job, executed: &[(&Activity, Option<Location>)]) -> Result<bool, GenericError> {
+    let job_id = job.id.clone();
+    let mut matched = HashMap::<&str, HashSet<usize>>::new();
+
+    for (activity, location) in executed {
+        let activity_type = activity.activity_type.as_str();
+        let tasks = get_tasks_by_type(job, activity_type)
+            .ok_or_else(|| format!("job '{job_id}' has no {activity_type} tasks"))?;
+        let used = matched.entry(activity_type).or_default();
+
+        let is_matched = |place: &JobPlace| match (&activity.job_tag, &place.tag) {
+            (Some(job_tag), Some(tag)) => job_tag == tag,
+            _ => location.as_ref().is_some_and(|location| *location == place.location),
+        };
+
+        let task_idx = (0..tasks.len())
+            .filter(|idx| !used.contains(idx))
+            .find(|&idx| tasks[idx].places.iter().any(is_matched))
+            .or_else(|| (0..tasks.len()).find(|idx| !used.contains(idx)))
+            .ok_or_else(|| format!("cannot match executed {activity_type} activity of job '{job_id}'"))?;
+        used.insert(task_idx);
+
+        let task = &mut tasks[task_idx];
+        let place_idx = task.places.iter().position(is_matched);
+        let mut place = task.places.swap_remove(place_idx.unwrap_or_default());
+        place.times = None;
+        task.places = vec![place];
+    }
+
+    let executed_size = matched.values().map(|indices| indices.len()).sum::<usize>();
+    if executed_size == get_tasks(job).count() {
+        return Ok(true);
+    }
+
+    matched.into_iter().for_each(|(activity_type, indices)| {
+        if let Some(tasks) = get_tasks_by_type(job, activity_type) {
+            let mut idx = 0;
+            tasks.retain(|_| {
+                let is_executed = indices.contains(&idx);
+                idx += 1;
+                !is_executed
+            });
+        }
+    });
+
+    [&mut job.pickups, &mut job.deliveries, &mut job.replacements, &mut job.services].into_iter().for_each(|tasks| {
+        if tasks.as_ref().is_some_and(|tasks| tasks.is_empty()) {
+            *tasks = None;
+        }
+    });
+
+    Ok(false)
+}
+
+fn get_vehicle_shift<'a>(
+    problem: &'a Problem,
+    vehicle_id: &str,
+    shift_index: usize,
+) -> Result<&'a VehicleShift, GenericError> {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.vehicle_ids.iter().any(|id| id == vehicle_id))
+        .and_then(|vehicle| vehicle.shifts.get(shift_index))
+        .ok_or_else(|| format!("cannot find shift {shift_index} of vehicle '{vehicle_id}'").into())
+}
+
+fn get_tasks(job: &Job) -> impl Iterator<Item = &JobTask> + '_ {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services].into_iter().flatten().flatten()
+}
+
+fn get_tasks_by_type<'a>(job: &'a mut Job, activity_type: &str) -> Option<&'a mut Vec<JobTask>> {
+    match activity_type {
+        "pickup" => job.pickups.as_mut(),
+        "delivery" => job.deliveries.as_mut(),
+        "replacement" => job.replacements.as_mut(),
+        "service" => job.services.as_mut(),
+        _ => None,
+    }
+}
+
+fn get_times_size(place: &JobPlace) -> usize {
+    place.times.as_ref().map_or(0, |times| times.len())
+}
+
+fn get_stop_location(stop: &Stop) -> Option<Location> {
+    match stop {
+        Stop::Point(point) => Some(point.location.clone()),
+        Stop::Transit(_) => None,
+    }
+}
+
+fn is_job_activity(activity: &Activity) -> bool {
+    matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service")
+}
@@ -278,6 +278,19 @@ pub enum Violation {
         /// Index of the shift.
         shift_index: usize,
     },
+
+    /// A soft vehicle limit violation.
+    #[serde(rename(deserialize = "limit", serialize = "limit"), rename_all = "camelCase")]
+    Limit {
+        /// An id of a vehicle which limit is violated.
+        vehicle_id: String,
+        /// Index of the shift.
+        shift_index: usize,
+        /// A violated limit name: `maxDistance`, `maxDuration`, `tourSize` or `capacity`.
+        limit: String,
+        /// An amount of units exceeding the limit.
+        excess: i64,
+    },
 }
 
 /// Encapsulates different measurements regarding algorithm evaluation.
@@ -329,8 +342,21 @@ pub struct Population {
     pub individuals: Vec<Individual>,
 }
 
+/// Contains a lower bound estimation of the solution cost.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LowerBound {
+    /// A lower bound of the total cost.
+    pub cost: f64,
+    /// A relative gap between the solution cost and its lower bound.
+    /// Not set when some jobs are unassigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gap: Option<f64>,
+}
+
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -339,6 +365,10 @@ pub struct Extras {
     /// Represents solution as a collection of geo json features.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub features: Option<FeatureCollection>,
+
+    /// A lower bound estimation of the solution cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower_bound: Option<LowerBound>,
 }
 
 /// A VRP solution.
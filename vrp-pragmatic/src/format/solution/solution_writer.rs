@@ -7,13 +7,14 @@ use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::CoordIndex;
 use vrp_core::construction::enablers::{get_route_intervals, ReservedTimesIndex};
-use vrp_core::construction::features::JobDemandDimension;
+use vrp_core::construction::features::{JobDemandDimension, VehicleCapacityDimension};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
+use vrp_core::solver::{is_complete_assignment, LowerBoundExtraProperty};
 use vrp_core::utils::CollectGroupBy;
 
 struct Leg {
@@ -53,11 +54,12 @@ pub(crate) fn create_solution(
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
     let unassigned = create_unassigned(solution);
-    let violations = create_violations(solution);
+    let violations = create_violations(solution, tours.as_slice());
 
     let api_solution = ApiSolution { statistic, tours, unassigned, violations, extras: None };
 
-    let extras = create_extras(problem, &api_solution, solution.telemetry.as_ref(), output_type);
+    let is_complete = is_complete_assignment(solution.unassigned.iter().map(|(job, _)| job));
+    let extras = create_extras(problem, &api_solution, is_complete, solution.telemetry.as_ref(), output_type);
 
     ApiSolution { extras, ..api_solution }
 }
@@ -339,36 +341,44 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
 
             let reasons = match code {
                 UnassignmentInfo::Simple(code) => create_simple_reasons(*code),
-                UnassignmentInfo::Detailed(details) if !details.is_empty() => details
-                    .iter()
-                    .collect_group_by_key(|(_, code)| *code)
-                    .into_iter()
-                    .map(|(code, group)| {
-                        let (code, reason) = map_code_reason(code);
-                        let mut vehicle_details = group
-                            .iter()
-                            .map(|(actor, _)| {
-                                let dimens = &actor.vehicle.dimens;
-                                let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
-                                let shift_index = dimens.get_shift_index().copied().unwrap();
-                                (vehicle_id, shift_index)
-                            })
-                            .collect::<Vec<_>>();
-                        // NOTE sort to have consistent order
-                        vehicle_details.sort();
-
-                        UnassignedJobReason {
-                            details: Some(
-                                vehicle_details
-                                    .into_iter()
-                                    .map(|(vehicle_id, shift_index)| UnassignedJobDetail { vehicle_id, shift_index })
-                                    .collect(),
-                            ),
-                            code: code.to_string(),
-                            description: reason.to_string(),
-                        }
-                    })
-                    .collect(),
+                UnassignmentInfo::Detailed(details) if !details.is_empty() => {
+                    let mut groups =
+                        details.iter().collect_group_by_key(|(_, code)| *code).into_iter().collect::<Vec<_>>();
+                    // NOTE sort to have consistent order
+                    groups.sort_by_key(|(code, _)| *code);
+
+                    groups
+                        .into_iter()
+                        .map(|(code, group)| {
+                            let (code, reason) = map_code_reason(code);
+                            let mut vehicle_details = group
+                                .iter()
+                                .map(|(actor, _)| {
+                                    let dimens = &actor.vehicle.dimens;
+                                    let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
+                                    let shift_index = dimens.get_shift_index().copied().unwrap();
+                                    (vehicle_id, shift_index)
+                                })
+                                .collect::<Vec<_>>();
+                            // NOTE sort to have consistent order
+                            vehicle_details.sort();
+
+                            UnassignedJobReason {
+                                details: Some(
+                                    vehicle_details
+                                        .into_iter()
+                                        .map(|(vehicle_id, shift_index)| UnassignedJobDetail {
+                                            vehicle_id,
+                                            shift_index,
+                                        })
+                                        .collect(),
+                                ),
+                                code: code.to_string(),
+                                description: reason.to_string(),
+                            }
+                        })
+                        .collect()
+                }
                 _ => create_simple_reasons(0),
             };
 
@@ -383,8 +393,8 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
-fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
-    // NOTE at the moment only break violation is mapped
+fn create_violations(solution: &DomainSolution, tours: &[Tour]) -> Option<Vec<Violation>> {
+    // NOTE at the moment only break and soft limit violations are mapped
     let violations = solution
         .unassigned
         .iter()
@@ -393,6 +403,7 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
             vehicle_id: job.dimens().get_vehicle_id().expect("vehicle id").clone(),
             shift_index: job.dimens().get_shift_index().copied().expect("shift index"),
         })
+        .chain(solution.routes.iter().zip(tours.iter()).flat_map(|(route, tour)| create_limit_violations(route, tour)))
         .collect::<Vec<_>>();
 
     if violations.is_empty() {
@@ -402,6 +413,43 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     }
 }
 
+fn create_limit_violations(route: &Route, tour: &Tour) -> Vec<Violation> {
+    let vehicle = route.actor.vehicle.as_ref();
+    let Some(soft_limits) = vehicle.dimens.get_vehicle_soft_limits() else { return vec![] };
+
+    let max_distance = soft_limits.max_distance.map(|(limit, _)| tour.statistic.distance - limit.round() as i64);
+    let max_duration = soft_limits.max_duration.map(|(limit, _)| tour.statistic.duration - limit.round() as i64);
+    let tour_size = soft_limits.tour_size.map(|(limit, _)| route.tour.job_activity_count() as i64 - limit as i64);
+    let capacity = soft_limits.capacity.and_then(|_| {
+        let capacity =
+            vehicle.dimens.get_vehicle_capacity::<MultiDimLoad>().map(|capacity| capacity.as_vec()).or_else(|| {
+                vehicle.dimens.get_vehicle_capacity::<SingleDimLoad>().map(|capacity| vec![capacity.value])
+            })?;
+
+        Some(
+            capacity
+                .iter()
+                .enumerate()
+                .map(|(idx, capacity)| {
+                    let max_load = tour.stops.iter().filter_map(|stop| stop.load().get(idx)).max().copied();
+                    (max_load.unwrap_or_default() - capacity).max(0) as i64
+                })
+                .sum::<i64>(),
+        )
+    });
+
+    [("maxDistance", max_distance), ("maxDuration", max_duration), ("tourSize", tour_size), ("capacity", capacity)]
+        .into_iter()
+        .filter_map(|(limit, excess)| excess.filter(|excess| *excess > 0).map(|excess| (limit, excess)))
+        .map(|(limit, excess)| Violation::Limit {
+            vehicle_id: tour.vehicle_id.clone(),
+            shift_index: tour.shift_index,
+            limit: limit.to_string(),
+            excess,
+        })
+        .collect()
+}
+
 fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
@@ -433,12 +481,20 @@ fn get_parking_time(extras: &DomainExtras) -> f64 {
 fn create_extras(
     problem: &DomainProblem,
     solution: &ApiSolution,
+    is_complete: bool,
     metrics: Option<&TelemetryMetrics>,
     output_type: &PragmaticOutputType,
 ) -> Option<Extras> {
     match output_type {
         PragmaticOutputType::OnlyPragmatic => {
-            get_api_metrics(metrics).map(|metrics| Extras { metrics: Some(metrics), features: None })
+            let metrics = get_api_metrics(metrics);
+            let lower_bound = get_api_lower_bound(problem, solution, is_complete);
+
+            if metrics.is_some() || lower_bound.is_some() {
+                Some(Extras { metrics, features: None, lower_bound })
+            } else {
+                None
+            }
         }
         PragmaticOutputType::OnlyGeoJson => None,
         PragmaticOutputType::Combined => {
@@ -446,11 +502,21 @@ fn create_extras(
                 metrics: get_api_metrics(metrics),
                 // TODO do not hide error here, propagate it to the caller
                 features: create_feature_collection(problem, solution).ok(),
+                lower_bound: get_api_lower_bound(problem, solution, is_complete),
             })
         }
     }
 }
 
+fn get_api_lower_bound(problem: &DomainProblem, solution: &ApiSolution, is_complete: bool) -> Option<LowerBound> {
+    problem.extras.get_lower_bound().map(|lower_bound| {
+        // NOTE use the same completeness check as target gap termination does
+        let gap = if is_complete { Some(lower_bound.get_gap(solution.statistic.cost)) } else { None };
+
+        LowerBound { cost: lower_bound.cost, gap }
+    })
+}
+
 fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
     metrics.as_ref().map(|metrics| ApiMetrics {
         duration: metrics.duration,
@@ -179,6 +179,44 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job has positive service weight.
+fn check_e1108_positive_service_weight(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.service_weight.is_some_and(|weight| !weight.is_finite() || weight <= 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job has non positive service weight".to_string(),
+            format!("fix service weight in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job has non negative unassigned cost.
+fn check_e1109_non_negative_unassigned_cost(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.unassigned_cost.is_some_and(|cost| !cost.is_finite() || cost < 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "job has invalid unassigned cost".to_string(),
+            format!("fix unassigned cost in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +228,8 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_positive_service_weight(ctx),
+        check_e1109_non_negative_unassigned_cost(ctx),
     ])
     .map_err(From::from)
 }
@@ -87,6 +87,6 @@ impl<'a> ValidationContext<'a> {
     }
 }
 
-fn is_reserved_job_id(job_id: &str) -> bool {
+pub(crate) fn is_reserved_job_id(job_id: &str) -> bool {
     job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload"
 }
@@ -166,6 +166,77 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that load distance objective has valid weights.
+fn check_e1608_load_distance_weights(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid_weights = get_objectives_flattened(objectives)
+        .filter_map(|objective| match objective {
+            MinimizeLoadDistance { weights } => weights.as_ref(),
+            _ => None,
+        })
+        .flat_map(|weights| weights.iter())
+        .any(|weight| !weight.is_finite() || *weight < 0.);
+
+    if has_invalid_weights {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "invalid load distance weights".to_string(),
+            "make sure that all weights of 'minimize-load-distance' objective are non negative".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that limit violations objective is specified when some vehicles have soft limits.
+fn check_e1609_soft_limits_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_no_limits_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeLimitViolations));
+    let has_soft_limits = ctx
+        .vehicles()
+        .filter_map(|vehicle| vehicle.limits.as_ref().and_then(|limits| limits.penalties.as_ref()))
+        .any(|penalties| {
+            [penalties.max_distance, penalties.max_duration, penalties.tour_size, penalties.capacity]
+                .iter()
+                .any(Option::is_some)
+        });
+
+    if has_no_limits_objective && has_soft_limits {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "missing limit violations objective".to_string(),
+            "specify 'minimize-limit-violations' objective, remove objectives property or remove penalties from \
+             vehicle limits"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that cost objective is specified when some jobs have unassigned cost property set.
+fn check_e1610_jobs_with_unassigned_cost_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_no_cost_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeCost));
+    let has_jobs_with_cost = ctx.problem.plan.jobs.iter().any(|job| job.unassigned_cost.is_some());
+
+    if has_no_cost_objective && has_jobs_with_cost {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "missing cost objective for unassigned cost".to_string(),
+            "specify 'minimize-cost' objective, remove objectives property or remove unassigned cost property from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
@@ -188,6 +259,9 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_load_distance_weights(&objectives),
+            check_e1609_soft_limits_but_no_objective(ctx, &objectives),
+            check_e1610_jobs_with_unassigned_cost_but_no_objective(ctx, &objectives),
         ])
         .map_err(From::from)
     } else {
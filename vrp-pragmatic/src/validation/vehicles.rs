@@ -264,6 +264,43 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle limit penalties are defined correctly.
+fn check_e1309_vehicle_limit_penalties(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|vehicle, shift, _| {
+            let Some(limits) = vehicle.limits.as_ref() else { return true };
+            let Some(penalties) = limits.penalties.as_ref() else { return true };
+
+            let has_valid_values =
+                [penalties.max_distance, penalties.max_duration, penalties.tour_size, penalties.capacity]
+                    .into_iter()
+                    .flatten()
+                    .all(|penalty| penalty.is_finite() && penalty > 0.);
+            let has_limits = (penalties.max_distance.is_none() || limits.max_distance.is_some())
+                && (penalties.max_duration.is_none() || limits.max_duration.is_some())
+                && (penalties.tour_size.is_none() || limits.tour_size.is_some());
+            let has_no_reloads = penalties.capacity.is_none() || shift.reloads.is_none();
+
+            has_valid_values && has_limits && has_no_reloads
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle limit penalties".to_string(),
+            format!(
+                "make sure that penalties are positive, defined only for existing limits and capacity penalty is \
+                 not used with reloads, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -311,6 +348,7 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_limit_penalties(ctx),
     ])
     .map_err(From::from)
 }
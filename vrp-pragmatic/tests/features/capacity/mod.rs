@@ -1 +1,2 @@
+mod load_distance_test;
 mod simple_capacity_test;
@@ -1,5 +1,7 @@
 use super::*;
 use std::iter::once;
+use std::sync::Arc;
+use vrp_core::solver::{SolverHandle, VrpConfigBuilder};
 
 parameterized_test! {can_cluster_simple_jobs, (visiting, serving, stop2, stop3_schedule, statistic), {
     can_cluster_simple_jobs_impl(visiting, serving, StopData::new(stop2), stop3_schedule, statistic);
@@ -233,3 +235,41 @@ fn can_handle_two_clusters_impl(
             .build()
     );
 }
+
+#[test]
+fn can_unwrap_clusters_in_best_so_far_solution_of_solver_handle() {
+    let problem = create_test_problem(
+        &[(1., "delivery"), (2., "delivery"), (3., "delivery"), (10., "delivery")],
+        10,
+        Clustering::Vicinity {
+            profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+            threshold: VicinityThresholdPolicy {
+                duration: 3.,
+                distance: 3.,
+                min_shared_time: None,
+                smallest_time_window: None,
+                max_jobs_per_cluster: None,
+            },
+            visiting: VicinityVisitPolicy::Continue,
+            serving: VicinityServingPolicy::Original { parking: 0. },
+            filtering: None,
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let handle = SolverHandle::start(problem.clone(), move || {
+        VrpConfigBuilder::new(problem).prebuild()?.with_max_generations(Some(usize::MAX)).build()
+    });
+
+    let solution = loop {
+        if let Some(solution) = handle.best_so_far() {
+            break solution;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+    handle.stop();
+    handle.join().expect("cannot get solution");
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.iter().map(|route| route.tour.job_activity_count()).sum::<usize>(), 4);
+}
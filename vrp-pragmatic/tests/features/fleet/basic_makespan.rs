@@ -0,0 +1,41 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_use_more_vehicles_with_minimize_makespan_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeMakespan,
+            Objective::MinimizeCost,
+        ]),
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![4],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    solution.tours.iter().filter_map(|tour| tour.stops.last()).for_each(|stop| {
+        assert!(parse_time(&stop.schedule().arrival) <= 9., "unexpected tour end: {}", stop.schedule().arrival);
+    });
+}
@@ -1,3 +1,4 @@
+mod basic_makespan;
 mod basic_multi_shift;
 mod basic_open_end;
 mod multi_dimens;
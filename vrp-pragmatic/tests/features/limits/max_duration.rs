@@ -4,7 +4,12 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_max_duration_limit(max_duration: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, max_duration: Some(max_duration), tour_size: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            max_duration: Some(max_duration),
+            tour_size: None,
+            penalties: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -1,3 +1,4 @@
 mod max_distance;
 mod max_duration;
+mod soft_limits;
 mod tour_size;
@@ -0,0 +1,92 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_soft_limits(max_distance: Option<f64>, penalties: VehicleLimitPenalties) -> Option<VehicleLimits> {
+    Some(VehicleLimits { max_distance, max_duration: None, tour_size: None, penalties: Some(penalties) })
+}
+
+#[test]
+fn can_violate_soft_max_distance_limit() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (100., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: create_soft_limits(
+                    Some(99.),
+                    VehicleLimitPenalties {
+                        max_distance: Some(10.),
+                        max_duration: None,
+                        tour_size: None,
+                        capacity: None,
+                    },
+                ),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![1, 1, 1, 1],
+        distances: vec![1, 100, 100, 1],
+        error_codes: None,
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::Limit {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            limit: "maxDistance".to_string(),
+            excess: 101,
+        }])
+    );
+}
+
+#[test]
+fn can_violate_soft_capacity_limit() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: create_soft_limits(
+                    None,
+                    VehicleLimitPenalties {
+                        max_distance: None,
+                        max_duration: None,
+                        tour_size: None,
+                        capacity: Some(1.),
+                    },
+                ),
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::Limit {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            limit: "capacity".to_string(),
+            excess: 1,
+        }])
+    );
+}
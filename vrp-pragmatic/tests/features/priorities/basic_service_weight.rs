@@ -0,0 +1,36 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_serve_heavier_job_first_with_fast_service_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (3., 0.)),
+                Job { service_weight: Some(10.), ..create_delivery_job("job2", (-3., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::FastService,
+            Objective::MinimizeCost,
+        ]),
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["job2", "job1"]);
+}
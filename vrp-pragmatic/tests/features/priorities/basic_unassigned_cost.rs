@@ -0,0 +1,32 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_unassigned_cost, (unassigned_cost, expected), {
+    can_use_unassigned_cost_impl(unassigned_cost, expected);
+}}
+
+can_use_unassigned_cost! {
+    case01_cheap_to_skip: (10., vec!["job2"]),
+    case02_expensive_to_skip: (10000., vec![]),
+}
+
+fn can_use_unassigned_cost_impl(unassigned_cost: f64, expected: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job { unassigned_cost: Some(unassigned_cost), ..create_delivery_job("job2", (100., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(unassigned, expected);
+    assert_eq!(solution.tours.len(), 1);
+}
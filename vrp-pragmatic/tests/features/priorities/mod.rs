@@ -1,2 +1,4 @@
 mod basic_order;
+mod basic_service_weight;
+mod basic_unassigned_cost;
 mod basic_value;
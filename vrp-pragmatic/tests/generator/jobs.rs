@@ -89,6 +89,8 @@ prop_compose! {
             services: None,
             skills,
             value,
+            service_weight: None,
+            unassigned_cost: None,
             group,
             compatibility
         }
@@ -135,6 +137,8 @@ prop_compose! {
             services,
             skills,
             value,
+            service_weight: None,
+            unassigned_cost: None,
             group,
             compatibility,
         }
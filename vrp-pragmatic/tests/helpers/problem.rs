@@ -24,6 +24,8 @@ pub fn create_job(id: &str) -> Job {
         services: None,
         skills: None,
         value: None,
+        service_weight: None,
+        unassigned_cost: None,
         group: None,
         compatibility: None,
     }
@@ -0,0 +1,234 @@
+use super::*;
+use crate::format::solution::create_solution;
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::solver::{Solver, VrpConfigBuilder};
+use vrp_core::utils::Environment;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_multi_job(
+                    "job2",
+                    vec![((2., 0.), 1., vec![1]), ((3., 0.), 1., vec![1])],
+                    vec![((4., 0.), 1., vec![2])],
+                ),
+                create_delivery_job("job3", (5., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> Solution {
+    SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![2])
+                        .distance(2)
+                        .build_single_tag("job2", "pickup", "p1"),
+                    StopBuilder::default()
+                        .coordinate((3., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![3])
+                        .distance(3)
+                        .build_single_tag("job2", "pickup", "p2"),
+                    StopBuilder::default()
+                        .coordinate((4., 0.))
+                        .schedule_stamp(7., 8.)
+                        .load(vec![1])
+                        .distance(4)
+                        .build_single_tag("job2", "delivery", "d1"),
+                    StopBuilder::default()
+                        .coordinate((5., 0.))
+                        .schedule_stamp(9., 10.)
+                        .load(vec![0])
+                        .distance(5)
+                        .build_single("job3", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(15., 15.)
+                        .load(vec![0])
+                        .distance(10)
+                        .build_arrival(),
+                ])
+                .statistic(StatisticBuilder::default().driving(10).serving(5).build())
+                .build(),
+        )
+        .build()
+}
+
+fn create_state(time: f64) -> VehicleState {
+    VehicleState {
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: None,
+        location: (2., 0.).to_loc(),
+        time: format_time(time),
+    }
+}
+
+fn get_relation(problem: &Problem, relation_type: RelationType) -> Option<&Relation> {
+    problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .find(|relation| std::mem::discriminant(&relation.type_field) == std::mem::discriminant(&relation_type))
+}
+
+fn get_job<'a>(problem: &'a Problem, job_id: &str) -> &'a Job {
+    problem.plan.jobs.iter().find(|job| job.id == job_id).unwrap()
+}
+
+#[test]
+fn can_freeze_executed_part_of_tour() {
+    let problem = create_test_problem();
+    let solution = create_test_solution();
+
+    let (problem, solution) = create_reoptimization(&problem, &solution, &[create_state(3.)], None).unwrap();
+
+    let strict = get_relation(&problem, RelationType::Strict).unwrap();
+    assert_eq!(strict.jobs, to_strings(vec!["departure", "job1", "my_vehicle_1_position"]));
+    assert_eq!(strict.vehicle_id, "my_vehicle_1");
+    assert!(get_job(&problem, "job1").deliveries.as_ref().unwrap()[0].places[0].times.is_none());
+
+    let any = get_relation(&problem, RelationType::Any).unwrap();
+    assert_eq!(any.jobs, to_strings(vec!["job2", "job2"]));
+    let job2 = get_job(&problem, "job2");
+    assert_eq!(job2.pickups.as_ref().unwrap().len(), 1);
+    assert_eq!(job2.pickups.as_ref().unwrap()[0].places[0].tag.as_deref(), Some("p2"));
+    assert_eq!(job2.deliveries.as_ref().unwrap().len(), 1);
+
+    let position = get_job(&problem, "my_vehicle_1_position");
+    let times = position.services.as_ref().unwrap()[0].places[0].times.clone().unwrap();
+    assert_eq!(times, vec![vec![format_time(3.), format_time(1000.)]]);
+
+    let tour = &solution.tours[0];
+    assert_eq!(tour.stops.len(), 7);
+    assert_eq!(tour.stops[2].activities()[0].job_id, "my_vehicle_1_position");
+    assert_eq!(tour.stops[2].schedule().arrival, format_time(3.));
+    assert!(tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .all(|activity| activity.job_tag.as_deref() != Some("p1")));
+}
+
+#[test]
+fn can_solve_reoptimization_problem_with_new_job() {
+    let mut problem = create_test_problem();
+    let solution = create_test_solution();
+    problem.plan.jobs.push(create_delivery_job("job4", (1., 1.)));
+
+    let (problem, solution) = create_reoptimization(&problem, &solution, &[create_state(5.)], None).unwrap();
+
+    let environment = Arc::new(Environment::default());
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let init_solution = to_core_solution(&solution, core_problem.clone(), create_random()).unwrap();
+    let init_solution = InsertionContext::new_from_solution(core_problem.clone(), (init_solution, None), environment);
+    let config = VrpConfigBuilder::new(core_problem.clone())
+        .prebuild()
+        .unwrap()
+        .with_init_solutions(vec![init_solution], None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let core_solution = Solver::new(core_problem.clone(), config).solve().unwrap();
+    let result = create_solution(&core_problem, &core_solution, &Default::default());
+
+    assert!(result.unassigned.is_none());
+    assert_eq!(result.tours.len(), 1);
+    let ids = get_ids_from_tour(&result.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(ids[..3], to_strings(vec!["departure", "job1", "my_vehicle_1_position"]));
+    assert!(ids.contains(&"job2".to_string()));
+    assert!(ids.contains(&"job4".to_string()));
+}
+
+#[test]
+fn can_keep_unrelated_relations() {
+    let mut problem = create_test_problem();
+    problem.plan.relations = Some(vec![
+        Relation {
+            type_field: RelationType::Strict,
+            jobs: to_strings(vec!["departure", "job1", "job3"]),
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+        },
+        Relation {
+            type_field: RelationType::Sequence,
+            jobs: to_strings(vec!["job1", "job3"]),
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+        },
+    ]);
+
+    let (problem, _) = create_reoptimization(&problem, &create_test_solution(), &[create_state(3.)], None).unwrap();
+
+    let relations = problem.plan.relations.unwrap();
+    assert_eq!(relations.len(), 3);
+    assert_eq!(relations[0].jobs, to_strings(vec!["job3"]));
+}
+
+#[test]
+fn cannot_reoptimize_vehicle_without_tour() {
+    let state = VehicleState { vehicle_id: "unknown".to_string(), ..create_state(3.) };
+
+    let result = create_reoptimization(&create_test_problem(), &create_test_solution(), &[state], None);
+
+    assert!(result.is_err());
+}
+
+parameterized_test! {can_keep_plan_of_unexecuted_jobs, (relation_type, expected_jobs), {
+    can_keep_plan_of_unexecuted_jobs_impl(relation_type, to_strings(expected_jobs));
+}}
+
+can_keep_plan_of_unexecuted_jobs! {
+    case01_sequence: (RelationType::Sequence, vec!["job3"]),
+    case02_strict: (RelationType::Strict, vec!["job3"]),
+}
+
+fn can_keep_plan_of_unexecuted_jobs_impl(relation_type: RelationType, expected_jobs: Vec<String>) {
+    let problem = create_test_problem();
+    let solution = create_test_solution();
+
+    let (problem, _) = create_reoptimization(&problem, &solution, &[create_state(3.)], Some(relation_type)).unwrap();
+
+    let relations = problem.plan.relations.unwrap();
+    assert_eq!(relations.len(), 3);
+    assert_eq!(relations[2].jobs, expected_jobs);
+    assert_eq!(relations[2].vehicle_id, "my_vehicle_1");
+}
+
+#[test]
+fn cannot_reoptimize_vehicle_with_wrong_shift_index() {
+    let state = VehicleState { shift_index: Some(1), ..create_state(3.) };
+
+    let result = create_reoptimization(&create_test_problem(), &create_test_solution(), &[state], None);
+
+    assert!(result.is_err());
+}
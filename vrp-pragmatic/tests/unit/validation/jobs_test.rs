@@ -73,3 +73,65 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_non_positive_service_weight, (weight, expected), {
+    can_detect_non_positive_service_weight_impl(weight, expected);
+}}
+
+can_detect_non_positive_service_weight! {
+    case01: (Some(2.), None),
+    case02: (None, None),
+    case03: (Some(0.), Some("job1")),
+    case04: (Some(-1.), Some("job1")),
+}
+
+fn can_detect_non_positive_service_weight_impl(weight: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { service_weight: weight, ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_positive_service_weight(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1108", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_unassigned_cost, (cost, expected), {
+    can_detect_invalid_unassigned_cost_impl(cost, expected);
+}}
+
+can_detect_invalid_unassigned_cost! {
+    case01: (Some(2.), None),
+    case02: (Some(0.), None),
+    case03: (None, None),
+    case04: (Some(-1.), Some("job1")),
+    case05: (Some(f64::INFINITY), Some("job1")),
+}
+
+fn can_detect_invalid_unassigned_cost_impl(cost: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { unassigned_cost: cost, ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_non_negative_unassigned_cost(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(action) = expected {
+        assert_result("E1109", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
@@ -206,3 +206,93 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Objective>>, e
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_load_distance_weights, (weights, expected), {
+    can_detect_invalid_load_distance_weights_impl(weights, expected);
+}}
+
+can_detect_invalid_load_distance_weights! {
+    case01: (None, None),
+    case02: (Some(vec![1., 0.5]), None),
+    case03: (Some(vec![1., -0.5]), Some(())),
+    case04: (Some(vec![f64::NAN]), Some(())),
+}
+
+fn can_detect_invalid_load_distance_weights_impl(weights: Option<Vec<f64>>, expected: Option<()>) {
+    let problem =
+        Problem { objectives: Some(vec![MinimizeLoadDistance { weights }, MinimizeCost]), ..create_empty_problem() };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1608_load_distance_weights(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1608".to_string()));
+}
+
+parameterized_test! {can_detect_missing_limit_violations_objective, (objectives, expected), {
+    can_detect_missing_limit_violations_objective_impl(objectives, expected);
+}}
+
+can_detect_missing_limit_violations_objective! {
+    case01: (vec![MinimizeUnassigned { breaks: None }, MinimizeCost], Some("E1609".to_string())),
+    case02: (vec![MinimizeUnassigned { breaks: None }, MinimizeLimitViolations, MinimizeCost], None),
+}
+
+fn can_detect_missing_limit_violations_objective_impl(objectives: Vec<Objective>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: Some(100.),
+                    max_duration: None,
+                    tour_size: None,
+                    penalties: Some(VehicleLimitPenalties {
+                        max_distance: Some(1.),
+                        max_duration: None,
+                        tour_size: None,
+                        capacity: None,
+                    }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1609_soft_limits_but_no_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_missing_cost_objective_for_unassigned_cost, (objectives, expected), {
+    can_detect_missing_cost_objective_for_unassigned_cost_impl(objectives, expected);
+}}
+
+can_detect_missing_cost_objective_for_unassigned_cost! {
+    case01: (vec![MinimizeUnassigned { breaks: None }, MinimizeDistance], Some("E1610".to_string())),
+    case02: (vec![MinimizeUnassigned { breaks: None }, MinimizeCost], None),
+}
+
+fn can_detect_missing_cost_objective_for_unassigned_cost_impl(objectives: Vec<Objective>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { unassigned_cost: Some(10.), ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1610_jobs_with_unassigned_cost_but_no_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
@@ -136,3 +136,54 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_limit_penalties, (limits, has_reloads, expected), {
+    can_detect_invalid_limit_penalties_impl(limits, has_reloads, expected);
+}}
+
+can_detect_invalid_limit_penalties! {
+    case01: ((Some(100.), None, Some((1., None, None))), false, None),
+    case02: ((None, None, Some((1., None, None))), false, Some("E1309".to_string())),
+    case03: ((Some(100.), None, Some((-1., None, None))), false, Some("E1309".to_string())),
+    case04: ((Some(100.), Some(3), Some((1., Some(2.), None))), false, None),
+    case05: ((Some(100.), None, Some((1., Some(2.), None))), false, Some("E1309".to_string())),
+    case06: ((None, None, Some((1., None, Some(2.)))), false, Some("E1309".to_string())),
+    case07: ((Some(100.), None, Some((1., None, Some(2.)))), false, None),
+    case08: ((Some(100.), None, Some((1., None, Some(2.)))), true, Some("E1309".to_string())),
+    case09: ((Some(100.), None, None), true, None),
+}
+
+type LimitsData = (Option<f64>, Option<usize>, Option<(f64, Option<f64>, Option<f64>)>);
+
+fn can_detect_invalid_limit_penalties_impl(limits: LimitsData, has_reloads: bool, expected: Option<String>) {
+    let (max_distance, tour_size, penalties) = limits;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: if has_reloads { Some(vec![create_default_reload()]) } else { None },
+                    ..create_default_vehicle_shift()
+                }],
+                limits: Some(VehicleLimits {
+                    max_distance,
+                    max_duration: None,
+                    tour_size,
+                    penalties: penalties.map(|(max_distance, tour_size, capacity)| VehicleLimitPenalties {
+                        max_distance: Some(max_distance),
+                        max_duration: None,
+                        tour_size,
+                        capacity,
+                    }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1309_vehicle_limit_penalties(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
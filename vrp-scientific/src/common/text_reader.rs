@@ -112,6 +112,7 @@ pub(crate) fn create_goal_context_prefer_min_tours(
     GoalContextBuilder::with_features(&features)?
         .set_main_goal(Goal::subset_of(&features, &["min_unassigned", "min_tours", "min_distance"])?)
         .add_alternative_goal(Goal::subset_of(&features, &["min_unassigned", "min_distance"])?, 0.1)
+        .set_cost_feature("min_distance")
         .build()
 }
 
@@ -125,6 +126,7 @@ pub(crate) fn create_goal_context_distance_only(
     GoalContextBuilder::with_features(&features)?
         .set_main_goal(Goal::subset_of(&features, &["min_unassigned", "min_distance"])?)
         .add_alternative_goal(Goal::subset_of(&features, &["min_unassigned", "min_tours", "min_distance"])?, 0.1)
+        .set_cost_feature("min_distance")
         .build()
 }
 
@@ -1,8 +1,10 @@
+use std::sync::Arc;
 use vrp_core::construction::features::{JobDemandDimension, VehicleCapacityDimension};
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, JobIdDimension};
 use vrp_core::models::Problem;
+use vrp_core::prelude::{Environment, FeatureObjective, MoveContext};
 
 pub fn get_customer_id(job: &Job) -> String {
     get_job_id(job).to_owned()
@@ -87,3 +89,27 @@ pub fn get_job_simple_demand(job: &Job) -> &Demand<SingleDimLoad> {
     .get_job_demand()
     .unwrap()
 }
+
+/// Checks that the goal of the problem has a cost objective which can be augmented.
+pub fn assert_augmented_cost_objective(problem: Problem) {
+    struct ConstantObjective;
+
+    impl FeatureObjective for ConstantObjective {
+        fn fitness(&self, _: &InsertionContext) -> Cost {
+            10.
+        }
+
+        fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+            10.
+        }
+    }
+
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_empty(problem.clone(), Arc::new(Environment::default()));
+
+    let goal = problem.goal.with_augmented_objective(Arc::new(ConstantObjective)).expect("no augmented goal");
+
+    let mut expected = problem.goal.fitness(&insertion_ctx).collect::<Vec<_>>();
+    *expected.last_mut().unwrap() += 10.;
+    assert_eq!(goal.fitness(&insertion_ctx).collect::<Vec<_>>(), expected);
+}
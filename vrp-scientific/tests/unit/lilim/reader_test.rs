@@ -1,4 +1,4 @@
-use crate::helpers::{create_lc101_problem, get_job_ids, get_vehicle_capacity};
+use crate::helpers::{assert_augmented_cost_objective, create_lc101_problem, get_job_ids, get_vehicle_capacity};
 
 #[test]
 fn can_read_lilim_format_from_test_file() {
@@ -12,3 +12,8 @@ fn can_read_lilim_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+#[test]
+fn can_augment_cost_objective_of_lilim_goal() {
+    assert_augmented_cost_objective(create_lc101_problem());
+}
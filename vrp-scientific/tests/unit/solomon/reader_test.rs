@@ -37,3 +37,8 @@ fn can_read_solomon_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+#[test]
+fn can_augment_cost_objective_of_solomon_goal() {
+    assert_augmented_cost_objective(create_c101_25_problem());
+}